@@ -0,0 +1,56 @@
+//! Reflection Test Example
+//!
+//! This example shows `generate_tests: { test_reflect: true }`, which
+//! generates a test that adds the plugin to an app and checks that every
+//! `register_type` and `register_components` entry is in the `AppTypeRegistry`.
+//!
+//! Run the example with: cargo run --example reflect_test
+//! Run its generated test with: cargo test --example reflect_test --features testing
+
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+
+#[derive(Reflect, Default)]
+#[reflect(Default)]
+struct Difficulty {
+    enemy_health: f32,
+}
+
+#[derive(Component, Reflect)]
+struct Loot {
+    gold: u32,
+}
+
+define_plugin!(LootPlugin {
+    register_type: [Difficulty],
+    register_components: [Loot],
+
+    generate_tests: {
+        module: loot_plugin_tests,
+        test_reflect: true
+    }
+});
+
+fn main() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, LootPlugin));
+
+    // Build a Difficulty from its type registration alone, as an editor would
+    let difficulty = {
+        let registry = app.world().resource::<AppTypeRegistry>().read();
+        let reflect_default = registry
+            .get_type_data::<ReflectDefault>(std::any::TypeId::of::<Difficulty>())
+            .expect("LootPlugin registers Difficulty with ReflectDefault");
+        reflect_default
+            .default()
+            .take::<Difficulty>()
+            .expect("ReflectDefault builds a Difficulty")
+    };
+    info!("Default enemy health: {}", difficulty.enemy_health);
+
+    let loot = app.world_mut().spawn(Loot { gold: 25 }).id();
+    info!(
+        "Dropped {} gold",
+        app.world().get::<Loot>(loot).unwrap().gold
+    );
+}
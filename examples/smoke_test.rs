@@ -0,0 +1,61 @@
+//! Smoke Test Example
+//!
+//! This example shows `generate_tests: { test_app_boots: true }`, which
+//! generates a test that adds the plugin to a headless app and runs a few
+//! updates, catching systems that panic on their first run.
+//!
+//! Run the example with: cargo run --example smoke_test
+//! Run its generated test with: cargo test --example smoke_test --features testing
+
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy_plugin_builder::define_plugin;
+
+#[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+enum RoundState {
+    #[default]
+    Warmup,
+    Running,
+}
+
+#[derive(Resource, Default)]
+struct RoundClock {
+    elapsed: f32,
+}
+
+// Needs the Time resource, which the generated test gets from MinimalPlugins
+fn tick_round_clock(time: Res<Time>, mut clock: ResMut<RoundClock>) {
+    clock.elapsed += time.delta_secs();
+}
+
+fn start_round(mut next_state: ResMut<NextState<RoundState>>) {
+    info!("Warmup over, starting the round");
+    next_state.set(RoundState::Running);
+}
+
+define_plugin!(RoundPlugin {
+    init_resource: [RoundClock],
+    init_state: [RoundState],
+    add_systems_update: [
+        tick_round_clock,
+        start_round.run_if(in_state(RoundState::Warmup))
+    ],
+
+    // RoundPlugin declares a state, so the test also adds StatesPlugin
+    generate_tests: {
+        module: round_plugin_tests,
+        test_app_boots: true
+    }
+});
+
+fn main() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, StatesPlugin, RoundPlugin));
+    for _ in 0..3 {
+        app.update();
+    }
+    info!(
+        "Round state after three updates: {:?}",
+        app.world().resource::<State<RoundState>>().get()
+    );
+}
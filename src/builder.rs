@@ -0,0 +1,357 @@
+//! Runtime plugin builder.
+//!
+//! This module provides `PluginBuilder`, a runtime alternative to the
+//! `define_plugin!` macro for plugins whose configuration is only known
+//! at runtime.
+
+#[cfg(feature = "introspection")]
+use crate::metadata::{PluginMetadata, PluginSystems, TypeInfo};
+use crate::traits::{is_plugin_added, MissingPluginError, PluginMarker};
+use bevy::ecs::message::Message;
+use bevy::ecs::system::ScheduleSystem;
+use bevy::prelude::*;
+use std::sync::Mutex;
+
+/// A deferred registration applied to the App when the plugin is built.
+type Registration = Box<dyn FnOnce(&mut App) + Send>;
+
+/// A dependency check: whether the plugin is added, and its type name.
+type DependencyCheck = (fn(&App) -> bool, &'static str);
+
+/// Short type name for metadata, matching the names `define_plugin!` records.
+///
+/// Names of generic and compound types are built at runtime, so they are
+/// leaked like the rest of the recorded metadata.
+#[cfg(feature = "introspection")]
+fn short_type_name<T: ?Sized>() -> &'static str {
+    match crate::checks::short_type_name::<T>() {
+        std::borrow::Cow::Borrowed(name) => name,
+        std::borrow::Cow::Owned(name) => name.leak(),
+    }
+}
+
+/// Metadata recorded as builder methods are called.
+#[cfg(feature = "introspection")]
+#[derive(Default)]
+struct RecordedMetadata {
+    resources: Vec<TypeInfo>,
+    messages: Vec<TypeInfo>,
+    dependencies: Vec<&'static str>,
+    startup: Vec<&'static str>,
+    update: Vec<&'static str>,
+}
+
+#[cfg(feature = "introspection")]
+impl RecordedMetadata {
+    /// Leak the recorded metadata so it can be shared like macro metadata.
+    fn leak(self, name: &'static str) -> &'static PluginMetadata {
+        Box::leak(Box::new(PluginMetadata {
+            name,
+            display_name: None,
+            version: None,
+            description: None,
+            resources: self.resources.leak(),
+            messages: self.messages.leak(),
+            states: &[],
+            sub_states: &[],
+            reflected_types: &[],
+            reflected_components: &[],
+            sub_plugins: &[],
+            dependencies: self.dependencies.leak(),
+            observers: &[],
+            has_custom_build: false,
+            has_custom_finish: false,
+            systems: PluginSystems {
+                startup: self.startup.leak(),
+                update: self.update.leak(),
+                fixed_update: &[],
+                state_transition: &[],
+                on_enter_count: 0,
+                on_exit_count: 0,
+            },
+        }))
+    }
+}
+
+/// Builds a plugin at runtime with chainable registration methods.
+///
+/// Each method records a registration that is applied when the resulting
+/// plugin is added to an App. Dependencies are verified before any
+/// registration runs, matching the behavior of `define_plugin!`.
+///
+/// ## Example
+///
+/// ```rust
+/// use bevy_plugin_builder::PluginBuilder;
+/// use bevy::prelude::*;
+///
+/// #[derive(Resource, Default)]
+/// struct GameSettings;
+///
+/// #[derive(Message)]
+/// struct GameStarted;
+///
+/// fn game_loop() {}
+///
+/// let plugin = PluginBuilder::new("MyGamePlugin")
+///     .init_resource::<GameSettings>()
+///     .add_message::<GameStarted>()
+///     .add_systems_update(game_loop)
+///     .build();
+///
+/// App::new().add_plugins(plugin);
+/// ```
+pub struct PluginBuilder {
+    name: &'static str,
+    is_unique: bool,
+    dependencies: Vec<DependencyCheck>,
+    registrations: Vec<Registration>,
+    #[cfg(feature = "introspection")]
+    metadata: RecordedMetadata,
+}
+
+impl PluginBuilder {
+    /// Create a new builder for a plugin with the given name.
+    ///
+    /// The name is returned by `Plugin::name()`, so it must be unique among
+    /// the plugins added to an App.
+    pub fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            is_unique: true,
+            dependencies: Vec::new(),
+            registrations: Vec::new(),
+            #[cfg(feature = "introspection")]
+            metadata: RecordedMetadata::default(),
+        }
+    }
+
+    /// Initialize a resource with `init_resource`
+    pub fn init_resource<R: Resource + FromWorld>(mut self) -> Self {
+        #[cfg(feature = "introspection")]
+        self.metadata
+            .resources
+            .push(TypeInfo::new::<R>(short_type_name::<R>()));
+        self.registrations.push(Box::new(|app: &mut App| {
+            app.init_resource::<R>();
+        }));
+        self
+    }
+
+    /// Register a message with `add_message`
+    pub fn add_message<M: Message>(mut self) -> Self {
+        #[cfg(feature = "introspection")]
+        self.metadata
+            .messages
+            .push(TypeInfo::new::<M>(short_type_name::<M>()));
+        self.registrations.push(Box::new(|app: &mut App| {
+            app.add_message::<M>();
+        }));
+        self
+    }
+
+    /// Add systems to the `Startup` schedule
+    pub fn add_systems_startup<S, M>(mut self, systems: S) -> Self
+    where
+        S: IntoScheduleConfigs<ScheduleSystem, M> + Send + 'static,
+    {
+        #[cfg(feature = "introspection")]
+        self.metadata.startup.push(short_type_name::<S>());
+        self.registrations.push(Box::new(move |app: &mut App| {
+            app.add_systems(Startup, systems);
+        }));
+        self
+    }
+
+    /// Add systems to the `Update` schedule
+    pub fn add_systems_update<S, M>(mut self, systems: S) -> Self
+    where
+        S: IntoScheduleConfigs<ScheduleSystem, M> + Send + 'static,
+    {
+        #[cfg(feature = "introspection")]
+        self.metadata.update.push(short_type_name::<S>());
+        self.registrations.push(Box::new(move |app: &mut App| {
+            app.add_systems(Update, systems);
+        }));
+        self
+    }
+
+    /// Declare a dependency on a plugin created with `define_plugin!`
+    ///
+    /// The dependency must be added to the App before the built plugin,
+    /// otherwise building panics with a `MissingPluginError` message.
+    pub fn depends_on<P: PluginMarker>(mut self) -> Self {
+        #[cfg(feature = "introspection")]
+        self.metadata.dependencies.push(short_type_name::<P>());
+        self.dependencies
+            .push((is_plugin_added::<P>, std::any::type_name::<P>()));
+        self
+    }
+
+    /// Set what `Plugin::is_unique()` returns (default `true`)
+    ///
+    /// Bevy rejects a second plugin with the same name unless it is not
+    /// unique, so pass `false` to add several plugins built under one name.
+    pub fn is_unique(mut self, is_unique: bool) -> Self {
+        self.is_unique = is_unique;
+        self
+    }
+
+    /// Finish building and return the plugin
+    pub fn build(self) -> BuiltPlugin {
+        BuiltPlugin {
+            name: self.name,
+            is_unique: self.is_unique,
+            dependencies: self.dependencies,
+            registrations: Mutex::new(self.registrations),
+            #[cfg(feature = "introspection")]
+            metadata: self.metadata.leak(self.name),
+        }
+    }
+}
+
+/// A plugin produced by `PluginBuilder::build()`.
+pub struct BuiltPlugin {
+    name: &'static str,
+    is_unique: bool,
+    dependencies: Vec<DependencyCheck>,
+    registrations: Mutex<Vec<Registration>>,
+    #[cfg(feature = "introspection")]
+    metadata: &'static PluginMetadata,
+}
+
+impl BuiltPlugin {
+    /// Get the metadata recorded while the plugin was built
+    ///
+    /// Register it with `PluginRegistry::register_metadata` to make the
+    /// plugin visible to registry queries.
+    #[cfg(feature = "introspection")]
+    pub fn metadata(&self) -> &'static PluginMetadata {
+        self.metadata
+    }
+
+    /// Verify all declared dependencies are present in the App.
+    pub fn verify_dependencies(&self, app: &App) -> Result<(), Vec<MissingPluginError>> {
+        let missing: Vec<MissingPluginError> = self
+            .dependencies
+            .iter()
+            .filter(|(is_added, _)| !is_added(app))
+            .map(|(_, missing)| MissingPluginError {
+                required_by: self.name,
+                missing,
+            })
+            .collect();
+
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(missing)
+        }
+    }
+}
+
+impl Plugin for BuiltPlugin {
+    fn build(&self, app: &mut App) {
+        if let Err(errors) = self.verify_dependencies(app) {
+            panic!("{}", MissingPluginError::combined_message(&errors));
+        }
+
+        // Registrations are applied once; a plugin is only built a single time
+        let registrations = std::mem::take(
+            &mut *self
+                .registrations
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner()),
+        );
+        for registration in registrations {
+            registration(app);
+        }
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn is_unique(&self) -> bool {
+        self.is_unique
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Resource, Default)]
+    struct BuilderResource;
+
+    #[test]
+    fn test_builder_registers_resource() {
+        let plugin = PluginBuilder::new("BuilderTestPlugin")
+            .init_resource::<BuilderResource>()
+            .build();
+
+        assert_eq!(plugin.name(), "BuilderTestPlugin");
+
+        let mut app = App::new();
+        app.add_plugins(plugin);
+        assert!(app.world().contains_resource::<BuilderResource>());
+    }
+
+    #[test]
+    fn test_builder_empty_dependencies_verify() {
+        let plugin = PluginBuilder::new("NoDepsPlugin").build();
+        let app = App::new();
+        assert!(plugin.verify_dependencies(&app).is_ok());
+    }
+
+    #[test]
+    fn test_builder_not_unique_added_twice() {
+        let mut app = App::new();
+        app.add_plugins(
+            PluginBuilder::new("RepeatedPlugin")
+                .is_unique(false)
+                .build(),
+        );
+        app.add_plugins(
+            PluginBuilder::new("RepeatedPlugin")
+                .is_unique(false)
+                .build(),
+        );
+        assert_eq!(app.get_added_plugins::<BuiltPlugin>().len(), 2);
+    }
+
+    #[cfg(feature = "introspection")]
+    #[test]
+    fn test_builder_records_metadata() {
+        fn tick() {}
+
+        let plugin = PluginBuilder::new("RecordedPlugin")
+            .init_resource::<BuilderResource>()
+            .add_systems_update(tick)
+            .build();
+
+        let metadata = plugin.metadata();
+        assert_eq!(metadata.name, "RecordedPlugin");
+        assert!(metadata.has_resource::<BuilderResource>());
+        assert_eq!(metadata.resources[0].name, "BuilderResource");
+        assert_eq!(metadata.systems.update.len(), 1);
+    }
+
+    #[cfg(feature = "introspection")]
+    #[test]
+    fn test_builder_short_generic_type_names() {
+        #[derive(Resource, Default)]
+        struct GenericResource<T: Send + Sync + 'static>(std::marker::PhantomData<T>);
+
+        let plugin = PluginBuilder::new("GenericNamesPlugin")
+            .init_resource::<GenericResource<BuilderResource>>()
+            .build();
+
+        // Paths are stripped inside the generic arguments too
+        let metadata = plugin.metadata();
+        assert_eq!(
+            metadata.resources[0].name,
+            "GenericResource<BuilderResource>"
+        );
+    }
+}
@@ -0,0 +1,239 @@
+//! Runtime checks called by macro-generated plugin code.
+//!
+//! These are public so `define_plugin!` can reach them through `$crate`,
+//! but they are not part of the documented API.
+
+use bevy::prelude::*;
+use bevy::state::state::{StateTransitionEvent, SubStates};
+use std::borrow::Cow;
+
+/// Panic if the sub-state type of `_state` was never registered with
+/// `add_sub_state`.
+///
+/// Called from the generated `finish()` for `on_substate_update:` entries,
+/// once every plugin has been built.
+pub fn assert_sub_state_registered<P: 'static, S: SubStates>(app: &App, _state: &S) {
+    // add_sub_state registers this message, even while the sub-state is inactive
+    if !app
+        .world()
+        .contains_resource::<Messages<StateTransitionEvent<S>>>()
+    {
+        panic!(
+            "Plugin '{}' uses sub-state '{}' in on_substate_update, but it was never registered. Add it with add_sub_state: [{}].",
+            short_type_name::<P>(),
+            short_type_name::<S>(),
+            short_type_name::<S>()
+        );
+    }
+}
+
+/// Panic if the Bevy plugin `F` behind `feature` was never added.
+///
+/// Called from the generated `finish()` for `requires_bevy_features:` entries,
+/// once every plugin has been built.
+pub fn assert_bevy_feature_plugin<P: 'static, F: Plugin>(app: &App, feature: &str) {
+    if !app.is_plugin_added::<F>() {
+        panic!(
+            "Plugin '{}' requires the bevy feature '{}', but {} was never added. Enable the feature and add DefaultPlugins (or {}) to the App.",
+            short_type_name::<P>(),
+            feature,
+            short_type_name::<F>(),
+            short_type_name::<F>()
+        );
+    }
+}
+
+/// Panic if resource `R` is missing.
+///
+/// Called from the generated `finish()` for `assert_resources_present:`
+/// entries, once every plugin has been built.
+pub fn assert_resource_present<P: 'static, R: Resource>(app: &App) {
+    if !app.world().contains_resource::<R>() {
+        panic!(
+            "Plugin '{}' requires the resource '{}', but it was not present after every plugin was built. Add the plugin that initializes it, or insert it before adding '{}'.",
+            short_type_name::<P>(),
+            short_type_name::<R>(),
+            short_type_name::<P>()
+        );
+    }
+}
+
+/// Panic if the Bevy plugin `D` hasn't been added yet.
+///
+/// Called from the generated `build()` for `depends_on_bevy:` entries. Bevy's
+/// own plugins don't implement `PluginMarker`, so this asks the `App` directly.
+pub fn assert_bevy_plugin_added<P: 'static, D: Plugin>(app: &App) {
+    if !app.is_plugin_added::<D>() {
+        panic!(
+            "Plugin '{}' requires the Bevy plugin '{}' to be added first. Add '{}' (or DefaultPlugins) before '{}' in your app.add_plugins() call.",
+            short_type_name::<P>(),
+            short_type_name::<D>(),
+            short_type_name::<D>(),
+            short_type_name::<P>()
+        );
+    }
+}
+
+/// Insert the resource returned by `init`, which gets full world access.
+///
+/// Called from the generated `build()` for `init_resource_with:` entries. Taking
+/// the closure as a generic parameter lets `|world| ...` infer `&mut World`.
+pub fn init_resource_with<R: Resource>(world: &mut World, init: impl FnOnce(&mut World) -> R) {
+    let value = init(world);
+    world.insert_resource(value);
+}
+
+/// Log one registration made by plugin `P`'s `build()`.
+///
+/// Called from the generated `build()` for `trace_registrations: true`.
+pub fn trace_registration<P: 'static>(kind: &str, item: &str) {
+    trace!("{}: {} {}", short_type_name::<P>(), kind, item);
+}
+
+/// Log one registration plugin `P`'s `build()` would have made.
+///
+/// Called from the generated `build()` for `dry_run: true`.
+pub fn log_dry_run<P: 'static>(kind: &str, item: &str) {
+    info!("{}: (dry run) {} {}", short_type_name::<P>(), kind, item);
+}
+
+/// Type name with the module path stripped from every path in it, so
+/// `bevy_asset::assets::Assets<bevy_image::image::Image>` becomes
+/// `Assets<Image>`.
+///
+/// Plain paths are borrowed from `type_name`; only generic and compound
+/// types allocate.
+pub(crate) fn short_type_name<T: ?Sized>() -> Cow<'static, str> {
+    let name = std::any::type_name::<T>();
+    if !name.contains(['<', '(', '[', ' ']) {
+        return Cow::Borrowed(name.rsplit("::").next().unwrap_or(name));
+    }
+
+    let mut short = String::with_capacity(name.len());
+    let mut path = String::new();
+    for c in name.chars() {
+        if c.is_alphanumeric() || c == '_' || c == ':' {
+            path.push(c);
+        } else {
+            short.push_str(path.rsplit("::").next().unwrap_or(&path));
+            path.clear();
+            short.push(c);
+        }
+    }
+    short.push_str(path.rsplit("::").next().unwrap_or(&path));
+    Cow::Owned(short)
+}
+
+/// Log a warning if plugin `P` declares no systems and no resources.
+///
+/// Called from the generated `build()` for `warn_if_empty: true`.
+#[cfg(feature = "introspection")]
+pub fn warn_if_empty<P: crate::PluginInfo>() {
+    let metadata = P::metadata();
+    if metadata.total_systems() + metadata.resources.len() == 0 {
+        warn!("Plugin {} registered nothing", metadata.name);
+    }
+}
+
+/// Log a warning if plugin `P` has state transition systems but initializes
+/// no states itself.
+///
+/// Called from the generated `build()` for
+/// `warn_if_transitions_without_states: true`. The `OnEnter`/`OnExit` keys are
+/// state values, not types, so the exact state can't be checked. The systems
+/// still run if another plugin initializes the state, hence a warning rather
+/// than a panic.
+#[cfg(feature = "introspection")]
+pub fn warn_if_transitions_without_states<P: crate::PluginInfo>() {
+    let metadata = P::metadata();
+    let transition_systems = metadata.systems.on_enter_count + metadata.systems.on_exit_count;
+    if transition_systems > 0 && metadata.states.is_empty() && metadata.sub_states.is_empty() {
+        warn!(
+            "Plugin {} adds OnEnter/OnExit systems but initializes no states; they only run if another plugin adds the state with init_state",
+            metadata.name
+        );
+    }
+}
+
+/// Metadata of the `define_plugin!` plugins whose `finish()` has run, in
+/// the order they were added
+#[cfg(feature = "introspection")]
+#[derive(Resource, Default)]
+pub(crate) struct FinishedPlugins(Vec<&'static crate::PluginMetadata>);
+
+/// Log a warning for each resource of plugin `P` that a `define_plugin!`
+/// plugin added before it also initializes.
+///
+/// Called from the generated `finish()`, once every plugin has been built,
+/// so it compares against the plugins finished so far. Each overlap is
+/// reported once, by the later of the two plugins. Initializing a resource
+/// twice keeps the first value, so this is a warning rather than a panic.
+#[cfg(feature = "introspection")]
+pub fn warn_on_overlapping_resources<P: crate::PluginInfo>(app: &mut App) {
+    let metadata = P::metadata();
+    let mut finished = app.world_mut().get_resource_or_init::<FinishedPlugins>();
+    // A non-unique plugin added twice doesn't overlap with itself
+    if finished
+        .0
+        .iter()
+        .any(|other| std::ptr::eq(*other, metadata))
+    {
+        return;
+    }
+
+    for resource in metadata.resources {
+        let earlier: Vec<&str> = finished
+            .0
+            .iter()
+            .filter(|other| {
+                other
+                    .resources
+                    .iter()
+                    .any(|info| info.type_id() == resource.type_id())
+            })
+            .map(|other| other.name)
+            .collect();
+        if !earlier.is_empty() {
+            warn!(
+                "Plugin {} initializes resource {}, which is already initialized by {}",
+                metadata.name,
+                resource.name,
+                earlier.join(", ")
+            );
+        }
+    }
+    finished.0.push(metadata);
+}
+
+/// Log a warning for each system name that plugin `P` lists in more than one
+/// of its `Startup`, `Update`, `FixedUpdate` and `StateTransition` lists.
+///
+/// A system in two schedules runs in both, which is rarely intended. Names are
+/// the stringified list entries, so only identical entries are caught.
+#[cfg(feature = "introspection")]
+pub fn warn_on_systems_in_multiple_schedules<P: crate::PluginInfo>() {
+    let metadata = P::metadata();
+    let schedules = [
+        ("Startup", metadata.systems.startup),
+        ("Update", metadata.systems.update),
+        ("FixedUpdate", metadata.systems.fixed_update),
+        ("StateTransition", metadata.systems.state_transition),
+    ];
+
+    for (index, (schedule, systems)) in schedules.iter().enumerate() {
+        for (position, system) in systems.iter().enumerate() {
+            // Report each name once, even if it is repeated within one list
+            if systems[..position].contains(system) {
+                continue;
+            }
+            for (other_schedule, other_systems) in &schedules[index + 1..] {
+                if other_systems.contains(system) {
+                    warn!(
+                        "Plugin {} adds system {} to both {} and {}",
+                        metadata.name, system, schedule, other_schedule
+                    );
+                }
+            }
+        }
+    }
+}
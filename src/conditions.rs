@@ -0,0 +1,182 @@
+//! Run conditions used by macro-generated plugin code.
+//!
+//! These are ordinary Bevy run conditions, usable with `.run_if(...)` in
+//! hand-written schedules as well. Like Bevy's own common conditions, the
+//! ones without parameters are condition systems passed by name, and the
+//! ones with parameters are functions returning the condition.
+
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use std::marker::PhantomData;
+
+/// Run condition that is true while a primary window exists.
+///
+/// Headless apps, such as `App::new()` in tests, have no window, so systems
+/// that need a window or renderer are skipped instead of panicking.
+/// Used by the `update_if_windowed:` key.
+pub fn has_primary_window(windows: Query<(), With<PrimaryWindow>>) -> bool {
+    !windows.is_empty()
+}
+
+/// Run condition that is true if resource `R` exists and was added or
+/// changed since the condition last ran.
+///
+/// Unlike Bevy's `resource_changed`, a missing resource is not an error.
+pub fn resource_changed_or_added<R: Resource>(resource: Option<Res<R>>) -> bool {
+    resource.is_some_and(|resource| resource.is_changed())
+}
+
+/// Run condition that is true the first time it is checked and false after.
+///
+/// Each use of the condition keeps its own state, so two systems gated by
+/// `on_first_frame` both run once.
+pub fn on_first_frame(mut fired: Local<bool>) -> bool {
+    !std::mem::replace(&mut *fired, true)
+}
+
+/// Run condition that is true on every `n`th frame: frames `n`, `2n`, `3n`
+/// and so on, counting the frames on which the condition is checked.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_plugin_builder::every_n_frames;
+///
+/// fn autosave() {}
+///
+/// App::new().add_systems(Update, autosave.run_if(every_n_frames(600)));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `n` is zero.
+pub fn every_n_frames(n: u32) -> impl FnMut(Local<u32>) -> bool + Clone {
+    assert!(n > 0, "every_n_frames requires n > 0");
+    move |mut frames: Local<u32>| {
+        *frames += 1;
+        if *frames == n {
+            *frames = 0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Runtime switch for a plugin declared with `togglable: true`.
+///
+/// The plugin inserts it enabled, unless the app already has one. While it
+/// is disabled the plugin's `Update` and `FixedUpdate` systems (its
+/// `PluginSystemSet`) are skipped.
+#[derive(Resource)]
+pub struct PluginEnabled<P: 'static> {
+    enabled: bool,
+    _plugin: PhantomData<fn() -> P>,
+}
+
+impl<P: 'static> PluginEnabled<P> {
+    /// Create the switch for plugin `P`
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            _plugin: PhantomData,
+        }
+    }
+
+    /// Whether the plugin's systems currently run
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Enable or disable the plugin's systems
+    pub fn set(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+}
+
+impl<P: 'static> Default for PluginEnabled<P> {
+    fn default() -> Self {
+        Self::new(true)
+    }
+}
+
+/// Run condition that is true while `PluginEnabled<P>` exists and is enabled.
+///
+/// Used by the `togglable:` key.
+pub fn plugin_enabled<P: 'static>(enabled: Option<Res<PluginEnabled<P>>>) -> bool {
+    enabled.is_some_and(|enabled| enabled.is_enabled())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Resource, Default)]
+    struct Fired(Vec<u32>);
+
+    #[derive(Resource, Default)]
+    struct Frame(u32);
+
+    fn next_frame(mut frame: ResMut<Frame>) {
+        frame.0 += 1;
+    }
+
+    fn record(frame: Res<Frame>, mut fired: ResMut<Fired>) {
+        fired.0.push(frame.0);
+    }
+
+    fn fired_frames<M>(condition: impl SystemCondition<M>, frames: u32) -> Vec<u32> {
+        let mut app = App::new();
+        app.init_resource::<Fired>()
+            .init_resource::<Frame>()
+            .add_systems(Update, (next_frame, record.run_if(condition)).chain());
+        for _ in 0..frames {
+            app.update();
+        }
+        app.world().resource::<Fired>().0.clone()
+    }
+
+    #[test]
+    fn test_every_n_frames() {
+        assert_eq!(fired_frames(every_n_frames(3), 10), vec![3, 6, 9]);
+        assert_eq!(fired_frames(every_n_frames(1), 3), vec![1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "n > 0")]
+    fn test_every_n_frames_zero() {
+        let _ = every_n_frames(0);
+    }
+
+    #[test]
+    fn test_on_first_frame() {
+        assert_eq!(fired_frames(on_first_frame, 5), vec![1]);
+    }
+
+    #[test]
+    fn test_resource_changed_or_added() {
+        #[derive(Resource)]
+        struct Settings(u32);
+
+        let mut app = App::new();
+        app.init_resource::<Fired>()
+            .init_resource::<Frame>()
+            .add_systems(
+                Update,
+                (
+                    next_frame,
+                    record.run_if(resource_changed_or_added::<Settings>),
+                )
+                    .chain(),
+            );
+
+        // Missing, then added, then unchanged, then changed
+        app.update();
+        app.insert_resource(Settings(1));
+        app.update();
+        app.update();
+        app.world_mut().resource_mut::<Settings>().0 = 2;
+        app.update();
+
+        assert_eq!(app.world().resource::<Fired>().0, vec![2, 4]);
+    }
+}
@@ -0,0 +1,62 @@
+//! Headless debug report of registered plugins.
+//!
+//! Plugins declared with `generate_debug_hud: true` register themselves in
+//! the `PluginRegistry` resource and keep a `PluginDebugReport` up to date,
+//! so a game can draw the report in its own overlay or log it. Enabled by
+//! the `introspection` feature.
+
+use crate::metadata::PluginInfo;
+use crate::registry::PluginRegistry;
+use bevy::prelude::*;
+
+/// Text listing every plugin in the `PluginRegistry` with its counts.
+///
+/// Refreshed every frame by the system that `generate_debug_hud: true` adds.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct PluginDebugReport(pub String);
+
+/// Format one line per registered plugin, in registration order.
+pub fn plugin_debug_report(registry: &PluginRegistry) -> String {
+    registry
+        .list_all()
+        .map(|meta| {
+            format!(
+                "{} {}: {} resources, {} messages, {} states, {} systems",
+                meta.display_name.unwrap_or(meta.name),
+                meta.version.unwrap_or("-"),
+                meta.resources.len(),
+                meta.messages.len(),
+                meta.states.len(),
+                meta.total_systems()
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Rewrite `PluginDebugReport` from the current `PluginRegistry`
+pub fn update_plugin_debug_report(
+    registry: Res<PluginRegistry>,
+    mut report: ResMut<PluginDebugReport>,
+) {
+    let text = plugin_debug_report(&registry);
+    // Only touch the resource when the text changed, so readers can use change detection
+    if report.0 != text {
+        report.0 = text;
+    }
+}
+
+/// Register plugin `P` and add the report system once per App.
+///
+/// Called from the generated `build()` for `generate_debug_hud: true`.
+pub fn enable_debug_hud<P: PluginInfo + 'static>(app: &mut App) {
+    app.init_resource::<PluginRegistry>();
+    app.world_mut()
+        .resource_mut::<PluginRegistry>()
+        .register::<P>();
+
+    if !app.world().contains_resource::<PluginDebugReport>() {
+        app.init_resource::<PluginDebugReport>();
+        app.add_systems(Update, update_plugin_debug_report);
+    }
+}
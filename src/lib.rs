@@ -75,29 +75,168 @@
 //!
 //! - **`init_resource: [Type]`** - Initialize resources with `init_resource`
 //! - **`insert_resource: [Instance]`** - Insert resource instances directly
+//! - **`init_resource_with: { Type => |world| ... }`** - Insert a resource computed
+//!   from `&mut World`
+//! - **`insert_resource_if_absent: { Type => value }`** - Insert a resource unless
+//!   it is already present
+//! - **`inspectable_resources: [Type]`** - Initialize resources and register them
+//!   for reflection, for inspector tools
 //! - **`add_message: [Msg]`** - Register messages with `add_message`
+//! - **`add_message_with: { Msg => ManualUpdate }`** - Register messages with an
+//!   update policy (`Automatic`, or `ManualUpdate` for messages that are never
+//!   cleared automatically; the app must drain them itself)
+//! - **`add_message_buffered: { Msg => 3 }`** - Register messages that stay
+//!   readable for the given number of updates instead of Bevy's two. The
+//!   message must not also be registered with `add_message` beforehand
 //! - **`add_plugins: [Plugin]`** - Add sub-plugins with `add_plugins`
-//! - **`init_state: [State]`** - Initialize states with `init_state`
+//! - **`add_plugins_configured: { Group => .disable::<P>() }`** - Add a plugin
+//!   group through its `PluginGroupBuilder`, e.g. to disable one sub-plugin
+//! - **`init_state: [State]`** - Initialize states with `init_state` (this also
+//!   enables `DespawnOnExit`/`DespawnOnEnter` cleanup for the state)
 //! - **`add_sub_state: [SubState]`** - Add sub-states with `add_sub_state`
 //! - **`register_type: [Type]`** - Register types for reflection
+//! - **`register_components: [Component]`** - Register components for reflection,
+//!   listed under `reflected_components` in the metadata
+//! - **`auto_reflect_resources: true`** - Also register every `init_resource`
+//!   type for reflection (the types must implement `Reflect`, or `build()` panics)
+//! - **`add_observers: [observer]`** - Add global observers with `add_observer`
 //!
 //! ### System Scheduling Options
 //!
 //! - **`add_systems_startup: [sys]`** - Add startup systems
 //! - **`add_systems_update: [sys]`** - Add update systems
 //! - **`add_systems_fixed_update: [sys]`** - Add fixed update systems
+//!   (entries in these lists may be gated with `#[cfg(...)]`)
+//! - **`add_systems_update_raw: expr`** - Pass one pre-built system config to
+//!   `add_systems` as-is (also `add_systems_startup_raw`, `add_systems_fixed_update_raw`)
+//! - **`add_systems_state_transition: [sys]`** - Add systems to the `StateTransition`
+//!   schedule, where both the old and new state can be read
+//! - **`add_systems_update_exclusive: [sys]`** - Add exclusive `&mut World`
+//!   systems to `Update`, each in its own `add_systems` call
+//! - **`ambiguous_with_all_update: [sys]`** - Add update systems that are exempt
+//!   from execution order ambiguity detection
+//! - **`add_systems_update_if: { flag => [sys] }`** - Add update systems only if a
+//!   `bool` evaluated in `build()` is true
 //! - **`add_systems_on_enter: { State => [sys] }`** - State enter systems
 //! - **`add_systems_on_exit: { State => [sys] }`** - State exit systems
+//!   (`State if condition => [sys]` gates the whole list with a run condition)
+//! - **`on_enter_any: { [State1, State2] => [sys] }`** - The same enter systems for
+//!   several states
+//! - **`with_conditions: [cond]`** - Run conditions for the directly following
+//!   `add_systems_update` or `add_systems_fixed_update` list
+//! - **`distributive_run_if_update: cond`** - Condition checked by each system of
+//!   the directly following `add_systems_update` list
+//! - **`on_substate_update: { SubState::Variant => [sys] }`** - Update systems
+//!   that only run in a sub-state; the sub-state must be added with `add_sub_state`
+//! - **`update_if_resource_exists: { Res => [sys] }`** - Update systems that only
+//!   run while the resource exists
+//! - **`update_if_windowed: [sys]`** - Update systems that only run while a primary
+//!   window exists, so headless apps skip them
+//! - **`update_every_n_frames: { 5 => [sys] }`** - Update systems that only run on
+//!   every 5th frame
+//! - **`update_before: [Set]`** / **`update_after: [Set]`** - Order the plugin's
+//!   `Update` systems (its `PluginSystemSet`) relative to other system sets
+//! - **`togglable: true`** - Gate the plugin's `Update` and `FixedUpdate` systems
+//!   on a `PluginEnabled<MyPlugin>` resource, to switch the plugin off at runtime
+//! - **`run_if: condition`** - Run the plugin's `Update` and `FixedUpdate` systems
+//!   only while the condition holds, e.g. `run_if: in_state(GameState::Playing)`
+//! - **`generate_system_set: MyPluginSystems`** - Declare a named system set holding
+//!   the plugin's `Update` systems, for other plugins to order against
+//! - **`flush_between_update: true`** - Chain the directly following
+//!   `add_systems_update` list, applying commands between its systems
+//! - **`chain_startup: true`** - Run each `add_systems_startup` list in the order
+//!   it is written (unchained by default)
+//!
+//! ### Custom Schedule Options
+//!
+//! - **`init_schedule: [Label]`** - Create custom schedules with `init_schedule`
+//! - **`add_systems_to: { Label => [sys] }`** - Add systems to any schedule label
+//! - **`add_systems_to_many: { [Label1, Label2] => [sys] }`** - Add the same systems
+//!   to several schedule labels
+//!
+//! ### Struct Attributes
+//!
+//! `define_plugin!(#[derive(Debug, Default)] MyPlugin { ... })` applies the
+//! attributes (and doc comments) before the name to the generated struct.
+//!
+//! ### Plugin Fields
+//!
+//! - **`fields: { speed: f32 }`** - Generate the plugin with `pub` fields
+//! - **`constructors: { with_speed(speed: f32) }`** - Generate constructors that
+//!   set the named fields and default the rest (the plugin must implement `Default`)
+//! - **`settings_resource: MySettings`** - Insert a `MySettings` resource built
+//!   from clones of the plugin's fields, for systems to read
+//! - **`custom_build: |app, plugin| { ... }`** - Custom build logic that reads the
+//!   plugin's fields
+//!
+//! ### Wrapping Another Plugin
+//!
+//! `define_plugin!(MyPhysics wraps PhysicsPlugin::default() { ... })` adds the
+//! wrapped plugin first, then applies the configuration block.
+//!
+//! ### Several Plugins at Once
+//!
+//! `define_plugins! { PluginA { ... } PluginB { ... } }` passes each block to
+//! `define_plugin!`, for modules holding several small plugins.
 //!
 //! ### Custom Logic Options
 //!
 //! - **`custom_build: |app| { ... }`** - Custom build logic
 //! - **`custom_finish: |app| { ... }`** - Custom finish logic
+//! - **`remove_resource: [Res]`** - Remove resources in `finish()`
+//! - **`finish_init_resource: { Res => |world| ... }`** - Insert a resource computed in
+//!   `finish()`, e.g. from a dependency plugin's resources
+//! - **`assert_resources_present: [Res]`** - Panic in `finish()` if a listed
+//!   resource is missing once every plugin has been built
+//!
+//! `custom_build` and `custom_finish` also take a named `fn(&mut App)`.
+//!
+//! Whatever order the keys are written in, `build()` registers sub-plugins,
+//! resources, messages, states and types first, then sub-states, then systems,
+//! then runs `custom_build`.
+//!
+//! ### Uniqueness
+//!
+//! - **`is_unique: false`** - Allow the plugin to be added more than once by
+//!   overriding `Plugin::is_unique()`
+//!
+//! ### Diagnostics
+//!
+//! - **`warn_if_empty: true`** - Log a warning if the plugin declares no systems
+//!   and no resources (requires `introspection` feature)
+//! - **`warn_if_transitions_without_states: true`** - Log a warning if the plugin
+//!   adds `OnEnter`/`OnExit` systems but initializes no states (requires `introspection` feature)
+//! - **`generate_debug_hud: true`** - Register the plugin in the `PluginRegistry`
+//!   and write a per-plugin summary to the `PluginDebugReport` resource every frame
+//!   (requires `introspection` feature)
+//! - **`generate_reflect_report: true`** - Snapshot the `PluginRegistry` into a reflectable
+//!   `ReflectablePluginReport` resource in `finish()`, queryable over the Bevy Remote
+//!   Protocol (requires `reflect` feature)
+//! - **`impl_debug: true`** - Implement `Debug` for the plugin, printing its name
+//!   and registration counts (requires `introspection` feature)
+//! - **`metadata_const_name: MY_PLUGIN_METADATA`** - Declare a `pub const` pointing at
+//!   the plugin's metadata, for const assertions (requires `introspection` feature)
+//! - **`lint_custom_build: true`** - Warn about `custom_build` calls such as
+//!   `app.init_resource::<T>()` that a declarative key covers (matched by method
+//!   name, whatever the receiver)
+//! - **`trace_registrations: true`** - Log every registration with `trace!` as
+//!   `build()` runs, to debug plugin load order
+//! - **`dry_run: true`** - Log every registration with `info!` and register nothing,
+//!   to compare what different versions of a plugin would register
 //!
 //! ### Plugin Features
 //!
-//! - **`depends_on: [Plugin1, Plugin2]`** - Declare plugin dependencies (panics if missing)
+//! - **`depends_on: [Plugin1, Plugin2]`** - Declare plugin dependencies (panics if missing);
+//!   prefix with `#[cfg(...)]` to only require them when the predicate holds
+//! - **`dependency_check: warn`** - Log missing dependencies instead of panicking,
+//!   handy while prototyping (default `panic`)
+//! - **`depends_on_bevy: [AssetPlugin]`** - Require one of Bevy's own plugins, which
+//!   `depends_on` can't check because they don't implement `PluginMarker`
+//! - **`requires_bevy_features: ["bevy_render"]`** - Panic in `finish()` with the
+//!   feature name if the plugin for a required Bevy feature was never added
 //! - **`meta: { version: "1.0", description: "..." }`** - Plugin metadata (requires `introspection` feature)
+//!   and an optional `default_settings: MySettings::default()` resource inserted on build
+//!   and `display_name: "..."`, recorded in `PluginMetadata::display_name`
 //!
 //! ## Advanced Example
 //!
@@ -172,8 +311,38 @@
 //!
 //! - **`introspection`** - Enables runtime metadata querying via `PluginInfo` trait and `PluginRegistry`
 //! - **`testing`** - Enables automatic test generation with `generate_tests:` syntax
+//! - **`reflect`** - Enables `generate_reflect_report:` and derives `Reflect` for
+//!   `OwnedPluginMetadata` (implies `introspection`)
 //! - **`full`** - Enables all features
 //!
+//! ### Test Generation Example
+//!
+//! With the `testing` feature enabled, `generate_tests:` emits `#[test]`
+//! functions into the module named by the required `module:` entry. Give each
+//! plugin in a scope its own module name:
+//!
+//! ```rust,ignore
+//! define_plugin!(InventoryPlugin {
+//!     init_resource: [Inventory],
+//!     generate_tests: {
+//!         module: inventory_plugin_tests,
+//!         test_resources: true
+//!     }
+//! });
+//! // cargo test reports `inventory_plugin_tests::test_resources`
+//! ```
+//!
+//! Available checks: `test_resources`, `test_messages`, `test_states`,
+//! `test_dependencies`, `test_reflect` and `test_unique`, which adds the
+//! plugin twice and expects Bevy's duplicate plugin panic, or no panic if the
+//! plugin sets `is_unique: false`. With `introspection` also enabled,
+//! `test_no_duplicate_resources` fails if two plugins among the plugin, its
+//! declared dependencies and theirs initialize the same resource.
+//! `test_app_boots` adds the plugin to a headless app with `MinimalPlugins`
+//! (plus `StatesPlugin` if it declares states) after its dependencies and
+//! theirs, finishes the app and runs a few updates, catching systems that
+//! panic on their first run. Plugins with `fields:` are built with `Default`.
+//!
 //! ### Introspection Example
 //!
 //! With the `introspection` feature enabled:
@@ -202,6 +371,39 @@
 //! let plugins = registry.plugins_with_resource::<MyResource>();
 //! ```
 //!
+//! Plugins created with `PluginBuilder` record the same metadata as their
+//! methods are called. Access it with `BuiltPlugin::metadata()` and add it
+//! to a registry with `PluginRegistry::register_metadata()`.
+//!
+//! ## Prelude
+//!
+//! `use bevy_plugin_builder::prelude::*;` imports the macro, the dependency
+//! traits, `PluginBuilder`, and (with `introspection`) the metadata and
+//! registry types.
+//!
+//! ## Run Conditions
+//!
+//! `has_primary_window`, `resource_changed_or_added::<R>`, `on_first_frame` and
+//! `every_n_frames(n)` back the `update_if_*` keys and can be used with
+//! `.run_if(...)` anywhere.
+//!
+//! ## Runtime Builder
+//!
+//! `PluginBuilder` is an alternative to the macro for plugins whose
+//! configuration is only known at runtime:
+//!
+//! ```rust,ignore
+//! use bevy_plugin_builder::PluginBuilder;
+//!
+//! let plugin = PluginBuilder::new("MyPlugin")
+//!     .depends_on::<PhysicsPlugin>()
+//!     .init_resource::<MyResource>()
+//!     .add_systems_update(my_system)
+//!     .build();
+//!
+//! app.add_plugins(plugin);
+//! ```
+//!
 //! ## Migration Guide
 //!
 //! Converting existing plugins is straightforward:
@@ -214,25 +416,90 @@
 //!
 
 // Private implementation modules
+mod builder;
+mod checks;
+mod conditions;
 mod macros;
+mod messages;
+mod sets;
 mod traits;
 
+pub mod prelude;
+
 // Introspection modules (feature-gated)
 #[cfg(feature = "introspection")]
+mod debug_hud;
+#[cfg(feature = "introspection")]
 mod metadata;
 #[cfg(feature = "introspection")]
 mod registry;
+#[cfg(feature = "reflect")]
+mod remote;
 
 // Re-export commonly used Bevy types for convenience
-pub use bevy::prelude::{App, FixedUpdate, OnEnter, OnExit, Plugin, Startup, Update};
+pub use bevy::prelude::{
+    App, FixedUpdate, OnEnter, OnExit, Plugin, Startup, StateTransition, Update,
+};
 
 // Re-export traits for plugin dependency checking
-pub use traits::{MissingPluginError, PluginDependencies, PluginMarker, PluginSet};
+#[doc(hidden)]
+pub use traits::{
+    assert_component, assert_plugin_marker, plugin_system, AutoReflect, DependencyCheck,
+    InitStateEntry, PluginSystem, RegisterReflectedResource, SkipDependencyCheck,
+    SkipReflectedResource, SubStateEntry, VerifyDependencyCheck,
+};
+pub use traits::{AppPluginExt, MissingPluginError, PluginDependencies, PluginMarker, PluginSet};
+#[cfg(feature = "testing")]
+#[doc(hidden)]
+pub use traits::{BootTestInstance, BootTestPlugin};
+
+// Re-export the per-plugin system set
+pub use sets::PluginSystemSet;
+
+// Runtime checks used by generated code
+#[doc(hidden)]
+pub use checks::{
+    assert_bevy_feature_plugin, assert_bevy_plugin_added, assert_resource_present,
+    assert_sub_state_registered, init_resource_with, log_dry_run, trace_registration,
+};
+#[cfg(feature = "introspection")]
+#[doc(hidden)]
+pub use checks::{
+    warn_if_empty, warn_if_transitions_without_states, warn_on_overlapping_resources,
+    warn_on_systems_in_multiple_schedules,
+};
+
+// Re-export run conditions
+pub use conditions::{
+    every_n_frames, has_primary_window, on_first_frame, plugin_enabled, resource_changed_or_added,
+    PluginEnabled,
+};
+
+// Re-export message retention for add_message_buffered
+#[doc(hidden)]
+pub use messages::add_buffered_message;
+pub use messages::{update_retained_messages, MessageRetention};
+
+// Re-export the runtime builder
+pub use builder::{BuiltPlugin, PluginBuilder};
 
 // Re-export introspection types (feature-gated)
 #[cfg(feature = "introspection")]
-pub use metadata::{PluginInfo, PluginMetadata, PluginSystems, TypeInfo};
+#[doc(hidden)]
+pub use debug_hud::enable_debug_hud;
+#[cfg(feature = "introspection")]
+pub use debug_hud::{plugin_debug_report, update_plugin_debug_report, PluginDebugReport};
+#[cfg(feature = "introspection")]
+pub use metadata::{
+    OwnedPluginMetadata, PluginDependencyTree, PluginInfo, PluginMetadata, PluginMetadataDiff,
+    PluginSystems, TypeInfo,
+};
 #[cfg(feature = "introspection")]
 pub use registry::PluginRegistry;
+#[cfg(feature = "reflect")]
+pub use remote::ReflectablePluginReport;
+#[cfg(feature = "reflect")]
+#[doc(hidden)]
+pub use remote::{enable_reflect_report, update_reflect_report};
 
 // The macro is automatically available via #[macro_export] in macros.rs
@@ -14,29 +14,293 @@
 ///
 /// ### Metadata
 /// - `meta: { version: "1.0.0", description: "..." }` - Plugin metadata
+/// - `meta: { default_settings: MySettings::default() }` - Settings resource
+///   inserted when the plugin is built
+/// - `meta: { display_name: "Foo Subsystem" }` - Human-readable name recorded
+///   in `PluginMetadata::display_name` and shown by the debug HUD.
+///   `Plugin::name()` stays the type path, and `PluginMetadata::name` and
+///   `PluginInfo::NAME` keep the identifier
 ///
 /// ### Dependencies
-/// - `depends_on: [Plugin1, Plugin2]` - Declare plugin dependencies
+/// - `depends_on: [Plugin1, Plugin2]` - Declare plugin dependencies. The key
+///   can appear anywhere and more than once; every list is checked before
+///   anything else is built
+/// - `#[cfg(feature = "audio")] depends_on: [Plugin3]` - Only require the
+///   dependencies when the cfg predicate holds (cfg applies to the whole list,
+///   which must come first)
+/// - `dependency_check: warn` - Log missing dependencies with `warn!` and keep
+///   building instead of panicking (the default, `dependency_check: panic`)
+/// - `depends_on_bevy: [AssetPlugin]` - Declare dependencies on Bevy's own
+///   plugins, which don't implement `PluginMarker`. `build()` panics with a
+///   clear message unless `app.is_plugin_added::<AssetPlugin>()`. Can be
+///   combined with `depends_on`
+/// - `requires_bevy_features: ["bevy_render"]` - Check in `finish()` that the
+///   plugin behind each Bevy feature was added, panicking with the feature
+///   name otherwise. The check names the feature's plugin through `bevy::`,
+///   so a feature disabled in your `bevy` dependency fails to compile there.
+///   Supported: `bevy_asset`, `bevy_audio`, `bevy_render`, `bevy_state`,
+///   `bevy_text`, `bevy_ui`, `bevy_window`, `bevy_winit`
 ///
 /// ### Type Registration (Bevy-aligned naming)
 /// - `init_resource: [Type1, Type2]` - Initialize resources with `init_resource`
 /// - `insert_resource: [Value1, Value2]` - Insert resources with values
+/// - `insert_resource_if_absent: { Type3 => Type3::default() }` - Insert a resource
+///   only if no other plugin has inserted it yet, so the first value wins
+/// - `init_resource_with: { Type4 => |world| Type4::new(world) }` - Insert a
+///   resource computed by a closure taking `&mut World`, e.g. from resources
+///   inserted earlier in the same plugin
+/// - `inspectable_resources: [Settings]` - Initialize resources and register
+///   them for reflection in one entry, for inspector tools such as
+///   `bevy-inspector-egui`. The types must derive `Reflect` (with
+///   `#[reflect(Resource)]`) as well as `Resource` and `Default`
 /// - `add_message: [Msg1, Msg2]` - Register messages with `add_message`
+/// - `add_message_with: { Msg3 => ManualUpdate }` - Register messages with an
+///   update policy: `Automatic` (same as `add_message`) or `ManualUpdate`
+///   (never cleared automatically, for messages read less often than every
+///   frame; drain them with `Messages::drain` or call `Messages::update`
+///   yourself, or they accumulate forever)
+/// - `add_message_buffered: { Msg5 => 3 }` - Register messages that stay
+///   readable for at least the given number of updates (at least 2), for
+///   readers that poll at a lower rate than every frame. `build()` panics if
+///   the message is already registered, e.g. by another plugin's `add_message`
 /// - `add_plugins: [Plugin1, Plugin2]` - Add sub-plugins
-/// - `init_state: [State1]` - Initialize states
+/// - `add_plugins_configured: { DefaultPlugins => .disable::<LogPlugin>() }` -
+///   Add a `PluginGroup` after applying a chain of `PluginGroupBuilder` methods
+///   to `group.build()`. Each key must implement `PluginGroup`
+/// - `init_state: [State1]` - Initialize states. Bevy enables state-scoped
+///   entity cleanup (`DespawnOnExit`/`DespawnOnEnter`) for every initialized
+///   state, so no separate key is needed
 /// - `add_sub_state: [SubState1]` - Add sub-states
-/// - `register_type: [Type1, Type2]` - Register types for reflection
+/// - `register_type: [Type1, Wrapper<Type2>]` - Register types for reflection,
+///   including instantiations of generic types
+/// - `register_components: [Player, Boss]` - Register components for
+///   reflection. Same as `register_type`, but recorded in the metadata's
+///   `reflected_components` rather than `reflected_types`
+/// - `auto_reflect_resources: true` - Also register every `init_resource`
+///   type for reflection. Every such type must then implement `Reflect`
+///   (usually `#[derive(Reflect)]` with `#[reflect(Resource)]`); `build()`
+///   panics on one that does not
+/// - `add_observers: [on_player_added]` - Add global observers with
+///   `add_observer`. The observer expressions are listed in the metadata
 ///
 /// ### System Scheduling (Bevy-aligned naming)
 /// - `add_systems_startup: [system1, system2]` - Add startup systems
 /// - `add_systems_update: [system3, system4]` - Add update systems
 /// - `add_systems_fixed_update: [system5]` - Add fixed update systems
+/// - An entry in these three lists may carry outer attributes, e.g.
+///   `add_systems_update: [#[cfg(feature = "netcode")] net_sync, movement]`.
+///   Each attributed entry is added with its own `add_systems` call under
+///   those attributes, and the metadata lists it only when its `#[cfg]` holds
+/// - `add_systems_update_raw: my_systems()` - Pass a single pre-built system
+///   config (e.g. a helper returning `impl IntoScheduleConfigs`) straight to
+///   `add_systems` without wrapping it in a tuple. `add_systems_startup_raw`
+///   and `add_systems_fixed_update_raw` do the same for their schedules
+/// - `add_systems_state_transition: [log_transition]` - Add systems to the
+///   `StateTransition` schedule, which runs state changes, for systems that
+///   read both the old and new state
+/// - `add_systems_update_exclusive: [reset_world]` - Add exclusive systems
+///   (taking `&mut World`) to `Update`, each with its own `add_systems` call so
+///   they are never mixed into a tuple with parallel systems
+/// - `ambiguous_with_all_update: [system10]` - Add update systems with
+///   `.ambiguous_with_all()`, so Bevy's ambiguity detection ignores them
+/// - `add_systems_update_if: { flag => [system9] }` - Add update systems only if
+///   the `bool` expression is true when the plugin is built (unlike `run_if`,
+///   which is checked every frame)
 /// - `add_systems_on_enter: { State::Variant => [system6] }` - State enter systems
 /// - `add_systems_on_exit: { State::Variant => [system7] }` - State exit systems
+/// - `on_enter_any: { [State::A, State::B] => [system8] }` - Add the same
+///   systems to `OnEnter` of each listed state
+///
+/// A state's list can be ordered with `State::Variant => [system6, system8].chain()`,
+/// or given as any system expression, e.g. `State::Variant => (system6, system8).chain()`.
+/// An empty list (`State::Variant => []`) adds nothing.
+///
+/// A single entry can be gated as usual, e.g. `[spawn_tutorial.run_if(first_time)]`.
+/// `State::Variant if condition => [system6, system8]` gates the whole list with
+/// one run condition. The state must be written as a path in this form.
+///
+/// State transition entries merge. Repeating a state within one map, or across
+/// several `add_systems_on_enter`/`add_systems_on_exit` keys, registers each list
+/// with its own `add_systems` call. Bevy appends rather than replaces, so every
+/// list runs on that transition, with no ordering between separate lists.
+///
+/// ### Run Conditions
+/// - `with_conditions: [cond1, cond2]` - Gate the next system list with
+///   `.run_if(cond1.and(cond2))`. Must directly precede `add_systems_update`
+///   or `add_systems_fixed_update`.
+/// - `distributive_run_if_update: cond` - Gate each system of the next
+///   `add_systems_update` list with `.distributive_run_if(cond)`, so every
+///   system checks the condition when it runs rather than once for the list
+/// - `on_substate_update: { SubState::Variant => [system8] }` - Update systems
+///   gated by `in_state(SubState::Variant)`. `finish()` panics if the sub-state
+///   was never added with `add_sub_state`.
+/// - `update_if_resource_exists: { MyConfig => [system10] }` - Update systems
+///   gated by `resource_exists::<MyConfig>`, so they start running once the
+///   resource is inserted
+/// - `update_if_windowed: [system11]` - Update systems gated by
+///   `has_primary_window`, so headless apps (such as `App::new()` in tests)
+///   skip systems that need a window or renderer
+/// - `update_every_n_frames: { 5 => [system12] }` - Update systems gated by
+///   `every_n_frames(5)`, to throttle expensive systems
+///
+/// ### Ordering
+/// - `update_before: [SomeSet]` - Run this plugin's `Update` systems before a set
+/// - `update_after: [OtherSet]` - Run this plugin's `Update` systems after a set
+///
+/// `Update` and `FixedUpdate` systems are placed in
+/// `PluginSystemSet<PluginName>`, which these keys configure.
+///
+/// - `togglable: true` - Insert a `PluginEnabled<PluginName>` resource
+///   (enabled) and only run the `PluginSystemSet` while it is enabled, so
+///   `app.world_mut().resource_mut::<PluginEnabled<PluginName>>().set(false)`
+///   switches the plugin's `Update` and `FixedUpdate` systems off
+/// - `run_if: in_state(GameState::Playing)` - Only run the `PluginSystemSet`
+///   while the condition holds, pausing all of the plugin's `Update` and
+///   `FixedUpdate` systems at once. The condition expression is used once
+///   per schedule, so it needn't be `Clone`, but a stateful condition such as
+///   `every_n_frames(2)` keeps a separate count in each schedule
+/// - `generate_system_set: MyPluginSystems` - Declare a `pub` system set named
+///   `MyPluginSystems` containing this plugin's `Update` systems, so other
+///   plugins can order against it with `.after(MyPluginSystems)`
+/// - `flush_between_update: true` - Chain the directly following
+///   `add_systems_update` list, so each system runs after the previous one and
+///   sees its commands applied. For ordering without the command flush, give
+///   `(a, b).chain_ignore_deferred()` as a single list entry instead.
+/// - `chain_startup: true` - Chain each `add_systems_startup` list, so its
+///   systems run in the order they are listed (e.g. load assets before spawning
+///   them). Unchained by default. Entries with attributes are added on their own
+///   and are not part of the chain
+///
+/// ### Custom Schedules
+/// - `init_schedule: [MySchedule]` - Create schedules with `init_schedule`
+/// - `add_systems_to: { MySchedule => [system8] }` - Add systems to any schedule label
+/// - `add_systems_to_many: { [Update, PostUpdate] => [system9] }` - Add the same
+///   systems to each listed schedule label
+///
+/// ### Struct Attributes
+/// Attributes and doc comments written before the plugin name are applied to
+/// the generated struct, so it can carry its own derives:
+///
+/// ```rust
+/// use bevy_plugin_builder::define_plugin;
+/// use bevy::prelude::*;
+///
+/// fn tick() {}
+///
+/// define_plugin!(
+///     /// Ticks the world
+///     #[derive(Debug, Clone, Copy, Default)]
+///     TickPlugin {
+///         add_systems_update: [tick]
+///     }
+/// );
+///
+/// App::new().add_plugins(TickPlugin::default());
+/// ```
+///
+/// ### Plugin Fields
+/// - `fields: { speed: f32 }` - Generate the plugin as a struct with these
+///   `pub` fields instead of a unit struct
+/// - `constructors: { with_speed(speed: f32) }` - Generate
+///   `pub fn with_speed(speed: f32) -> Self`, setting the named fields and
+///   taking the rest from `Default` (derive it with a struct attribute)
+/// - `settings_resource: MovementSettings` - Insert a `MovementSettings`
+///   resource whose fields are cloned from the plugin's fields of the same
+///   names, so systems can read the values the plugin was constructed with.
+///   Requires `fields:`; every field type must implement `Clone`
+/// - `custom_build: |app: &mut App, plugin: &Self| { ... }` - A two-argument
+///   `custom_build` also receives the plugin, to read its fields. It runs
+///   before the rest of the configuration
+///
+/// ```rust
+/// use bevy_plugin_builder::define_plugin;
+/// use bevy::prelude::*;
+///
+/// #[derive(Resource)]
+/// struct Speed(f32);
+///
+/// define_plugin!(#[derive(Default)] MovementPlugin {
+///     fields: { speed: f32 },
+///     constructors: { with_speed(speed: f32) },
+///     custom_build: |app: &mut App, plugin: &Self| {
+///         app.insert_resource(Speed(plugin.speed));
+///     }
+/// });
+///
+/// App::new().add_plugins(MovementPlugin::with_speed(5.0));
+/// ```
+///
+/// Plugins with fields are values rather than unit structs, so
+/// `generate_tests:` can't construct them.
+///
+/// ### Wrapping Another Plugin
+/// `define_plugin!(MyPhysics wraps PhysicsPlugin::default() { ... })` adds the
+/// wrapped plugin expression first (after the `depends_on` check), then applies
+/// the rest of the configuration, so a third-party plugin and your own
+/// resources and systems can be added as one unit.
 ///
 /// ### Custom Logic
 /// - `custom_build: |app| { ... }` - Custom build logic
 /// - `custom_finish: |app| { ... }` - Custom finish logic
+/// - `remove_resource: [LoadingState]` - Remove resources in `finish()`, e.g.
+///   temporary resources only needed while the app is being built
+/// - `finish_init_resource: { Atlas => |world| Atlas::from(world.resource::<Tiles>()) }` -
+///   Insert a resource computed in `finish()`, once every plugin has been built,
+///   so it can be derived from resources added by dependency plugins
+/// - `assert_resources_present: [GameSettings, PlayerStats]` - Panic in
+///   `finish()`, naming the resource, if any listed resource is missing once
+///   every plugin has been built. Checked where the key appears among the
+///   other `finish()` keys
+///
+/// `custom_build` and `custom_finish` also accept a named function taking
+/// `&mut App`, such as `custom_build: setup_rendering` or
+/// `custom_finish: hooks::finish`.
+///
+/// `build()` applies the configuration in a fixed order, whatever order the
+/// keys are written in: sub-plugins, resources, messages, states, reflected
+/// types and schedules first, then sub-states (so `add_sub_state` always
+/// follows the `init_state` of its source state), then systems and the
+/// remaining keys, then `custom_build`. Keys in the same group keep their
+/// written order.
+///
+/// ### Uniqueness
+/// - `is_unique: false` - Override `Plugin::is_unique()` so the plugin can be
+///   added to an App more than once (Bevy panics on a second add by default).
+///   Every added instance runs `build()`, so its systems are added again
+///
+/// ### Diagnostics
+/// - `warn_if_empty: true` - Log a warning from `build()` if the plugin declares
+///   no systems and no resources (requires the `introspection` feature)
+/// - `warn_if_transitions_without_states: true` - Log a warning from `build()`
+///   if the plugin adds `OnEnter`/`OnExit` systems but initializes no states,
+///   for plugins that are expected to own their states (requires the
+///   `introspection` feature)
+/// - `generate_debug_hud: true` - Register the plugin in the `PluginRegistry`
+///   resource and refresh a `PluginDebugReport` text resource every frame
+///   (requires the `introspection` feature)
+/// - `generate_reflect_report: true` - Register the plugin in the
+///   `PluginRegistry` resource and snapshot the registry into a reflectable
+///   `ReflectablePluginReport` resource in `finish()`, for remote inspection
+///   (requires the `reflect` feature)
+/// - `impl_debug: true` - Implement `Debug` for the plugin struct, printing its
+///   name and registration counts from its metadata, e.g.
+///   `MyPlugin { resources: 2, messages: 0, ... }` (requires the
+///   `introspection` feature)
+/// - `metadata_const_name: MY_PLUGIN_METADATA` - Declare
+///   `pub const MY_PLUGIN_METADATA: &PluginMetadata` next to the plugin, usable
+///   in const contexts such as `const _: () = assert!(...)` (requires the
+///   `introspection` feature)
+/// - `lint_custom_build: true` - Warn (as a deprecation warning) about calls
+///   in `custom_build` closures that a declarative key covers, such as
+///   `app.init_resource::<T>()` or `app.add_systems(...)`. Calls are matched by
+///   method name only, so e.g. `commands.insert_resource(...)` is flagged too
+/// - `trace_registrations: true` - Log each resource, message, state, type and
+///   system with `trace!` as `build()` registers it, e.g.
+///   `MyPlugin: init_resource GameSettings`
+/// - `dry_run: true` - Log each registration with `info!` instead of making
+///   it, e.g. `MyPlugin: (dry run) init_resource GameSettings`. Nothing is
+///   added to the app: `custom_build` and `finish()` are skipped as well,
+///   while dependency checks still run
 ///
 /// ## Example
 ///
@@ -88,9 +352,35 @@
 /// ```
 #[macro_export]
 macro_rules! define_plugin {
+    // cfg-gated dependencies: expand the plugin once with and once without the
+    // depends_on block, keeping whichever the predicate selects
+    ($(#[$attr:meta])* $plugin_name:ident {
+        #[cfg($pred:meta)]
+        depends_on: [$($dep:ty),* $(,)?]
+        $(, $($rest:tt)*)?
+    }) => {
+        #[cfg($pred)]
+        $crate::define_plugin!($(#[$attr])* $plugin_name { depends_on: [$($dep),*] $(, $($rest)*)? });
+        #[cfg(not($pred))]
+        $crate::define_plugin!($(#[$attr])* $plugin_name { $($($rest)*)? });
+    };
+
+    // Wrapper around an existing plugin: `MyPlugin wraps OtherPlugin::default() { ... }`
+    ($(#[$attr:meta])* $plugin_name:ident wraps $($wrapped:tt)+) => {
+        $crate::define_plugin_wraps!({ $(#[$attr])* } $plugin_name [] $($wrapped)+);
+    };
+
     // Main entry point - delegates to internal implementation
-    ($plugin_name:ident { $($config:tt)* }) => {
-        $crate::define_plugin_impl!($plugin_name { $($config)* });
+    ($(#[$attr:meta])* $plugin_name:ident { $($config:tt)* }) => {
+        // Attributes written before the name (derives, docs) go on the struct
+        $crate::define_plugin_struct!({ $(#[$attr])* } $plugin_name { $($config)* });
+        $crate::define_plugin_constructors!($plugin_name { $($config)* });
+
+        $crate::define_plugin_order_config!($plugin_name [] [] [] [] [] , $($config)*);
+        // Declare the plugin's named system set if requested
+        $crate::define_plugin_system_set!($plugin_name { $($config)* });
+        // Implement Debug from the metadata if requested
+        $crate::define_plugin_debug_scan!($plugin_name { $($config)* });
         // Generate metadata when introspection feature is enabled
         $crate::define_plugin_metadata!($plugin_name { $($config)* });
         // Generate tests when testing feature is enabled
@@ -98,54 +388,294 @@ macro_rules! define_plugin {
     };
 }
 
+/// Define several plugins in one invocation.
+///
+/// Each `Name { ... }` block is passed to `define_plugin!` unchanged, so every
+/// configuration key and struct attribute works the same way. The `wraps`
+/// form still needs its own `define_plugin!` call.
+///
+/// ```rust
+/// use bevy_plugin_builder::define_plugins;
+/// use bevy::prelude::*;
+///
+/// #[derive(Resource, Default)]
+/// struct Score;
+/// #[derive(Resource, Default)]
+/// struct Lives;
+///
+/// define_plugins! {
+///     ScorePlugin {
+///         init_resource: [Score]
+///     }
+///
+///     /// Tracks the player's remaining lives
+///     LivesPlugin {
+///         init_resource: [Lives]
+///     }
+/// }
+///
+/// App::new().add_plugins((ScorePlugin, LivesPlugin));
+/// ```
+#[macro_export]
+macro_rules! define_plugins {
+    ($($(#[$attr:meta])* $plugin_name:ident { $($config:tt)* })*) => {
+        $($crate::define_plugin!($(#[$attr])* $plugin_name { $($config)* });)*
+    };
+}
+
+/// Split `wraps <expr> { config }` into the wrapped plugin expression and the
+/// configuration, then add the wrapped plugin before everything else.
+/// The expression is collected one token at a time until only the
+/// configuration block is left.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_wraps {
+    // depends_on stays first so it is still checked before anything is added
+    ({ $($attrs:tt)* } $plugin_name:ident [$($wrapped:tt)+] {
+        $(#[cfg($pred:meta)])?
+        depends_on: [$($dep:ty),* $(,)?]
+        $(, $($rest:tt)*)?
+    }) => {
+        $crate::define_plugin!($($attrs)* $plugin_name {
+            $(#[cfg($pred)])?
+            depends_on: [$($dep),*],
+            add_plugins: [$($wrapped)+]
+            $(, $($rest)*)?
+        });
+    };
+
+    ({ $($attrs:tt)* } $plugin_name:ident [$($wrapped:tt)+] {}) => {
+        $crate::define_plugin!($($attrs)* $plugin_name { add_plugins: [$($wrapped)+] });
+    };
+
+    ({ $($attrs:tt)* } $plugin_name:ident [$($wrapped:tt)+] { $($config:tt)+ }) => {
+        $crate::define_plugin!($($attrs)* $plugin_name { add_plugins: [$($wrapped)+], $($config)+ });
+    };
+
+    // Move the next token of the wrapped plugin expression into the accumulator
+    ({ $($attrs:tt)* } $plugin_name:ident [$($wrapped:tt)*] $next:tt $($rest:tt)+) => {
+        $crate::define_plugin_wraps!({ $($attrs)* } $plugin_name [$($wrapped)* $next] $($rest)+);
+    };
+
+    ({ $($attrs:tt)* } $plugin_name:ident [$($wrapped:tt)*] $($rest:tt)*) => {
+        compile_error!("wraps expects `define_plugin!(MyPlugin wraps WrappedPlugin { ... })`");
+    };
+}
+
+/// Put the configuration in the order `build()` applies it, whatever order
+/// the keys are written in: every `depends_on:` block first, merged into one
+/// list, then sub-plugins, resources, messages, states, reflected types and
+/// schedules, then sub-states, then systems and everything else, then
+/// `custom_build`. Keys in the same group keep their written order.
+///
+/// Entries are moved a key-value pair at a time where possible to keep the
+/// recursion shallow, into the bucket define_plugin_order_group! looks up for
+/// the key; anything else (attributes, multi-token values) is moved
+/// one token at a time. `custom_build` values are matched whole, keeping the
+/// closure tokens that define_plugin_lint_scan! and
+/// define_plugin_build_with_plugin! look for.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_order_config {
+    (@impl $plugin_name:ident , $($config:tt)*) => {
+        $crate::define_plugin_impl!($plugin_name { $($config)* });
+    };
+
+    (@impl $plugin_name:ident) => {
+        $crate::define_plugin_impl!($plugin_name {});
+    };
+
+    // A key-value pair whose bucket define_plugin_order_group! looked up
+    (@push regs $plugin_name:ident [$($deps:ty,)*] [$($regs:tt)*] [$($sub_states:tt)*] [$($systems:tt)*] [$($custom:tt)*] , $key:ident : $value:tt $($rest:tt)*) => {
+        $crate::define_plugin_order_config!($plugin_name [$($deps,)*] [$($regs)* , $key : $value] [$($sub_states)*] [$($systems)*] [$($custom)*] $($rest)*);
+    };
+    (@push sub_states $plugin_name:ident [$($deps:ty,)*] [$($regs:tt)*] [$($sub_states:tt)*] [$($systems:tt)*] [$($custom:tt)*] , $key:ident : $value:tt $($rest:tt)*) => {
+        $crate::define_plugin_order_config!($plugin_name [$($deps,)*] [$($regs)*] [$($sub_states)* , $key : $value] [$($systems)*] [$($custom)*] $($rest)*);
+    };
+    (@push systems $plugin_name:ident [$($deps:ty,)*] [$($regs:tt)*] [$($sub_states:tt)*] [$($systems:tt)*] [$($custom:tt)*] , $key:ident : $value:tt $($rest:tt)*) => {
+        $crate::define_plugin_order_config!($plugin_name [$($deps,)*] [$($regs)*] [$($sub_states)*] [$($systems)* , $key : $value] [$($custom)*] $($rest)*);
+    };
+
+    ($plugin_name:ident [$($deps:ty,)*] [$($regs:tt)*] [$($sub_states:tt)*] [$($systems:tt)*] [$($custom:tt)*] , depends_on: [$($dep:ty),* $(,)?] $($rest:tt)*) => {
+        $crate::define_plugin_order_config!($plugin_name [$($deps,)* $($dep,)*] [$($regs)*] [$($sub_states)*] [$($systems)*] [$($custom)*] $($rest)*);
+    };
+
+    // custom_build, last
+    ($plugin_name:ident [$($deps:ty,)*] [$($regs:tt)*] [$($sub_states:tt)*] [$($systems:tt)*] [$($custom:tt)*] , custom_build : |$($arg:ident $(: $ty:ty)?),*| { $($body:tt)* } $($rest:tt)*) => {
+        $crate::define_plugin_order_config!($plugin_name [$($deps,)*] [$($regs)*] [$($sub_states)*] [$($systems)*] [$($custom)* , custom_build : |$($arg $(: $ty)?),*| { $($body)* }] $($rest)*);
+    };
+
+    ($plugin_name:ident [$($deps:ty,)*] [$($regs:tt)*] [$($sub_states:tt)*] [$($systems:tt)*] [$($custom:tt)*] , custom_build : |$app_arg:ident $(: $app_ty:ty)?, $plugin_arg:ident $(: $plugin_ty:ty)?| $body:expr $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_order_config!($plugin_name [$($deps,)*] [$($regs)*] [$($sub_states)*] [$($systems)*] [$($custom)* , custom_build : |$app_arg $(: $app_ty)?, $plugin_arg $(: $plugin_ty)?| $body] $(, $($rest)*)?);
+    };
+
+    ($plugin_name:ident [$($deps:ty,)*] [$($regs:tt)*] [$($sub_states:tt)*] [$($systems:tt)*] [$($custom:tt)*] , custom_build : $build_fn:expr $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_order_config!($plugin_name [$($deps,)*] [$($regs)*] [$($sub_states)*] [$($systems)*] [$($custom)* , custom_build : $build_fn] $(, $($rest)*)?);
+    };
+
+    // Any other key-value pair goes to the bucket its key belongs in
+    ($plugin_name:ident [$($deps:ty,)*] [$($regs:tt)*] [$($sub_states:tt)*] [$($systems:tt)*] [$($custom:tt)*] , $key:ident : $value:tt $($rest:tt)*) => {
+        $crate::define_plugin_order_group!($key, $plugin_name [$($deps,)*] [$($regs)*] [$($sub_states)*] [$($systems)*] [$($custom)*] , $key : $value $($rest)*);
+    };
+
+    // Trailing comma
+    ($plugin_name:ident [$($deps:ty,)*] [$($regs:tt)*] [$($sub_states:tt)*] [$($systems:tt)*] [$($custom:tt)*] ,) => {
+        $crate::define_plugin_order_config!($plugin_name [$($deps,)*] [$($regs)*] [$($sub_states)*] [$($systems)*] [$($custom)*]);
+    };
+
+    ($plugin_name:ident [$($deps:ty,)*] [$($regs:tt)*] [$($sub_states:tt)*] [$($systems:tt)*] [$($custom:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::define_plugin_order_config!($plugin_name [$($deps,)*] [$($regs)*] [$($sub_states)*] [$($systems)* $next] [$($custom)*] $($rest)*);
+    };
+
+    ($plugin_name:ident [] [$($regs:tt)*] [$($sub_states:tt)*] [$($systems:tt)*] [$($custom:tt)*]) => {
+        $crate::define_plugin_order_config!(@impl $plugin_name $($regs)* $($sub_states)* $($systems)* $($custom)*);
+    };
+
+    ($plugin_name:ident [$($deps:ty,)+] [$($regs:tt)*] [$($sub_states:tt)*] [$($systems:tt)*] [$($custom:tt)*]) => {
+        $crate::define_plugin_impl!($plugin_name { depends_on: [$($deps),+] $($regs)* $($sub_states)* $($systems)* $($custom)* });
+    };
+}
+
+/// Look up which define_plugin_order_config! bucket a key belongs in and hand
+/// the pair back to it: registrations, sub-states (after the other
+/// registrations, so their source states exist), or systems and everything
+/// else.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_order_group {
+    (add_plugins, $($args:tt)*) => { $crate::define_plugin_order_config!(@push regs $($args)*); };
+    (add_plugins_configured, $($args:tt)*) => { $crate::define_plugin_order_config!(@push regs $($args)*); };
+    (meta, $($args:tt)*) => { $crate::define_plugin_order_config!(@push regs $($args)*); };
+    (depends_on_bevy, $($args:tt)*) => { $crate::define_plugin_order_config!(@push regs $($args)*); };
+    (init_resource, $($args:tt)*) => { $crate::define_plugin_order_config!(@push regs $($args)*); };
+    (insert_resource, $($args:tt)*) => { $crate::define_plugin_order_config!(@push regs $($args)*); };
+    (insert_resource_if_absent, $($args:tt)*) => { $crate::define_plugin_order_config!(@push regs $($args)*); };
+    (init_resource_with, $($args:tt)*) => { $crate::define_plugin_order_config!(@push regs $($args)*); };
+    (inspectable_resources, $($args:tt)*) => { $crate::define_plugin_order_config!(@push regs $($args)*); };
+    (add_message, $($args:tt)*) => { $crate::define_plugin_order_config!(@push regs $($args)*); };
+    (add_message_with, $($args:tt)*) => { $crate::define_plugin_order_config!(@push regs $($args)*); };
+    (add_message_buffered, $($args:tt)*) => { $crate::define_plugin_order_config!(@push regs $($args)*); };
+    (init_state, $($args:tt)*) => { $crate::define_plugin_order_config!(@push regs $($args)*); };
+    (register_type, $($args:tt)*) => { $crate::define_plugin_order_config!(@push regs $($args)*); };
+    (register_components, $($args:tt)*) => { $crate::define_plugin_order_config!(@push regs $($args)*); };
+    (init_schedule, $($args:tt)*) => { $crate::define_plugin_order_config!(@push regs $($args)*); };
+    (add_sub_state, $($args:tt)*) => { $crate::define_plugin_order_config!(@push sub_states $($args)*); };
+    ($key:ident, $($args:tt)*) => { $crate::define_plugin_order_config!(@push systems $($args)*); };
+}
+
 /// Internal implementation macro that handles the actual code generation.
 /// This separates the entry point from the implementation details.
 #[macro_export]
 #[doc(hidden)]
 macro_rules! define_plugin_impl {
-    // Case 1: Plugin WITH dependencies (depends_on must be first if present)
+    // Case 1: Plugin WITH dependencies (moved first by define_plugin_order_config!)
     ($plugin_name:ident {
         depends_on: [$($dep:ty),* $(,)?]
         $(, $($rest:tt)*)?
     }) => {
-        pub struct $plugin_name;
-
         // PluginMarker trait - enables compile-time dependency checking
         impl $crate::PluginMarker for $plugin_name {
             type Id = $plugin_name;
         }
 
+        // Compile-time check: verify dependency types implement PluginMarker
+        const _: () = {
+            $($crate::assert_plugin_marker::<$dep>();)*
+        };
+
         // PluginDependencies trait - declares what this plugin requires
-        impl $crate::PluginDependencies for $plugin_name {
+        // Used by the generated test_app_boots test
+        $crate::define_plugin_if_testing! {
+            impl $crate::BootTestPlugin for $plugin_name
+            where
+                for<'a> $plugin_name: $crate::BootTestInstance,
+                $(for<'a> $dep: $crate::BootTestPlugin,)*
+            {
+                fn add_for_boot_test(app: &mut ::bevy::prelude::App) {
+                    $(<$dep as $crate::BootTestPlugin>::add_for_boot_test(app);)*
+                    if app.get_added_plugins::<Self>().is_empty() {
+                        app.add_plugins(<Self as $crate::BootTestInstance>::boot_test_instance());
+                    }
+                }
+            }
+        }
+
+        // Higher-ranked bounds defer the PluginSet check on Required, so a
+        // non-plugin dependency is reported by assert_plugin_marker above
+        impl $crate::PluginDependencies for $plugin_name
+        where
+            $(for<'a> $dep: $crate::PluginMarker,)*
+        {
             type Required = ($($dep,)*);
+
+            const DEPENDENCY_TYPE_IDS: &'static [fn() -> ::std::any::TypeId] =
+                &[$(::std::any::TypeId::of::<$dep>,)*];
+        }
+
+        impl $plugin_name {
+            // Read by the registration arms of define_plugin_internal!
+            #[allow(dead_code)]
+            const TRACE_REGISTRATIONS: bool =
+                $crate::define_plugin_trace_enabled!({ $($($rest)*)? });
+            const DRY_RUN: bool = $crate::define_plugin_dry_run_enabled!({ $($($rest)*)? });
+            #[allow(dead_code)]
+            const CHAIN_STARTUP: bool =
+                $crate::define_plugin_chain_startup_enabled!({ $($($rest)*)? });
+            #[allow(dead_code)]
+            const AUTO_REFLECT_RESOURCES: bool =
+                $crate::define_plugin_auto_reflect_enabled!({ $($($rest)*)? });
+            // meta: display_name, read by the introspection metadata
+            #[doc(hidden)]
+            #[allow(dead_code)]
+            pub const __DISPLAY_NAME: ::std::option::Option<&'static str> =
+                $crate::define_plugin_display_name!($plugin_name { $($($rest)*)? });
         }
 
         impl ::bevy::prelude::Plugin for $plugin_name {
             fn build(&self, app: &mut ::bevy::prelude::App) {
-                // Compile-time check: verify dependency types implement PluginMarker
-                $(
-                    let _: <$dep as $crate::PluginMarker>::Id;
-                )*
+                // Runtime check: verify dependencies were added in correct order,
+                // reporting every missing dependency at once
+                {
+                    use $crate::{SkipDependencyCheck as _, VerifyDependencyCheck as _};
+                    if let Err(errors) = (&$crate::DependencyCheck::<Self>(::std::marker::PhantomData)).verify_declared(app) {
+                        $crate::define_plugin_dependency_failure!(errors { $($($rest)*)? });
+                    }
+                }
+
+                $crate::define_plugin_introspection_checks!(Self);
 
-                // Runtime check: verify dependencies were added in correct order
-                if let Err(e) = <Self as $crate::PluginDependencies>::verify_dependencies(app) {
-                    panic!("{}", e);
+                // settings_resource: built from the plugin's fields
+                $crate::define_plugin_settings_scan!(self, app { $($($rest)*)? } { $($($rest)*)? });
+
+                // custom_build closures that take the plugin itself
+                if !Self::DRY_RUN {
+                    $crate::define_plugin_build_with_plugin!(self, app { $($($rest)*)? });
                 }
 
+                // lint_custom_build: true
+                $crate::define_plugin_lint_scan!({ $($($rest)*)? } { $($($rest)*)? });
+
                 // Process remaining configuration
                 $crate::define_plugin_internal!(app, $($($rest)*)?);
             }
 
             fn finish(&self, app: &mut ::bevy::prelude::App) {
-                $crate::define_plugin_finish!(app, $($($rest)*)?);
+                if !Self::DRY_RUN {
+                    $crate::define_plugin_finish!(app, $($($rest)*)?);
+                    $crate::define_plugin_introspection_finish_checks!(Self, app);
+                }
+            }
+
+            // name() is left at Bevy's type-path default: App::is_plugin_added
+            // and the unique-plugin check compare plugin names against it
+            fn is_unique(&self) -> bool {
+                $crate::define_plugin_is_unique!({ $($($rest)*)? })
             }
         }
     };
 
     // Case 2: Plugin WITHOUT dependencies (backward compatible)
     ($plugin_name:ident { $($config:tt)* }) => {
-        pub struct $plugin_name;
-
         // PluginMarker trait - all plugins get this for dependency checking
         impl $crate::PluginMarker for $plugin_name {
             type Id = $plugin_name;
@@ -156,13 +686,60 @@ macro_rules! define_plugin_impl {
             type Required = ();
         }
 
+        $crate::define_plugin_if_testing! {
+            impl $crate::BootTestPlugin for $plugin_name
+            where
+                for<'a> $plugin_name: $crate::BootTestInstance,
+            {
+                fn add_for_boot_test(app: &mut ::bevy::prelude::App) {
+                    if app.get_added_plugins::<Self>().is_empty() {
+                        app.add_plugins(<Self as $crate::BootTestInstance>::boot_test_instance());
+                    }
+                }
+            }
+        }
+
+        impl $plugin_name {
+            // Read by the registration arms of define_plugin_internal!
+            #[allow(dead_code)]
+            const TRACE_REGISTRATIONS: bool = $crate::define_plugin_trace_enabled!({ $($config)* });
+            const DRY_RUN: bool = $crate::define_plugin_dry_run_enabled!({ $($config)* });
+            #[allow(dead_code)]
+            const CHAIN_STARTUP: bool = $crate::define_plugin_chain_startup_enabled!({ $($config)* });
+            #[allow(dead_code)]
+            const AUTO_REFLECT_RESOURCES: bool =
+                $crate::define_plugin_auto_reflect_enabled!({ $($config)* });
+            // meta: display_name, read by the introspection metadata
+            #[doc(hidden)]
+            #[allow(dead_code)]
+            pub const __DISPLAY_NAME: ::std::option::Option<&'static str> =
+                $crate::define_plugin_display_name!($plugin_name { $($config)* });
+        }
+
         impl ::bevy::prelude::Plugin for $plugin_name {
             fn build(&self, app: &mut ::bevy::prelude::App) {
+                $crate::define_plugin_introspection_checks!(Self);
+                $crate::define_plugin_settings_scan!(self, app { $($config)* } { $($config)* });
+                if !Self::DRY_RUN {
+                    $crate::define_plugin_build_with_plugin!(self, app { $($config)* });
+                }
+
+                // lint_custom_build: true
+                $crate::define_plugin_lint_scan!({ $($config)* } { $($config)* });
                 $crate::define_plugin_internal!(app, $($config)*);
             }
 
             fn finish(&self, app: &mut ::bevy::prelude::App) {
-                $crate::define_plugin_finish!(app, $($config)*);
+                if !Self::DRY_RUN {
+                    $crate::define_plugin_finish!(app, $($config)*);
+                    $crate::define_plugin_introspection_finish_checks!(Self, app);
+                }
+            }
+
+            // name() is left at Bevy's type-path default: App::is_plugin_added
+            // and the unique-plugin check compare plugin names against it
+            fn is_unique(&self) -> bool {
+                $crate::define_plugin_is_unique!({ $($config)* })
             }
         }
     };
@@ -181,6 +758,7 @@ macro_rules! define_plugin_internal {
     // ========================================================================
 
     ($app:ident, meta: { $($meta:tt)* } $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_meta!($app, $($meta)*);
         $crate::define_plugin_internal!($app, $($($rest)*)?);
     };
 
@@ -188,6 +766,86 @@ macro_rules! define_plugin_internal {
         $crate::define_plugin_internal!($app, $($($rest)*)?);
     };
 
+    // dependency_check: (applied by define_plugin_dependency_failure!)
+    ($app:ident, dependency_check: panic $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    ($app:ident, dependency_check: warn $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    ($app:ident, dependency_check: $mode:tt $(, $($rest:tt)*)?) => {
+        compile_error!(concat!(
+            "Unknown dependency_check mode: ",
+            stringify!($mode),
+            "\nSupported modes: panic, warn"
+        ));
+    };
+
+    // settings_resource: (skip in build, handled by define_plugin_settings_scan!)
+    ($app:ident, settings_resource: $settings:path $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    // fields: and constructors: (generate the struct, nothing to build)
+    ($app:ident, fields: { $($field:ident : $ty:ty),* $(,)? } $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    ($app:ident, constructors: { $($constructor:ident($($arg:ident : $arg_ty:ty),* $(,)?)),* $(,)? } $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    // depends_on_bevy: (Bevy's own plugins, checked when this entry is built)
+    ($app:ident, depends_on_bevy: [$($plugin:ty),* $(,)?] $(, $($rest:tt)*)?) => {
+        $(
+            $crate::assert_bevy_plugin_added::<Self, $plugin>($app);
+        )*
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    // requires_bevy_features: (skip in build, checked in finish)
+    ($app:ident, requires_bevy_features: [$($feature:literal),* $(,)?] $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    // trace_registrations: (read through the plugin's TRACE_REGISTRATIONS const)
+    ($app:ident, trace_registrations: true $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    ($app:ident, trace_registrations: false $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    // chain_startup: (read through the plugin's CHAIN_STARTUP const)
+    ($app:ident, chain_startup: true $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    ($app:ident, chain_startup: false $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    // dry_run: (read through the plugin's DRY_RUN const)
+    ($app:ident, dry_run: true $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    ($app:ident, dry_run: false $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    // is_unique: (read by define_plugin_is_unique! for Plugin::is_unique)
+    ($app:ident, is_unique: true $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    ($app:ident, is_unique: false $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
     // ========================================================================
     // NEW Bevy-aligned syntax
     // ========================================================================
@@ -195,7 +853,19 @@ macro_rules! define_plugin_internal {
     // init_resource: (new name for resources:)
     ($app:ident, init_resource: [$($resource:ty),* $(,)?] $(, $($rest:tt)*)?) => {
         $(
-            $app.init_resource::<$resource>();
+            $crate::define_plugin_trace!(init_resource, $resource);
+            if !Self::DRY_RUN {
+                $app.init_resource::<$resource>();
+            }
+            // auto_reflect_resources: true
+            if Self::AUTO_REFLECT_RESOURCES {
+                $crate::define_plugin_trace!(auto_reflect_resources, $resource);
+                if !Self::DRY_RUN {
+                    use $crate::{RegisterReflectedResource as _, SkipReflectedResource as _};
+                    (&$crate::AutoReflect::<$resource>(::std::marker::PhantomData))
+                        .register_resource_type($app);
+                }
+            }
         )*
         $crate::define_plugin_internal!($app, $($($rest)*)?);
     };
@@ -203,388 +873,3030 @@ macro_rules! define_plugin_internal {
     // insert_resource: (new - insert resources with values)
     ($app:ident, insert_resource: [$($resource:expr),* $(,)?] $(, $($rest:tt)*)?) => {
         $(
-            $app.insert_resource($resource);
+            $crate::define_plugin_trace!(insert_resource, $resource);
+            if !Self::DRY_RUN {
+                $app.insert_resource($resource);
+            }
         )*
         $crate::define_plugin_internal!($app, $($($rest)*)?);
     };
 
-    // add_message: (Bevy 0.17+ uses Messages instead of Events)
-    ($app:ident, add_message: [$($message:ty),* $(,)?] $(, $($rest:tt)*)?) => {
+    // insert_resource_if_absent: (keep a value another plugin already inserted)
+    ($app:ident, insert_resource_if_absent: { $($resource:ty => $value:expr),* $(,)? } $(, $($rest:tt)*)?) => {
         $(
-            $app.add_message::<$message>();
+            if !$app.world().contains_resource::<$resource>() {
+                $crate::define_plugin_trace!(insert_resource_if_absent, $resource);
+                if !Self::DRY_RUN {
+                    $app.insert_resource::<$resource>($value);
+                }
+            }
         )*
         $crate::define_plugin_internal!($app, $($($rest)*)?);
     };
 
-    // add_plugins: (new name for plugins:)
-    ($app:ident, add_plugins: [$($plugin:expr),* $(,)?] $(, $($rest:tt)*)?) => {
+    // init_resource_with: (value computed from the world, e.g. other resources)
+    ($app:ident, init_resource_with: { $($resource:ty => $init:expr),* $(,)? } $(, $($rest:tt)*)?) => {
         $(
-            $app.add_plugins($plugin);
+            $crate::define_plugin_trace!(init_resource_with, $resource);
+            if !Self::DRY_RUN {
+                $crate::init_resource_with::<$resource>($app.world_mut(), $init);
+            }
         )*
         $crate::define_plugin_internal!($app, $($($rest)*)?);
     };
 
-    // init_state: (new name for states:)
-    ($app:ident, init_state: [$($state:ty),* $(,)?] $(, $($rest:tt)*)?) => {
+    // inspectable_resources: (init_resource + register_type for inspector tools)
+    ($app:ident, inspectable_resources: [$($resource:ty),* $(,)?] $(, $($rest:tt)*)?) => {
         $(
-            $app.init_state::<$state>();
+            $crate::define_plugin_trace!(inspectable_resources, $resource);
+            if !Self::DRY_RUN {
+                $app.init_resource::<$resource>();
+                $app.register_type::<$resource>();
+            }
         )*
         $crate::define_plugin_internal!($app, $($($rest)*)?);
     };
 
-    // add_sub_state: (new name for sub_states:)
-    ($app:ident, add_sub_state: [$($state:ty),* $(,)?] $(, $($rest:tt)*)?) => {
+    // add_message: (Bevy 0.17+ uses Messages instead of Events)
+    ($app:ident, add_message: [$($message:ty),* $(,)?] $(, $($rest:tt)*)?) => {
         $(
-            $app.add_sub_state::<$state>();
+            $crate::define_plugin_trace!(add_message, $message);
+            if !Self::DRY_RUN {
+                $app.add_message::<$message>();
+            }
         )*
         $crate::define_plugin_internal!($app, $($($rest)*)?);
     };
 
-    // register_type: (new name for reflect:)
-    ($app:ident, register_type: [$($reflect_type:ty),* $(,)?] $(, $($rest:tt)*)?) => {
+    // add_message_with: (messages with an update policy)
+    ($app:ident, add_message_with: { $($message:ty => $policy:ident),* $(,)? } $(, $($rest:tt)*)?) => {
         $(
-            $app.register_type::<$reflect_type>();
+            $crate::define_plugin_trace!(add_message_with, $message);
+            if !Self::DRY_RUN {
+                $crate::define_plugin_message_policy!($app, $message, $policy);
+            }
         )*
         $crate::define_plugin_internal!($app, $($($rest)*)?);
     };
 
-    // add_systems_startup: (new name for startup:)
-    ($app:ident, add_systems_startup: [$($system:expr),* $(,)?] $(, $($rest:tt)*)?) => {
-        $app.add_systems(
-            ::bevy::prelude::Startup,
-            ($($system,)*)
-        );
+    // add_message_buffered: (messages kept for a number of updates)
+    ($app:ident, add_message_buffered: { $($message:ty => $frames:literal),* $(,)? } $(, $($rest:tt)*)?) => {
+        $(
+            $crate::define_plugin_trace!(add_message_buffered, $message);
+            if !Self::DRY_RUN {
+                $crate::add_buffered_message::<$message>($app, $frames);
+            }
+        )*
         $crate::define_plugin_internal!($app, $($($rest)*)?);
     };
 
-    // add_systems_update: (new name for update:)
-    ($app:ident, add_systems_update: [$($system:expr),* $(,)?] $(, $($rest:tt)*)?) => {
-        $app.add_systems(
-            ::bevy::prelude::Update,
-            ($($system,)*)
-        );
+    // add_plugins: (new name for plugins:)
+    ($app:ident, add_plugins: [$($plugin:expr),* $(,)?] $(, $($rest:tt)*)?) => {
+        $(
+            $crate::define_plugin_trace!(add_plugins, $plugin);
+            if !Self::DRY_RUN {
+                $app.add_plugins($plugin);
+            }
+        )*
         $crate::define_plugin_internal!($app, $($($rest)*)?);
     };
 
-    // add_systems_fixed_update: (new name for fixed_update:)
-    ($app:ident, add_systems_fixed_update: [$($system:expr),* $(,)?] $(, $($rest:tt)*)?) => {
-        $app.add_systems(
-            ::bevy::prelude::FixedUpdate,
-            ($($system,)*)
-        );
+    // add_plugins_configured: (a plugin group adjusted through its PluginGroupBuilder)
+    ($app:ident, add_plugins_configured: { $($group:expr => $(.$method:ident $(::<$($generic:ty),* $(,)?>)? ($($args:tt)*))+),* $(,)? } $(, $($rest:tt)*)?) => {
+        $(
+            $crate::define_plugin_trace!(add_plugins_configured, $group);
+            if !Self::DRY_RUN {
+                $app.add_plugins(
+                    ::bevy::app::PluginGroup::build($group)$(.$method$(::<$($generic),*>)?($($args)*))+
+                );
+            }
+        )*
         $crate::define_plugin_internal!($app, $($($rest)*)?);
     };
 
-    // add_systems_on_enter: (new name for on_enter:)
-    ($app:ident, add_systems_on_enter: { $($state:expr => [$($system:expr),* $(,)?]),* $(,)? } $(, $($rest:tt)*)?) => {
+    // init_state: (new name for states:)
+    ($app:ident, init_state: [$($state:ty),* $(,)?] $(, $($rest:tt)*)?) => {
         $(
-            $app.add_systems(
-                ::bevy::prelude::OnEnter($state),
-                ($($system,)*)
-            );
+            $crate::define_plugin_trace!(init_state, $state);
+            if !Self::DRY_RUN {
+                <$state as $crate::InitStateEntry>::init_state($app);
+            }
         )*
         $crate::define_plugin_internal!($app, $($($rest)*)?);
     };
 
-    // add_systems_on_exit: (new name for on_exit:)
-    ($app:ident, add_systems_on_exit: { $($state:expr => [$($system:expr),* $(,)?]),* $(,)? } $(, $($rest:tt)*)?) => {
+    // add_sub_state: (new name for sub_states:)
+    ($app:ident, add_sub_state: [$($state:ty),* $(,)?] $(, $($rest:tt)*)?) => {
         $(
-            $app.add_systems(
-                ::bevy::prelude::OnExit($state),
-                ($($system,)*)
-            );
+            $crate::define_plugin_trace!(add_sub_state, $state);
+            if !Self::DRY_RUN {
+                <$state as $crate::SubStateEntry>::add_sub_state($app);
+            }
         )*
         $crate::define_plugin_internal!($app, $($($rest)*)?);
     };
 
-    // custom_build: (new name for custom_init:)
-    ($app:ident, custom_build: $build_fn:expr $(, $($rest:tt)*)?) => {
-        $build_fn($app);
-        $crate::define_plugin_internal!($app, $($($rest)*)?);
-    };
+    // register_type: (new name for reflect:)
+    ($app:ident, register_type: [$($reflect_type:ty),* $(,)?] $(, $($rest:tt)*)?) => {
+        $(
+            $crate::define_plugin_trace!(register_type, $reflect_type);
+            if !Self::DRY_RUN {
+                $app.register_type::<$reflect_type>();
+            }
+        )*
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
 
-    // custom_finish: (skip in build, handled in finish)
-    ($app:ident, custom_finish: $finish_fn:expr $(, $($rest:tt)*)?) => {
+    // register_components: (register_type, recorded as components in metadata)
+    ($app:ident, register_components: [$($component:ty),* $(,)?] $(, $($rest:tt)*)?) => {
+        $(
+            const _: () = $crate::assert_component::<$component>();
+            $crate::define_plugin_trace!(register_components, $component);
+            if !Self::DRY_RUN {
+                $app.register_type::<$component>();
+            }
+        )*
         $crate::define_plugin_internal!($app, $($($rest)*)?);
     };
 
-    // generate_tests: (skip in build, handled by separate macro)
-    ($app:ident, generate_tests: { $($test_config:tt)* } $(, $($rest:tt)*)?) => {
+    // add_observers: (global observers)
+    ($app:ident, add_observers: [$($observer:expr),* $(,)?] $(, $($rest:tt)*)?) => {
+        $(
+            $crate::define_plugin_trace!(add_observers, $observer);
+            if !Self::DRY_RUN {
+                $app.add_observer($observer);
+            }
+        )*
         $crate::define_plugin_internal!($app, $($($rest)*)?);
     };
 
-    // ========================================================================
-    // Error case - unrecognized configuration
-    // ========================================================================
+    // add_systems_startup: (new name for startup:)
+    ($app:ident, add_systems_startup: [$($systems:tt)*] $(, $($rest:tt)*)?) => {
+        if Self::CHAIN_STARTUP {
+            $crate::define_plugin_schedule_systems!(
+                $app, add_systems_startup, ::bevy::prelude::Startup, { .chain() }, [], [], [$($systems)*]
+            );
+        } else {
+            $crate::define_plugin_schedule_systems!(
+                $app, add_systems_startup, ::bevy::prelude::Startup, {}, [], [], [$($systems)*]
+            );
+        }
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
 
-    ($app:ident, $unknown:tt $($rest:tt)*) => {
-        compile_error!(concat!(
-            "Unknown plugin configuration option: ",
-            stringify!($unknown),
-            "\nSupported options: depends_on, meta, init_resource, insert_resource, add_message, add_plugins, init_state, add_sub_state, register_type, add_systems_startup, add_systems_update, add_systems_fixed_update, add_systems_on_enter, add_systems_on_exit, custom_build, custom_finish, generate_tests"
-        ));
+    // add_systems_update: (new name for update:)
+    ($app:ident, add_systems_update: [$($systems:tt)*] $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_schedule_systems!(
+            $app, add_systems_update, ::bevy::prelude::Update,
+            { .in_set($crate::PluginSystemSet::<Self>::new()) }, [], [], [$($systems)*]
+        );
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
     };
-}
 
-/// Macro for handling Plugin finish() method configuration
-#[macro_export]
-#[doc(hidden)]
-macro_rules! define_plugin_finish {
-    // Empty configuration (base case) - default finish does nothing
-    ($app:ident,) => {};
+    // add_systems_fixed_update: (new name for fixed_update:)
+    ($app:ident, add_systems_fixed_update: [$($systems:tt)*] $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_schedule_systems!(
+            $app, add_systems_fixed_update, ::bevy::prelude::FixedUpdate,
+            { .in_set($crate::PluginSystemSet::<Self>::new()) }, [], [], [$($systems)*]
+        );
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
 
-    // Skip all standard configurations (only process custom_finish)
-    ($app:ident, meta: { $($meta:tt)* } $(, $($rest:tt)*)?) => {
-        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    // add_systems_state_transition: (systems in the StateTransition schedule)
+    ($app:ident, add_systems_state_transition: [$($systems:tt)*] $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_schedule_systems!(
+            $app, add_systems_state_transition, ::bevy::prelude::StateTransition,
+            {}, [], [], [$($systems)*]
+        );
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
     };
-    ($app:ident, depends_on: [$($dep:ty),* $(,)?] $(, $($rest:tt)*)?) => {
-        $crate::define_plugin_finish!($app, $($($rest)*)?);
+
+    // add_systems_*_raw: (a single pre-built system config, passed through as-is)
+    ($app:ident, add_systems_startup_raw: $systems:expr $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_trace!(add_systems_startup_raw, $systems);
+        if !Self::DRY_RUN {
+            $app.add_systems(::bevy::prelude::Startup, $systems);
+        }
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
     };
-    ($app:ident, init_resource: [$($resource:ty),* $(,)?] $(, $($rest:tt)*)?) => {
-        $crate::define_plugin_finish!($app, $($($rest)*)?);
+
+    ($app:ident, add_systems_update_raw: $systems:expr $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_trace!(add_systems_update_raw, $systems);
+        if !Self::DRY_RUN {
+            $app.add_systems(
+                ::bevy::prelude::Update,
+                $systems.in_set($crate::PluginSystemSet::<Self>::new())
+            );
+        }
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
     };
-    ($app:ident, insert_resource: [$($resource:expr),* $(,)?] $(, $($rest:tt)*)?) => {
-        $crate::define_plugin_finish!($app, $($($rest)*)?);
+
+    ($app:ident, add_systems_fixed_update_raw: $systems:expr $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_trace!(add_systems_fixed_update_raw, $systems);
+        if !Self::DRY_RUN {
+            $app.add_systems(
+                ::bevy::prelude::FixedUpdate,
+                $systems.in_set($crate::PluginSystemSet::<Self>::new())
+            );
+        }
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
     };
-    ($app:ident, add_message: [$($message:ty),* $(,)?] $(, $($rest:tt)*)?) => {
-        $crate::define_plugin_finish!($app, $($($rest)*)?);
+
+    // with_conditions: (run conditions shared by the next system list)
+    ($app:ident, with_conditions: [$first:expr $(, $condition:expr)* $(,)?], add_systems_update: [$($system:expr),* $(,)?] $(, $($rest:tt)*)?) => {
+        $($crate::define_plugin_trace!(add_systems_update, $system);)*
+        if !Self::DRY_RUN {
+            $app.add_systems(
+                ::bevy::prelude::Update,
+                ($($system,)*)
+                    .in_set($crate::PluginSystemSet::<Self>::new())
+                    .run_if($first $(.and($condition))*)
+            );
+        }
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
     };
-    ($app:ident, add_plugins: [$($plugin:expr),* $(,)?] $(, $($rest:tt)*)?) => {
-        $crate::define_plugin_finish!($app, $($($rest)*)?);
+
+    ($app:ident, with_conditions: [$first:expr $(, $condition:expr)* $(,)?], add_systems_fixed_update: [$($system:expr),* $(,)?] $(, $($rest:tt)*)?) => {
+        $($crate::define_plugin_trace!(add_systems_fixed_update, $system);)*
+        if !Self::DRY_RUN {
+            $app.add_systems(
+                ::bevy::prelude::FixedUpdate,
+                ($($system,)*)
+                    .in_set($crate::PluginSystemSet::<Self>::new())
+                    .run_if($first $(.and($condition))*)
+            );
+        }
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
     };
-    ($app:ident, init_state: [$($state:ty),* $(,)?] $(, $($rest:tt)*)?) => {
-        $crate::define_plugin_finish!($app, $($($rest)*)?);
+
+    ($app:ident, with_conditions: [$($condition:expr),* $(,)?] $(, $($rest:tt)*)?) => {
+        compile_error!("with_conditions: must list at least one condition and be directly followed by add_systems_update: or add_systems_fixed_update:");
     };
-    ($app:ident, add_sub_state: [$($state:ty),* $(,)?] $(, $($rest:tt)*)?) => {
-        $crate::define_plugin_finish!($app, $($($rest)*)?);
+
+    // distributive_run_if_update: (condition checked by each system of the next list)
+    ($app:ident, distributive_run_if_update: $condition:expr, add_systems_update: [$($system:expr),* $(,)?] $(, $($rest:tt)*)?) => {
+        $($crate::define_plugin_trace!(add_systems_update, $system);)*
+        if !Self::DRY_RUN {
+            $app.add_systems(
+                ::bevy::prelude::Update,
+                ($($system,)*)
+                    .in_set($crate::PluginSystemSet::<Self>::new())
+                    .distributive_run_if($condition)
+            );
+        }
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
     };
-    ($app:ident, register_type: [$($reflect_type:ty),* $(,)?] $(, $($rest:tt)*)?) => {
-        $crate::define_plugin_finish!($app, $($($rest)*)?);
+
+    ($app:ident, distributive_run_if_update: $condition:expr $(, $($rest:tt)*)?) => {
+        compile_error!("distributive_run_if_update: must be directly followed by add_systems_update:");
     };
-    ($app:ident, add_systems_startup: [$($system:expr),* $(,)?] $(, $($rest:tt)*)?) => {
-        $crate::define_plugin_finish!($app, $($($rest)*)?);
+
+    // flush_between_update: (chain the next list, applying commands between systems)
+    ($app:ident, flush_between_update: true, add_systems_update: [$($system:expr),* $(,)?] $(, $($rest:tt)*)?) => {
+        $($crate::define_plugin_trace!(add_systems_update, $system);)*
+        if !Self::DRY_RUN {
+            $app.add_systems(
+                ::bevy::prelude::Update,
+                ($($system,)*)
+                    .chain()
+                    .in_set($crate::PluginSystemSet::<Self>::new())
+            );
+        }
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
     };
-    ($app:ident, add_systems_update: [$($system:expr),* $(,)?] $(, $($rest:tt)*)?) => {
-        $crate::define_plugin_finish!($app, $($($rest)*)?);
+
+    ($app:ident, flush_between_update: false, add_systems_update: [$($system:expr),* $(,)?] $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_internal!($app, add_systems_update: [$($system),*] $(, $($rest)*)?);
     };
-    ($app:ident, add_systems_fixed_update: [$($system:expr),* $(,)?] $(, $($rest:tt)*)?) => {
-        $crate::define_plugin_finish!($app, $($($rest)*)?);
+
+    ($app:ident, flush_between_update: $enabled:literal $(, $($rest:tt)*)?) => {
+        compile_error!("flush_between_update: must be directly followed by add_systems_update:");
     };
-    ($app:ident, add_systems_on_enter: { $($state:expr => [$($system:expr),* $(,)?]),* $(,)? } $(, $($rest:tt)*)?) => {
-        $crate::define_plugin_finish!($app, $($($rest)*)?);
+
+    // ambiguous_with_all_update: (Update systems excluded from ambiguity detection)
+    ($app:ident, ambiguous_with_all_update: [$($system:expr),* $(,)?] $(, $($rest:tt)*)?) => {
+        $($crate::define_plugin_trace!(ambiguous_with_all_update, $system);)*
+        if !Self::DRY_RUN {
+            $app.add_systems(
+                ::bevy::prelude::Update,
+                ($($system.ambiguous_with_all(),)*).in_set($crate::PluginSystemSet::<Self>::new())
+            );
+        }
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
     };
-    ($app:ident, add_systems_on_exit: { $($state:expr => [$($system:expr),* $(,)?]),* $(,)? } $(, $($rest:tt)*)?) => {
-        $crate::define_plugin_finish!($app, $($($rest)*)?);
+
+    // add_systems_update_if: (Update systems added only if a condition holds at build time)
+    ($app:ident, add_systems_update_if: { $($condition:expr => [$($system:expr),* $(,)?]),* $(,)? } $(, $($rest:tt)*)?) => {
+        $(
+            if $condition {
+                $($crate::define_plugin_trace!(add_systems_update_if, $system);)*
+                if !Self::DRY_RUN {
+                    $app.add_systems(
+                        ::bevy::prelude::Update,
+                        ($($system,)*).in_set($crate::PluginSystemSet::<Self>::new())
+                    );
+                }
+            }
+        )*
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
     };
-    ($app:ident, custom_build: $build_fn:expr $(, $($rest:tt)*)?) => {
-        $crate::define_plugin_finish!($app, $($($rest)*)?);
+
+    // on_substate_update: (Update systems that run only in a sub-state)
+    ($app:ident, on_substate_update: { $($substate:expr => [$($system:expr),* $(,)?]),* $(,)? } $(, $($rest:tt)*)?) => {
+        $(
+            $($crate::define_plugin_trace!(on_substate_update, $system);)*
+            if !Self::DRY_RUN {
+                $app.add_systems(
+                    ::bevy::prelude::Update,
+                    ($($system,)*)
+                        .in_set($crate::PluginSystemSet::<Self>::new())
+                        .run_if(::bevy::prelude::in_state($substate))
+                );
+            }
+        )*
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
     };
-    ($app:ident, generate_tests: { $($test_config:tt)* } $(, $($rest:tt)*)?) => {
-        $crate::define_plugin_finish!($app, $($($rest)*)?);
+
+    // update_if_resource_exists: (Update systems that run only while a resource exists)
+    ($app:ident, update_if_resource_exists: { $($resource:ty => [$($system:expr),* $(,)?]),* $(,)? } $(, $($rest:tt)*)?) => {
+        $(
+            $($crate::define_plugin_trace!(update_if_resource_exists, $system);)*
+            if !Self::DRY_RUN {
+                $app.add_systems(
+                    ::bevy::prelude::Update,
+                    ($($system,)*)
+                        .in_set($crate::PluginSystemSet::<Self>::new())
+                        .run_if(::bevy::prelude::resource_exists::<$resource>)
+                );
+            }
+        )*
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
     };
 
-    // Custom finish - this is what we're looking for!
-    ($app:ident, custom_finish: $finish_fn:expr $(, $($rest:tt)*)?) => {
-        $finish_fn($app);
-        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    // update_every_n_frames: (Update systems throttled to every nth frame)
+    ($app:ident, update_every_n_frames: { $($frames:literal => [$($system:expr),* $(,)?]),* $(,)? } $(, $($rest:tt)*)?) => {
+        $(
+            $($crate::define_plugin_trace!(update_every_n_frames, $system);)*
+            if !Self::DRY_RUN {
+                $app.add_systems(
+                    ::bevy::prelude::Update,
+                    ($($system,)*)
+                        .in_set($crate::PluginSystemSet::<Self>::new())
+                        .run_if($crate::every_n_frames($frames))
+                );
+            }
+        )*
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
     };
 
-    // Handle all other configurations (catch-all for unknown tokens)
-    ($app:ident, $unknown:tt $($rest:tt)*) => {
-        $crate::define_plugin_finish!($app, $($rest)*);
+    // add_systems_update_exclusive: (exclusive systems, one add_systems call each)
+    ($app:ident, add_systems_update_exclusive: [$($system:expr),* $(,)?] $(, $($rest:tt)*)?) => {
+        $(
+            $crate::define_plugin_trace!(add_systems_update_exclusive, $system);
+            if !Self::DRY_RUN {
+                $app.add_systems(
+                    ::bevy::prelude::Update,
+                    $system.in_set($crate::PluginSystemSet::<Self>::new())
+                );
+            }
+        )*
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
     };
-}
 
-// ============================================================================
-// Introspection support (feature-gated)
-// ============================================================================
+    // update_if_windowed: (Update systems skipped in headless apps)
+    ($app:ident, update_if_windowed: [$($system:expr),* $(,)?] $(, $($rest:tt)*)?) => {
+        $($crate::define_plugin_trace!(update_if_windowed, $system);)*
+        if !Self::DRY_RUN {
+            $app.add_systems(
+                ::bevy::prelude::Update,
+                ($($system,)*)
+                    .in_set($crate::PluginSystemSet::<Self>::new())
+                    .run_if($crate::has_primary_window)
+            );
+        }
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
 
-/// Helper macro to count items in a list (used for static array sizing)
-#[macro_export]
-#[doc(hidden)]
-macro_rules! count_items {
-    () => { 0usize };
-    ($first:ty $(, $rest:ty)*) => {
-        1usize + $crate::count_items!($($rest),*)
+    // update_before: (order this plugin's Update systems before other sets)
+    ($app:ident, update_before: [$($set:expr),* $(,)?] $(, $($rest:tt)*)?) => {
+        $(
+            if !Self::DRY_RUN {
+                $app.configure_sets(
+                    ::bevy::prelude::Update,
+                    $crate::PluginSystemSet::<Self>::new().before($set)
+                );
+            }
+        )*
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
     };
-    ($first:expr $(, $rest:expr)*) => {
-        1usize + $crate::count_items!($($rest),*)
+
+    // update_after: (order this plugin's Update systems after other sets)
+    ($app:ident, update_after: [$($set:expr),* $(,)?] $(, $($rest:tt)*)?) => {
+        $(
+            if !Self::DRY_RUN {
+                $app.configure_sets(
+                    ::bevy::prelude::Update,
+                    $crate::PluginSystemSet::<Self>::new().after($set)
+                );
+            }
+        )*
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
     };
-}
 
-/// Helper macro to generate TypeInfo array for a list of types
-#[macro_export]
-#[doc(hidden)]
-macro_rules! type_info_array {
-    ($name:ident: [$($ty:ty),* $(,)?]) => {
-        static $name: &'static [$crate::TypeInfo] = &[
-            $($crate::TypeInfo::new::<$ty>(stringify!($ty)),)*
-        ];
+    // togglable: (gate this plugin's system set on PluginEnabled<Self>)
+    ($app:ident, togglable: true $(, $($rest:tt)*)?) => {
+        if !Self::DRY_RUN {
+            $app.init_resource::<$crate::PluginEnabled<Self>>();
+            $app.configure_sets(
+                ::bevy::prelude::Update,
+                $crate::PluginSystemSet::<Self>::new().run_if($crate::plugin_enabled::<Self>)
+            );
+            $app.configure_sets(
+                ::bevy::prelude::FixedUpdate,
+                $crate::PluginSystemSet::<Self>::new().run_if($crate::plugin_enabled::<Self>)
+            );
+        }
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    ($app:ident, togglable: false $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    // run_if: (gate this plugin's system set on a condition). The condition
+    // is written once per schedule, so it needn't be Clone
+    ($app:ident, run_if: $condition:expr $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_trace!(run_if, $condition);
+        if !Self::DRY_RUN {
+            $app.configure_sets(
+                ::bevy::prelude::Update,
+                $crate::PluginSystemSet::<Self>::new().run_if($condition)
+            );
+            $app.configure_sets(
+                ::bevy::prelude::FixedUpdate,
+                $crate::PluginSystemSet::<Self>::new().run_if($condition)
+            );
+        }
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    // generate_system_set: (the named set is declared by define_plugin_system_set!)
+    ($app:ident, generate_system_set: true $(, $($rest:tt)*)?) => {
+        compile_error!("generate_system_set: takes the name of the set to declare, e.g. generate_system_set: MyPluginSystems");
+    };
+
+    ($app:ident, generate_system_set: $set:ident $(, $($rest:tt)*)?) => {
+        if !Self::DRY_RUN {
+            $app.configure_sets(
+                ::bevy::prelude::Update,
+                $crate::PluginSystemSet::<Self>::new().in_set($set)
+            );
+        }
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    // add_systems_on_enter: (new name for on_enter:)
+    ($app:ident, add_systems_on_enter: { $($entries:tt)* } $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_state_systems!($app, OnEnter, $($entries)*);
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    // add_systems_on_exit: (new name for on_exit:)
+    ($app:ident, add_systems_on_exit: { $($entries:tt)* } $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_state_systems!($app, OnExit, $($entries)*);
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    // on_enter_any: (the same systems on entering several states)
+    ($app:ident, on_enter_any: { $([$($state:expr),* $(,)?] => [$($system:expr),* $(,)?]),* $(,)? } $(, $($rest:tt)*)?) => {
+        $(
+            $crate::define_plugin_on_enter_any!($app, [$($state),*], [$($system),*]);
+        )*
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    // init_schedule: (create custom schedules owned by this plugin)
+    ($app:ident, init_schedule: [$($label:expr),* $(,)?] $(, $($rest:tt)*)?) => {
+        $(
+            $crate::define_plugin_trace!(init_schedule, $label);
+            if !Self::DRY_RUN {
+                $app.init_schedule($label);
+            }
+        )*
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    // add_systems_to: (systems for an arbitrary schedule label)
+    ($app:ident, add_systems_to: { $($label:expr => [$($system:expr),* $(,)?]),* $(,)? } $(, $($rest:tt)*)?) => {
+        $(
+            $($crate::define_plugin_trace!(add_systems_to, $system);)*
+            if !Self::DRY_RUN {
+                $app.add_systems(
+                    $label,
+                    ($($system,)*)
+                );
+            }
+        )*
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    // add_systems_to_many: (the same systems in several schedules)
+    ($app:ident, add_systems_to_many: { $([$($label:expr),* $(,)?] => [$($system:expr),* $(,)?]),* $(,)? } $(, $($rest:tt)*)?) => {
+        $(
+            $crate::define_plugin_systems_to_many!($app, [$($label),*], [$($system),*]);
+        )*
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    // custom_build: taking the plugin (handled by define_plugin_build_with_plugin!)
+    ($app:ident, custom_build: |$app_arg:ident $(: $app_ty:ty)?, $plugin_arg:ident $(: $plugin_ty:ty)?| $body:expr $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    // custom_build: (new name for custom_init:)
+    ($app:ident, custom_build: $build_fn:expr $(, $($rest:tt)*)?) => {
+        if !Self::DRY_RUN {
+            $build_fn($app);
+        }
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    // custom_finish: (skip in build, handled in finish)
+    ($app:ident, custom_finish: $finish_fn:expr $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
     };
-    // Empty case
-    ($name:ident: []) => {
-        static $name: &'static [$crate::TypeInfo] = &[];
+
+    // finish_init_resource: (skip in build, handled in finish)
+    ($app:ident, finish_init_resource: { $($resource:ty => $init:expr),* $(,)? } $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    // assert_resources_present: (skip in build, checked in finish)
+    ($app:ident, assert_resources_present: [$($resource:ty),* $(,)?] $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    // remove_resource: (skip in build, handled in finish)
+    ($app:ident, remove_resource: [$($resource:ty),* $(,)?] $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    // warn_if_empty: (runtime warning when the plugin declares nothing)
+    ($app:ident, warn_if_empty: true $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_warn_if_empty!(Self);
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    ($app:ident, warn_if_empty: false $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    // warn_if_transitions_without_states: (runtime warning for OnEnter/OnExit
+    // systems of states the plugin doesn't initialize)
+    ($app:ident, warn_if_transitions_without_states: true $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_warn_if_transitions_without_states!(Self);
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    ($app:ident, warn_if_transitions_without_states: false $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    // generate_debug_hud: (keep a PluginDebugReport of the registry up to date)
+    ($app:ident, generate_debug_hud: true $(, $($rest:tt)*)?) => {
+        if !Self::DRY_RUN {
+            $crate::define_plugin_debug_hud!(Self, $app);
+        }
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    ($app:ident, generate_debug_hud: false $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    // generate_reflect_report: (snapshot the registry into ReflectablePluginReport in finish)
+    ($app:ident, generate_reflect_report: true $(, $($rest:tt)*)?) => {
+        if !Self::DRY_RUN {
+            $crate::define_plugin_reflect_report!(Self, $app);
+        }
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    ($app:ident, generate_reflect_report: false $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    // auto_reflect_resources: (read through the plugin's AUTO_REFLECT_RESOURCES const)
+    ($app:ident, auto_reflect_resources: $enabled:literal $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    // lint_custom_build: (skip in build, handled by define_plugin_lint_scan!)
+    ($app:ident, lint_custom_build: $enabled:literal $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    // metadata_const_name: (declared by define_plugin_metadata_const!)
+    ($app:ident, metadata_const_name: $name:ident $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_metadata_const_enabled!($name);
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    // impl_debug: (skip in build, handled by define_plugin_debug_scan!)
+    ($app:ident, impl_debug: $enabled:literal $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    // generate_tests: (skip in build, handled by separate macro)
+    ($app:ident, generate_tests: { $($test_config:tt)* } $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_internal!($app, $($($rest)*)?);
+    };
+
+    // ========================================================================
+    // Error case - unrecognized configuration
+    // ========================================================================
+
+    ($app:ident, $unknown:tt $($rest:tt)*) => {
+        compile_error!(concat!(
+            "Unknown plugin configuration option: ",
+            stringify!($unknown),
+            "\nSupported options: depends_on, dependency_check, depends_on_bevy, requires_bevy_features, meta, fields, constructors, settings_resource, init_resource, insert_resource, insert_resource_if_absent, init_resource_with, inspectable_resources, add_message, add_message_with, add_message_buffered, add_plugins, add_plugins_configured, init_state, add_sub_state, register_type, register_components, auto_reflect_resources, add_observers, add_systems_startup, add_systems_update, add_systems_fixed_update, add_systems_startup_raw, add_systems_update_raw, add_systems_fixed_update_raw, add_systems_state_transition, add_systems_update_exclusive, ambiguous_with_all_update, add_systems_update_if, add_systems_on_enter, add_systems_on_exit, on_enter_any, with_conditions, distributive_run_if_update, flush_between_update, chain_startup, on_substate_update, update_if_resource_exists, update_if_windowed, update_every_n_frames, update_before, update_after, togglable, run_if, generate_system_set, init_schedule, add_systems_to, add_systems_to_many, custom_build, custom_finish, finish_init_resource, remove_resource, assert_resources_present, warn_if_empty, warn_if_transitions_without_states, trace_registrations, dry_run, generate_debug_hud, generate_reflect_report, impl_debug, metadata_const_name, lint_custom_build, is_unique, generate_tests"
+        ));
+    };
+}
+
+/// Declare the system set named by `generate_system_set:`, if present.
+/// Scans the configuration one key at a time, like `define_plugin_tests_scan!`.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_system_set {
+    // generate_system_set: true is reported by define_plugin_internal!
+    ($plugin_name:ident { generate_system_set: true $(, $($rest:tt)*)? }) => {};
+
+    // Found generate_system_set: - declare the set
+    ($plugin_name:ident { generate_system_set: $set:ident $(, $($rest:tt)*)? }) => {
+        #[derive(::bevy::ecs::schedule::SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+        pub struct $set;
+    };
+
+    // Skip other configs and keep looking
+    ($plugin_name:ident { $key:ident : [$($value:tt)*] $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_system_set!($plugin_name { $($($rest)*)? });
+    };
+    ($plugin_name:ident { $key:ident : { $($value:tt)* } $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_system_set!($plugin_name { $($($rest)*)? });
+    };
+    ($plugin_name:ident { $key:ident : $value:expr $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_system_set!($plugin_name { $($($rest)*)? });
+    };
+
+    // End of config, or a malformed entry that define_plugin_internal! reports
+    ($plugin_name:ident { $($rest:tt)* }) => {};
+}
+
+/// Declare the plugin struct: with the `fields:` entries as `pub` fields if
+/// present, otherwise a unit struct. Scans the configuration like
+/// `define_plugin_system_set!`.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_struct {
+    ({ $($attrs:tt)* } $plugin_name:ident { fields: { $($field:ident : $ty:ty),* $(,)? } $(, $($rest:tt)*)? }) => {
+        $($attrs)*
+        pub struct $plugin_name {
+            $(pub $field: $ty,)*
+        }
+
+        // for<'a> defers the bound, so a plugin without Default still compiles
+        // and only a test_app_boots test that needs it fails
+        $crate::define_plugin_if_testing! {
+            impl $crate::BootTestInstance for $plugin_name
+            where
+                for<'a> $plugin_name: ::core::default::Default,
+            {
+                fn boot_test_instance() -> Self {
+                    ::core::default::Default::default()
+                }
+            }
+        }
+    };
+
+    // Skip other configs and keep looking
+    ({ $($attrs:tt)* } $plugin_name:ident { $key:ident : [$($value:tt)*] $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_struct!({ $($attrs)* } $plugin_name { $($($rest)*)? });
+    };
+    ({ $($attrs:tt)* } $plugin_name:ident { $key:ident : { $($value:tt)* } $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_struct!({ $($attrs)* } $plugin_name { $($($rest)*)? });
+    };
+    ({ $($attrs:tt)* } $plugin_name:ident { $key:ident : $value:expr $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_struct!({ $($attrs)* } $plugin_name { $($($rest)*)? });
+    };
+
+    // End of config, or a malformed entry that define_plugin_internal! reports
+    ({ $($attrs:tt)* } $plugin_name:ident { $($rest:tt)* }) => {
+        $($attrs)*
+        pub struct $plugin_name;
+
+        $crate::define_plugin_if_testing! {
+            impl $crate::BootTestInstance for $plugin_name {
+                fn boot_test_instance() -> Self {
+                    $plugin_name
+                }
+            }
+        }
+    };
+}
+
+/// Generate the `constructors:` functions, if present.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_constructors {
+    ($plugin_name:ident { constructors: { $($constructor:ident($($arg:ident : $arg_ty:ty),* $(,)?)),* $(,)? } $(, $($rest:tt)*)? }) => {
+        impl $plugin_name {
+            $(
+                #[doc = concat!("Create the plugin with `", stringify!($($arg),*), "` set and the remaining fields from `Default`")]
+                #[allow(clippy::needless_update)]
+                pub fn $constructor($($arg: $arg_ty),*) -> Self {
+                    Self {
+                        $($arg,)*
+                        ..::core::default::Default::default()
+                    }
+                }
+            )*
+        }
+    };
+
+    // Skip other configs and keep looking
+    ($plugin_name:ident { $key:ident : [$($value:tt)*] $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_constructors!($plugin_name { $($($rest)*)? });
+    };
+    ($plugin_name:ident { $key:ident : { $($value:tt)* } $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_constructors!($plugin_name { $($($rest)*)? });
+    };
+    ($plugin_name:ident { $key:ident : $value:expr $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_constructors!($plugin_name { $($($rest)*)? });
+    };
+
+    // End of config, or a malformed entry that define_plugin_internal! reports
+    ($plugin_name:ident { $($rest:tt)* }) => {};
+}
+
+/// Call every `custom_build` closure that takes the plugin as a second
+/// argument. `self` is passed in from the generated `build()`, since macro
+/// hygiene keeps this macro from naming it directly.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_build_with_plugin {
+    ($this:ident, $app:ident { custom_build: |$app_arg:ident $(: $app_ty:ty)?, $plugin_arg:ident $(: $plugin_ty:ty)?| $body:expr $(, $($rest:tt)*)? }) => {
+        (|$app_arg $(: $app_ty)?, $plugin_arg $(: $plugin_ty)?| $body)($app, $this);
+        $crate::define_plugin_build_with_plugin!($this, $app { $($($rest)*)? });
+    };
+
+    // Skip other configs and keep looking
+    ($this:ident, $app:ident { $key:ident : [$($value:tt)*] $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_build_with_plugin!($this, $app { $($($rest)*)? });
+    };
+    ($this:ident, $app:ident { $key:ident : { $($value:tt)* } $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_build_with_plugin!($this, $app { $($($rest)*)? });
+    };
+    ($this:ident, $app:ident { $key:ident : $value:expr $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_build_with_plugin!($this, $app { $($($rest)*)? });
+    };
+
+    // End of config, or a malformed entry that define_plugin_internal! reports
+    ($this:ident, $app:ident { $($rest:tt)* }) => {};
+}
+
+/// Insert the `settings_resource:` type, built from the plugin's fields, if
+/// the configuration has one. Scans for the key like
+/// `define_plugin_lint_scan!`; the second copy of the configuration is the one
+/// searched for the `fields:` block.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_settings_scan {
+    ($this:ident, $app:ident { settings_resource: $settings:path $(, $($rest:tt)*)? } $config:tt) => {
+        $crate::define_plugin_settings_resource!($this, $app, $settings, $config);
+    };
+
+    // Skip other configs and keep looking
+    ($this:ident, $app:ident { $key:ident : [$($value:tt)*] $(, $($rest:tt)*)? } $config:tt) => {
+        $crate::define_plugin_settings_scan!($this, $app { $($($rest)*)? } $config);
+    };
+    ($this:ident, $app:ident { $key:ident : { $($value:tt)* } $(, $($rest:tt)*)? } $config:tt) => {
+        $crate::define_plugin_settings_scan!($this, $app { $($($rest)*)? } $config);
+    };
+    ($this:ident, $app:ident { $key:ident : $value:expr $(, $($rest:tt)*)? } $config:tt) => {
+        $crate::define_plugin_settings_scan!($this, $app { $($($rest)*)? } $config);
+    };
+
+    // End of config, or a malformed entry that define_plugin_internal! reports
+    ($this:ident, $app:ident { $($rest:tt)* } $config:tt) => {};
+}
+
+/// Insert the settings resource with each field of the plugin cloned into the
+/// field of the same name.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_settings_resource {
+    ($this:ident, $app:ident, $settings:path, { fields: { $($field:ident : $ty:ty),* $(,)? } $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_trace!(settings_resource, $settings);
+        if !Self::DRY_RUN {
+            // A path fragment can't name a struct literal, but an alias can
+            type Settings = $settings;
+            $app.insert_resource(Settings {
+                $($field: ::core::clone::Clone::clone(&$this.$field),)*
+            });
+        }
+    };
+
+    // Skip other configs and keep looking
+    ($this:ident, $app:ident, $settings:path, { $key:ident : [$($value:tt)*] $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_settings_resource!($this, $app, $settings, { $($($rest)*)? });
+    };
+    ($this:ident, $app:ident, $settings:path, { $key:ident : { $($value:tt)* } $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_settings_resource!($this, $app, $settings, { $($($rest)*)? });
+    };
+    ($this:ident, $app:ident, $settings:path, { $key:ident : $value:expr $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_settings_resource!($this, $app, $settings, { $($($rest)*)? });
+    };
+
+    ($this:ident, $app:ident, $settings:path, { $($rest:tt)* }) => {
+        compile_error!("settings_resource requires a `fields: { ... }` block to build the settings from");
+    };
+}
+
+/// Lint the `custom_build` closures if the configuration contains
+/// `lint_custom_build: true`. Scans for the flag like
+/// `define_plugin_debug_scan!`; the second copy of the configuration is the
+/// one searched for closures.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_lint_scan {
+    ({ lint_custom_build: true $(, $($rest:tt)*)? } $config:tt) => {
+        $crate::define_plugin_lint_custom_build!($config);
+    };
+
+    // Skip other configs and keep looking
+    ({ $key:ident : [$($value:tt)*] $(, $($rest:tt)*)? } $config:tt) => {
+        $crate::define_plugin_lint_scan!({ $($($rest)*)? } $config);
+    };
+    ({ $key:ident : { $($value:tt)* } $(, $($rest:tt)*)? } $config:tt) => {
+        $crate::define_plugin_lint_scan!({ $($($rest)*)? } $config);
+    };
+    ({ $key:ident : $value:expr $(, $($rest:tt)*)? } $config:tt) => {
+        $crate::define_plugin_lint_scan!({ $($($rest)*)? } $config);
+    };
+
+    // End of config, or a malformed entry that define_plugin_internal! reports
+    ({ $($rest:tt)* } $config:tt) => {};
+}
+
+/// Lint every `custom_build` closure with a block body. Named functions and
+/// closures with an expression body are not scanned.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_lint_custom_build {
+    ({ custom_build: |$($arg:ident $(: $ty:ty)?),*| { $($body:tt)* } $(, $($rest:tt)*)? }) => {
+        $($crate::define_plugin_lint_token!($body);)*
+        $crate::define_plugin_lint_custom_build!({ $($($rest)*)? });
+    };
+
+    // Skip other configs and keep looking
+    ({ $key:ident : [$($value:tt)*] $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_lint_custom_build!({ $($($rest)*)? });
+    };
+    ({ $key:ident : { $($value:tt)* } $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_lint_custom_build!({ $($($rest)*)? });
+    };
+    ({ $key:ident : $value:expr $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_lint_custom_build!({ $($($rest)*)? });
+    };
+
+    // End of config, or a malformed entry that define_plugin_internal! reports
+    ({ $($rest:tt)* }) => {};
+}
+
+/// Lint one token of a `custom_build` body, descending into groups. Each
+/// token gets its own invocation, so long bodies don't deepen the recursion.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_lint_token {
+    ({ $($inner:tt)* }) => { $($crate::define_plugin_lint_token!($inner);)* };
+    (( $($inner:tt)* )) => { $($crate::define_plugin_lint_token!($inner);)* };
+    ([ $($inner:tt)* ]) => { $($crate::define_plugin_lint_token!($inner);)* };
+    ($name:ident) => { $crate::define_plugin_lint_method!($name $name); };
+    ($other:tt) => {};
+}
+
+/// Warn about a call to a method that a declarative key covers. Only the
+/// method name is matched, not the receiver, so the wording leaves open that
+/// the call may be on something other than the `App` (e.g. `Commands`). The
+/// warning is a deprecation of a local function named after the method, so it
+/// points at the call in the user's `custom_build`.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_lint_method {
+    (init_resource $name:ident) => {
+        $crate::define_plugin_lint_warn!(
+            $name,
+            "an init_resource call was detected in custom_build; if it is on the App, use the `init_resource: [...]` key instead"
+        );
+    };
+    (insert_resource $name:ident) => {
+        $crate::define_plugin_lint_warn!(
+            $name,
+            "an insert_resource call was detected in custom_build; if it is on the App, use the `insert_resource: [...]` key instead"
+        );
+    };
+    (add_message $name:ident) => {
+        $crate::define_plugin_lint_warn!(
+            $name,
+            "an add_message call was detected in custom_build; if it is on the App, use the `add_message: [...]` key instead"
+        );
+    };
+    (add_plugins $name:ident) => {
+        $crate::define_plugin_lint_warn!(
+            $name,
+            "an add_plugins call was detected in custom_build; if it is on the App, use the `add_plugins: [...]` key instead"
+        );
+    };
+    (init_state $name:ident) => {
+        $crate::define_plugin_lint_warn!(
+            $name,
+            "an init_state call was detected in custom_build; if it is on the App, use the `init_state: [...]` key instead"
+        );
+    };
+    (add_sub_state $name:ident) => {
+        $crate::define_plugin_lint_warn!(
+            $name,
+            "an add_sub_state call was detected in custom_build; if it is on the App, use the `add_sub_state: [...]` key instead"
+        );
+    };
+    (register_type $name:ident) => {
+        $crate::define_plugin_lint_warn!(
+            $name,
+            "an register_type call was detected in custom_build; if it is on the App, use the `register_type: [...]` key instead"
+        );
+    };
+    (add_observer $name:ident) => {
+        $crate::define_plugin_lint_warn!(
+            $name,
+            "an add_observer call was detected in custom_build; if it is on the App, use the `add_observers: [...]` key instead"
+        );
+    };
+    (add_systems $name:ident) => {
+        $crate::define_plugin_lint_warn!(
+            $name,
+            "an add_systems call was detected in custom_build; if it is on the App, use an `add_systems_*` key instead"
+        );
+    };
+    ($other:ident $name:ident) => {};
+}
+
+/// Emit one `lint_custom_build` warning.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_lint_warn {
+    ($name:ident, $note:literal) => {{
+        #[deprecated(note = $note)]
+        fn $name() {}
+        $name();
+    }};
+}
+
+/// Implement `Debug` for the plugin if the configuration contains
+/// `impl_debug: true`. Scans the configuration like `define_plugin_system_set!`.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_debug_scan {
+    ($plugin_name:ident { impl_debug: true $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_impl_debug!($plugin_name);
+    };
+
+    // Skip other configs and keep looking
+    ($plugin_name:ident { $key:ident : [$($value:tt)*] $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_debug_scan!($plugin_name { $($($rest)*)? });
+    };
+    ($plugin_name:ident { $key:ident : { $($value:tt)* } $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_debug_scan!($plugin_name { $($($rest)*)? });
+    };
+    ($plugin_name:ident { $key:ident : $value:expr $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_debug_scan!($plugin_name { $($($rest)*)? });
+    };
+
+    // End of config, or a malformed entry that define_plugin_internal! reports
+    ($plugin_name:ident { $($rest:tt)* }) => {};
+}
+
+/// Report missing dependencies according to the `dependency_check:` mode.
+/// Scans the configuration for the key; without it, missing dependencies panic.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_dependency_failure {
+    // Log and keep building
+    ($errors:ident { dependency_check: warn $(, $($rest:tt)*)? }) => {
+        ::bevy::log::warn!("{}", $crate::MissingPluginError::combined_message(&$errors));
+    };
+
+    // Skip other configs and keep looking
+    ($errors:ident { $key:ident : [$($value:tt)*] $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_dependency_failure!($errors { $($($rest)*)? });
+    };
+    ($errors:ident { $key:ident : { $($value:tt)* } $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_dependency_failure!($errors { $($($rest)*)? });
+    };
+    ($errors:ident { $key:ident : $value:expr $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_dependency_failure!($errors { $($($rest)*)? });
+    };
+
+    // dependency_check: panic, no dependency_check: at all, or a malformed
+    // entry that define_plugin_internal! reports
+    ($errors:ident { $($rest:tt)* }) => {
+        panic!("{}", $crate::MissingPluginError::combined_message(&$errors));
+    };
+}
+
+/// Whether the configuration contains `trace_registrations: true`.
+/// Scans the configuration one key at a time, like `define_plugin_system_set!`.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_trace_enabled {
+    ({ trace_registrations: true $(, $($rest:tt)*)? }) => {
+        true
+    };
+
+    // Skip other configs and keep looking
+    ({ $key:ident : [$($value:tt)*] $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_trace_enabled!({ $($($rest)*)? })
+    };
+    ({ $key:ident : { $($value:tt)* } $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_trace_enabled!({ $($($rest)*)? })
+    };
+    ({ $key:ident : $value:expr $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_trace_enabled!({ $($($rest)*)? })
+    };
+
+    // End of config, or a malformed entry that define_plugin_internal! reports
+    ({ $($rest:tt)* }) => {
+        false
+    };
+}
+
+/// Whether the configuration contains `dry_run: true`.
+/// Scans the configuration one key at a time, like `define_plugin_trace_enabled!`.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_dry_run_enabled {
+    ({ dry_run: true $(, $($rest:tt)*)? }) => {
+        true
+    };
+
+    // Skip other configs and keep looking
+    ({ $key:ident : [$($value:tt)*] $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_dry_run_enabled!({ $($($rest)*)? })
+    };
+    ({ $key:ident : { $($value:tt)* } $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_dry_run_enabled!({ $($($rest)*)? })
+    };
+    ({ $key:ident : $value:expr $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_dry_run_enabled!({ $($($rest)*)? })
+    };
+
+    // End of config, or a malformed entry that define_plugin_internal! reports
+    ({ $($rest:tt)* }) => {
+        false
+    };
+}
+
+/// Whether `chain_startup: true` is set, read through the plugin's
+/// `CHAIN_STARTUP` const. Scans the configuration like `define_plugin_dry_run_enabled!`.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_chain_startup_enabled {
+    ({ chain_startup: true $(, $($rest:tt)*)? }) => {
+        true
+    };
+
+    // Skip other configs and keep looking
+    ({ $key:ident : [$($value:tt)*] $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_chain_startup_enabled!({ $($($rest)*)? })
+    };
+    ({ $key:ident : { $($value:tt)* } $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_chain_startup_enabled!({ $($($rest)*)? })
+    };
+    ({ $key:ident : $value:expr $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_chain_startup_enabled!({ $($($rest)*)? })
+    };
+
+    // End of config, or a malformed entry that define_plugin_internal! reports
+    ({ $($rest:tt)* }) => {
+        false
+    };
+}
+
+/// Whether `auto_reflect_resources: true` is set, read through the plugin's
+/// `AUTO_REFLECT_RESOURCES` const. Scans the configuration like `define_plugin_dry_run_enabled!`.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_auto_reflect_enabled {
+    ({ auto_reflect_resources: true $(, $($rest:tt)*)? }) => {
+        true
+    };
+
+    // Skip other configs and keep looking
+    ({ $key:ident : [$($value:tt)*] $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_auto_reflect_enabled!({ $($($rest)*)? })
+    };
+    ({ $key:ident : { $($value:tt)* } $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_auto_reflect_enabled!({ $($($rest)*)? })
+    };
+    ({ $key:ident : $value:expr $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_auto_reflect_enabled!({ $($($rest)*)? })
+    };
+
+    // End of config, or a malformed entry that define_plugin_internal! reports
+    ({ $($rest:tt)* }) => {
+        false
+    };
+}
+
+/// Whether the generated `Plugin::is_unique()` returns `true`, which it does
+/// unless the configuration contains `is_unique: false`.
+/// Scans the configuration one key at a time, like `define_plugin_trace_enabled!`.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_is_unique {
+    ({ is_unique: false $(, $($rest:tt)*)? }) => {
+        false
+    };
+
+    // Skip other configs and keep looking
+    ({ $key:ident : [$($value:tt)*] $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_is_unique!({ $($($rest)*)? })
+    };
+    ({ $key:ident : { $($value:tt)* } $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_is_unique!({ $($($rest)*)? })
+    };
+    ({ $key:ident : $value:expr $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_is_unique!({ $($($rest)*)? })
+    };
+
+    // End of config, or a malformed entry that define_plugin_internal! reports
+    ({ $($rest:tt)* }) => {
+        true
+    };
+}
+
+/// Log a single registration from `build()` when the plugin enabled
+/// `trace_registrations:`, or at info level with `dry_run:`, where the
+/// registration itself is skipped
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_trace {
+    ($kind:ident, $($item:tt)*) => {
+        if Self::DRY_RUN {
+            $crate::log_dry_run::<Self>(stringify!($kind), stringify!($($item)*));
+        } else if Self::TRACE_REGISTRATIONS {
+            $crate::trace_registration::<Self>(stringify!($kind), stringify!($($item)*));
+        }
+    };
+}
+
+/// The name a `meta:` block's `display_name` gives the plugin. Expands to an
+/// `Option<&'static str>` expression, `None` without a display_name.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_display_name {
+    (@meta $plugin_name:ident { display_name: $name:literal $(, $($meta_rest:tt)*)? } $config:tt) => {
+        ::std::option::Option::Some($name)
+    };
+    (@meta $plugin_name:ident { $key:ident : $value:expr $(, $($meta_rest:tt)*)? } $config:tt) => {
+        $crate::define_plugin_display_name!(@meta $plugin_name { $($($meta_rest)*)? } $config)
+    };
+    // meta: block read without a display_name, keep looking after it
+    (@meta $plugin_name:ident { $($meta_rest:tt)* } { $($config:tt)* }) => {
+        $crate::define_plugin_display_name!($plugin_name { $($config)* })
+    };
+
+    ($plugin_name:ident { meta: { $($meta:tt)* } $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_display_name!(@meta $plugin_name { $($meta)* } { $($($rest)*)? })
+    };
+
+    // Skip other configs and keep looking
+    ($plugin_name:ident { $key:ident : [$($value:tt)*] $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_display_name!($plugin_name { $($($rest)*)? })
+    };
+    ($plugin_name:ident { $key:ident : { $($value:tt)* } $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_display_name!($plugin_name { $($($rest)*)? })
+    };
+    ($plugin_name:ident { $key:ident : $value:expr $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_display_name!($plugin_name { $($($rest)*)? })
+    };
+
+    // End of config, or a malformed entry that define_plugin_internal! reports
+    ($plugin_name:ident { $($rest:tt)* }) => {
+        ::std::option::Option::None
+    };
+}
+
+/// Apply the parts of a `meta:` block that affect the build. Only
+/// `default_settings` does; version, description and display_name are
+/// metadata only.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_meta {
+    ($app:ident $(,)?) => {};
+
+    ($app:ident, default_settings: $settings:expr $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_trace!(default_settings, $settings);
+        if !Self::DRY_RUN {
+            $app.insert_resource($settings);
+        }
+        $crate::define_plugin_meta!($app $(, $($rest)*)?);
+    };
+
+    ($app:ident, $key:ident : $value:expr $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_meta!($app $(, $($rest)*)?);
+    };
+}
+
+/// Add the systems of an `add_systems_on_enter`/`add_systems_on_exit` map,
+/// one `State => systems` entry at a time
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_state_systems {
+    ($app:ident, $schedule:ident, $(,)?) => {};
+
+    // State => [] adds nothing; `()` is not a valid system config
+    ($app:ident, $schedule:ident,
+        $state:expr => [$(,)?] $(.$method:ident($($args:tt)*))*
+        $(, $($more:tt)*)?
+    ) => {
+        $crate::define_plugin_state_systems!($app, $schedule, $($($more)*)?);
+    };
+
+    // State if condition => [sys1, sys2]: the condition gates the whole tuple.
+    // `if` may not follow an expr fragment, so the state is a path here
+    ($app:ident, $schedule:ident,
+        $($state:ident)::+ if $condition:expr => [$($system:expr),* $(,)?] $(.$method:ident($($args:tt)*))*
+        $(, $($more:tt)*)?
+    ) => {
+        $($crate::define_plugin_trace!($schedule, $system);)*
+        if !Self::DRY_RUN {
+            $app.add_systems(
+                ::bevy::prelude::$schedule($($state)::+),
+                ($($system,)*)$(.$method($($args)*))*.run_if($condition)
+            );
+        }
+        $crate::define_plugin_state_systems!($app, $schedule, $($($more)*)?);
+    };
+
+    ($app:ident, $schedule:ident, $($state:ident)::+ if $condition:expr => $systems:expr $(, $($more:tt)*)?) => {
+        $crate::define_plugin_trace!($schedule, $systems);
+        if !Self::DRY_RUN {
+            $app.add_systems(::bevy::prelude::$schedule($($state)::+), $systems.run_if($condition));
+        }
+        $crate::define_plugin_state_systems!($app, $schedule, $($($more)*)?);
+    };
+
+    // State => [sys1, sys2] with optional config methods, e.g. `.chain()`
+    ($app:ident, $schedule:ident,
+        $state:expr => [$($system:expr),* $(,)?] $(.$method:ident($($args:tt)*))*
+        $(, $($more:tt)*)?
+    ) => {
+        $($crate::define_plugin_trace!($schedule, $system);)*
+        if !Self::DRY_RUN {
+            $app.add_systems(
+                ::bevy::prelude::$schedule($state),
+                ($($system,)*)$(.$method($($args)*))*
+            );
+        }
+        $crate::define_plugin_state_systems!($app, $schedule, $($($more)*)?);
+    };
+
+    // State => any system config expression, e.g. `(sys1, sys2).chain()`
+    ($app:ident, $schedule:ident, $state:expr => $systems:expr $(, $($more:tt)*)?) => {
+        $crate::define_plugin_trace!($schedule, $systems);
+        if !Self::DRY_RUN {
+            $app.add_systems(::bevy::prelude::$schedule($state), $systems);
+        }
+        $crate::define_plugin_state_systems!($app, $schedule, $($($more)*)?);
+    };
+}
+
+/// Adds one `add_systems_to_many:` system list to each of its schedule labels.
+/// The labels are taken one at a time so the system list can be repeated.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_systems_to_many {
+    ($app:ident, [], [$($system:expr),*]) => {};
+
+    ($app:ident, [$label:expr $(, $more:expr)*], [$($system:expr),*]) => {
+        $($crate::define_plugin_trace!(add_systems_to_many, $system);)*
+        if !Self::DRY_RUN {
+            $app.add_systems($label, ($($system,)*));
+        }
+        $crate::define_plugin_systems_to_many!($app, [$($more),*], [$($system),*]);
+    };
+}
+
+/// Adds an `add_systems_startup`/`add_systems_update`/`add_systems_fixed_update`
+/// list. Plain entries are added together as one tuple; an entry with outer
+/// attributes such as `#[cfg(feature = "netcode")]` gets its own `add_systems`
+/// call carrying those attributes. `{ ... }` is appended to each system tuple.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_schedule_systems {
+    // Collect the attributes in front of an entry
+    ($app:ident, $kind:ident, $schedule:path, { $($suffix:tt)* }, [$($plain:expr),*], [$(#[$attrs:meta])*], [# [$attr:meta] $($list:tt)*]) => {
+        $crate::define_plugin_schedule_systems!(
+            $app, $kind, $schedule, { $($suffix)* }, [$($plain),*], [$(#[$attrs])* #[$attr]], [$($list)*]
+        );
+    };
+
+    // A plain entry joins the shared tuple. Each entry is converted on its
+    // own, so a value that isn't a system is reported where it is listed
+    ($app:ident, $kind:ident, $schedule:path, { $($suffix:tt)* }, [$($plain:expr),*], [], [$system:expr $(, $($list:tt)*)?]) => {
+        $crate::define_plugin_trace!($kind, $system);
+        $crate::define_plugin_schedule_systems!(
+            $app, $kind, $schedule, { $($suffix)* }, [$($plain,)* $system], [], [$($($list)*)?]
+        );
+    };
+
+    // An attributed entry is added on its own, under its attributes
+    ($app:ident, $kind:ident, $schedule:path, { $($suffix:tt)* }, [$($plain:expr),*], [$(#[$attrs:meta])+], [$system:expr $(, $($list:tt)*)?]) => {
+        $(#[$attrs])*
+        $crate::define_plugin_trace!($kind, $system);
+        $(#[$attrs])*
+        if !Self::DRY_RUN {
+            $app.add_systems($schedule, ($crate::plugin_system($system),) $($suffix)*);
+        }
+        $crate::define_plugin_schedule_systems!(
+            $app, $kind, $schedule, { $($suffix)* }, [$($plain),*], [], [$($($list)*)?]
+        );
+    };
+
+    ($app:ident, $kind:ident, $schedule:path, { $($suffix:tt)* }, [], [], []) => {};
+
+    ($app:ident, $kind:ident, $schedule:path, { $($suffix:tt)* }, [$($plain:expr),+], [], []) => {
+        if !Self::DRY_RUN {
+            $app.add_systems($schedule, ($($crate::plugin_system($plain),)*) $($suffix)*);
+        }
+    };
+}
+
+/// Adds one `on_enter_any:` system list to `OnEnter` of each of its states.
+/// The states are taken one at a time so the system list can be repeated.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_on_enter_any {
+    ($app:ident, [], [$($system:expr),*]) => {};
+
+    ($app:ident, [$state:expr $(, $more:expr)*], [$($system:expr),*]) => {
+        $($crate::define_plugin_trace!(on_enter_any, $system);)*
+        if !Self::DRY_RUN {
+            $app.add_systems(::bevy::prelude::OnEnter($state), ($($system,)*));
+        }
+        $crate::define_plugin_on_enter_any!($app, [$($more),*], [$($system),*]);
+    };
+}
+
+/// Registers a message according to its `add_message_with:` update policy.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_message_policy {
+    // Same as add_message: Bevy clears messages after two updates
+    ($app:ident, $message:ty, Automatic) => {
+        $app.add_message::<$message>();
+    };
+    // Only the Messages resource; the app is responsible for calling update()
+    ($app:ident, $message:ty, ManualUpdate) => {
+        $app.init_resource::<::bevy::ecs::message::Messages<$message>>();
+    };
+    ($app:ident, $message:ty, $unknown:ident) => {
+        compile_error!(concat!(
+            "Unknown message update policy: ",
+            stringify!($unknown),
+            "\nSupported policies: Automatic, ManualUpdate"
+        ));
+    };
+}
+
+/// Checks that the plugin behind a `requires_bevy_features:` entry was added.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_bevy_feature {
+    ($app:ident, "bevy_asset") => {
+        $crate::assert_bevy_feature_plugin::<Self, ::bevy::asset::AssetPlugin>($app, "bevy_asset");
+    };
+    ($app:ident, "bevy_audio") => {
+        $crate::assert_bevy_feature_plugin::<Self, ::bevy::audio::AudioPlugin>($app, "bevy_audio");
+    };
+    ($app:ident, "bevy_render") => {
+        $crate::assert_bevy_feature_plugin::<Self, ::bevy::render::RenderPlugin>($app, "bevy_render");
+    };
+    ($app:ident, "bevy_state") => {
+        $crate::assert_bevy_feature_plugin::<Self, ::bevy::state::app::StatesPlugin>($app, "bevy_state");
+    };
+    ($app:ident, "bevy_text") => {
+        $crate::assert_bevy_feature_plugin::<Self, ::bevy::text::TextPlugin>($app, "bevy_text");
+    };
+    ($app:ident, "bevy_ui") => {
+        $crate::assert_bevy_feature_plugin::<Self, ::bevy::ui::UiPlugin>($app, "bevy_ui");
+    };
+    ($app:ident, "bevy_window") => {
+        $crate::assert_bevy_feature_plugin::<Self, ::bevy::window::WindowPlugin>($app, "bevy_window");
+    };
+    ($app:ident, "bevy_winit") => {
+        $crate::assert_bevy_feature_plugin::<Self, ::bevy::winit::WinitPlugin>($app, "bevy_winit");
+    };
+    ($app:ident, $unknown:literal) => {
+        compile_error!(concat!(
+            "Unsupported bevy feature in requires_bevy_features: ",
+            $unknown,
+            "\nSupported features: bevy_asset, bevy_audio, bevy_render, bevy_state, bevy_text, bevy_ui, bevy_window, bevy_winit"
+        ));
+    };
+}
+
+/// Expands the first block if the key is a known configuration option and
+/// the `else` block otherwise.
+///
+/// Lets paths that skip most options stop at unknown keys instead of
+/// ignoring them. The unknown key itself is reported by
+/// `define_plugin_internal!`, so the error appears exactly once.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_if_known_option {
+    (depends_on, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (dependency_check, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (depends_on_bevy, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (requires_bevy_features, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (fields, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (settings_resource, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (constructors, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (meta, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (init_resource, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (insert_resource, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (insert_resource_if_absent, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (init_resource_with, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (inspectable_resources, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (add_message, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (add_message_with, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (add_message_buffered, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (add_plugins, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (add_plugins_configured, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (init_state, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (add_sub_state, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (register_type, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (register_components, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (auto_reflect_resources, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (add_observers, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (add_systems_startup, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (add_systems_update, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (add_systems_fixed_update, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (add_systems_state_transition, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (add_systems_startup_raw, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (add_systems_update_raw, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (add_systems_fixed_update_raw, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (ambiguous_with_all_update, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (add_systems_update_if, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (add_systems_on_enter, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (add_systems_on_exit, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (on_enter_any, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (with_conditions, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (distributive_run_if_update, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (flush_between_update, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (chain_startup, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (on_substate_update, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (update_if_resource_exists, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (add_systems_update_exclusive, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (update_if_windowed, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (update_every_n_frames, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (togglable, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (run_if, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (update_before, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (update_after, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (generate_system_set, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (init_schedule, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (add_systems_to, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (add_systems_to_many, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (custom_build, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (custom_finish, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (finish_init_resource, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (remove_resource, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (assert_resources_present, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (warn_if_empty, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (warn_if_transitions_without_states, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (trace_registrations, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (generate_debug_hud, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (generate_reflect_report, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (impl_debug, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (metadata_const_name, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (lint_custom_build, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (dry_run, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (is_unique, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (generate_tests, { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    ($unknown:tt, { $($then:tt)* } else { $($else:tt)* }) => { $($else)* };
+}
+
+/// Macro for handling Plugin finish() method configuration
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_finish {
+    // Empty configuration (base case) - default finish does nothing
+    ($app:ident,) => {};
+
+    // Skip all standard configurations (only process custom_finish and remove_resource)
+    ($app:ident, meta: { $($meta:tt)* } $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, depends_on: [$($dep:ty),* $(,)?] $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, dependency_check: $mode:tt $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, fields: { $($field:ident : $ty:ty),* $(,)? } $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, settings_resource: $settings:path $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, constructors: { $($constructor:ident($($arg:ident : $arg_ty:ty),* $(,)?)),* $(,)? } $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, init_resource: [$($resource:ty),* $(,)?] $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, insert_resource: [$($resource:expr),* $(,)?] $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, insert_resource_if_absent: { $($resource:ty => $value:expr),* $(,)? } $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, init_resource_with: { $($resource:ty => $init:expr),* $(,)? } $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, inspectable_resources: [$($resource:ty),* $(,)?] $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, add_message: [$($message:ty),* $(,)?] $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, add_message_with: { $($message:ty => $policy:ident),* $(,)? } $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, add_message_buffered: { $($message:ty => $frames:literal),* $(,)? } $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, add_plugins: [$($plugin:expr),* $(,)?] $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, add_plugins_configured: { $($config:tt)* } $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, init_state: [$($state:ty),* $(,)?] $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, add_sub_state: [$($state:ty),* $(,)?] $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, register_type: [$($reflect_type:ty),* $(,)?] $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, register_components: [$($component:ty),* $(,)?] $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, add_observers: [$($observer:expr),* $(,)?] $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, add_systems_startup: [$($system:expr),* $(,)?] $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, add_systems_update: [$($system:expr),* $(,)?] $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, add_systems_fixed_update: [$($system:expr),* $(,)?] $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, add_systems_state_transition: [$($systems:tt)*] $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, add_systems_startup_raw: $systems:expr $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, add_systems_update_raw: $systems:expr $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, add_systems_fixed_update_raw: $systems:expr $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, ambiguous_with_all_update: [$($system:expr),* $(,)?] $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, add_systems_update_if: { $($condition:expr => [$($system:expr),* $(,)?]),* $(,)? } $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, with_conditions: [$($condition:expr),* $(,)?] $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, distributive_run_if_update: $condition:expr $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, flush_between_update: $enabled:literal $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, chain_startup: $enabled:literal $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    // Sub-states may be added by any plugin, so check once all are built
+    ($app:ident, on_substate_update: { $($substate:expr => [$($system:expr),* $(,)?]),* $(,)? } $(, $($rest:tt)*)?) => {
+        $(
+            $crate::assert_sub_state_registered::<Self, _>($app, &$substate);
+        )*
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, update_if_resource_exists: { $($resource:ty => [$($system:expr),* $(,)?]),* $(,)? } $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, add_systems_update_exclusive: [$($system:expr),* $(,)?] $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, update_if_windowed: [$($system:expr),* $(,)?] $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, update_every_n_frames: { $($frames:literal => [$($system:expr),* $(,)?]),* $(,)? } $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, togglable: $enabled:literal $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, run_if: $condition:expr $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, update_before: [$($set:expr),* $(,)?] $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, update_after: [$($set:expr),* $(,)?] $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, generate_system_set: $set:ident $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, add_systems_on_enter: { $($entries:tt)* } $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, add_systems_on_exit: { $($entries:tt)* } $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, on_enter_any: { $([$($state:expr),* $(,)?] => [$($system:expr),* $(,)?]),* $(,)? } $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, init_schedule: [$($label:expr),* $(,)?] $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, add_systems_to: { $($label:expr => [$($system:expr),* $(,)?]),* $(,)? } $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, add_systems_to_many: { $([$($label:expr),* $(,)?] => [$($system:expr),* $(,)?]),* $(,)? } $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, custom_build: $build_fn:expr $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, warn_if_empty: $enabled:literal $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, warn_if_transitions_without_states: $enabled:literal $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, trace_registrations: $enabled:literal $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, generate_debug_hud: $enabled:literal $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    // Every plugin has been built, so the registry is complete
+    ($app:ident, generate_reflect_report: true $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_reflect_report_finish!($app);
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, generate_reflect_report: false $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, impl_debug: $enabled:literal $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, metadata_const_name: $name:ident $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, auto_reflect_resources: $enabled:literal $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, lint_custom_build: $enabled:literal $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, is_unique: $enabled:literal $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, dry_run: $enabled:literal $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+    ($app:ident, generate_tests: { $($test_config:tt)* } $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+
+    // Custom finish - this is what we're looking for!
+    ($app:ident, custom_finish: $finish_fn:expr $(, $($rest:tt)*)?) => {
+        $finish_fn($app);
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+
+    ($app:ident, depends_on_bevy: [$($plugin:ty),* $(,)?] $(, $($rest:tt)*)?) => {
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+
+    // Every plugin has been built, so the feature plugins must be added by now.
+    // The names are taken as `tt` so define_plugin_bevy_feature! can match them.
+    ($app:ident, requires_bevy_features: [$($feature:tt),* $(,)?] $(, $($rest:tt)*)?) => {
+        $(
+            $crate::define_plugin_bevy_feature!($app, $feature);
+        )*
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+
+    // Every plugin has been built, so dependencies' resources are present
+    ($app:ident, finish_init_resource: { $($resource:ty => $init:expr),* $(,)? } $(, $($rest:tt)*)?) => {
+        $(
+            $crate::define_plugin_trace!(finish_init_resource, $resource);
+            $crate::init_resource_with::<$resource>($app.world_mut(), $init);
+        )*
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+
+    // Every plugin has been built, so the resources must be present by now
+    ($app:ident, assert_resources_present: [$($resource:ty),* $(,)?] $(, $($rest:tt)*)?) => {
+        $(
+            $crate::assert_resource_present::<Self, $resource>($app);
+        )*
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+
+    // Remove resources that were only needed while building
+    ($app:ident, remove_resource: [$($resource:ty),* $(,)?] $(, $($rest:tt)*)?) => {
+        $(
+            $crate::define_plugin_trace!(remove_resource, $resource);
+            $app.world_mut().remove_resource::<$resource>();
+        )*
+        $crate::define_plugin_finish!($app, $($($rest)*)?);
+    };
+
+    // Handle all other configurations (catch-all for unknown tokens)
+    ($app:ident, $unknown:tt $($rest:tt)*) => {
+        $crate::define_plugin_finish!($app, $($rest)*);
+    };
+}
+
+// Feature gates for generated items. A `#[cfg(feature = ...)]` written into
+// the expansion would be evaluated against the *calling* crate's features, so
+// the gate is chosen here, when this crate is compiled.
+
+/// Expand the given items only with the `testing` feature
+#[cfg(feature = "testing")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_if_testing {
+    ($($item:tt)*) => { $($item)* };
+}
+
+/// Expand the given items only with the `testing` feature
+#[cfg(not(feature = "testing"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_if_testing {
+    ($($item:tt)*) => {};
+}
+
+/// Build-time sanity checks that need the plugin's metadata
+#[cfg(feature = "introspection")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_introspection_checks {
+    ($plugin:ty) => {
+        $crate::warn_on_systems_in_multiple_schedules::<$plugin>();
+    };
+}
+
+/// Without introspection there is no metadata to check
+#[cfg(not(feature = "introspection"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_introspection_checks {
+    ($plugin:ty) => {};
+}
+
+/// Registry-backed warnings, run in `finish()` once every plugin is registered
+#[cfg(feature = "introspection")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_introspection_finish_checks {
+    ($plugin:ty, $app:ident) => {
+        $crate::warn_on_overlapping_resources::<$plugin>($app);
+    };
+}
+
+/// Without introspection there is no registry to check
+#[cfg(not(feature = "introspection"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_introspection_finish_checks {
+    ($plugin:ty, $app:ident) => {};
+}
+
+/// Warn at build time if the plugin declares no systems and no resources
+#[cfg(feature = "introspection")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_warn_if_empty {
+    ($plugin:ty) => {
+        $crate::warn_if_empty::<$plugin>();
+    };
+}
+
+/// Without introspection the plugin's declared registrations are not recorded
+#[cfg(not(feature = "introspection"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_warn_if_empty {
+    ($plugin:ty) => {
+        compile_error!("warn_if_empty requires the `introspection` feature of bevy-plugin-builder");
+    };
+}
+
+/// Warn at build time if the plugin has OnEnter/OnExit systems but no states
+#[cfg(feature = "introspection")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_warn_if_transitions_without_states {
+    ($plugin:ty) => {
+        $crate::warn_if_transitions_without_states::<$plugin>();
+    };
+}
+
+/// Without introspection the plugin's states and transition systems are not
+/// recorded
+#[cfg(not(feature = "introspection"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_warn_if_transitions_without_states {
+    ($plugin:ty) => {
+        compile_error!("warn_if_transitions_without_states requires the `introspection` feature of bevy-plugin-builder");
+    };
+}
+
+/// Register the plugin and keep the `PluginDebugReport` up to date
+#[cfg(feature = "introspection")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_debug_hud {
+    ($plugin:ty, $app:ident) => {
+        $crate::enable_debug_hud::<$plugin>($app);
+    };
+}
+
+/// The debug report is built from the `PluginRegistry`, which needs introspection
+#[cfg(not(feature = "introspection"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_debug_hud {
+    ($plugin:ty, $app:ident) => {
+        compile_error!(
+            "generate_debug_hud requires the `introspection` feature of bevy-plugin-builder"
+        );
+    };
+}
+
+/// Register the plugin and the `ReflectablePluginReport` type
+#[cfg(feature = "reflect")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_reflect_report {
+    ($plugin:ty, $app:ident) => {
+        $crate::enable_reflect_report::<$plugin>($app);
+    };
+}
+
+/// The report derives `Reflect` only with the `reflect` feature
+#[cfg(not(feature = "reflect"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_reflect_report {
+    ($plugin:ty, $app:ident) => {
+        compile_error!(
+            "generate_reflect_report requires the `reflect` feature of bevy-plugin-builder"
+        );
+    };
+}
+
+/// Fill the `ReflectablePluginReport` from the registry
+#[cfg(feature = "reflect")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_reflect_report_finish {
+    ($app:ident) => {
+        $crate::update_reflect_report($app);
+    };
+}
+
+/// Already reported by define_plugin_reflect_report!
+#[cfg(not(feature = "reflect"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_reflect_report_finish {
+    ($app:ident) => {};
+}
+
+/// `Debug` for the plugin, printing its name and registration counts
+#[cfg(feature = "introspection")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_impl_debug {
+    ($plugin_name:ident) => {
+        impl ::std::fmt::Debug for $plugin_name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                let metadata = <Self as $crate::PluginInfo>::metadata();
+                f.debug_struct(metadata.name)
+                    .field("resources", &metadata.resources.len())
+                    .field("messages", &metadata.messages.len())
+                    .field("states", &metadata.states.len())
+                    .field("sub_plugins", &metadata.sub_plugins.len())
+                    .field("dependencies", &metadata.dependencies.len())
+                    .field("systems", &metadata.total_systems())
+                    .finish()
+            }
+        }
+    };
+}
+
+/// The counts come from the plugin's metadata, which needs introspection
+#[cfg(not(feature = "introspection"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_impl_debug {
+    ($plugin_name:ident) => {
+        compile_error!("impl_debug requires the `introspection` feature of bevy-plugin-builder");
+    };
+}
+
+// ============================================================================
+// Introspection support (feature-gated)
+// ============================================================================
+
+/// Helper macro to count items in a list (used for static array sizing)
+#[cfg(feature = "introspection")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! count_items {
+    () => { 0usize };
+    ($first:ty $(, $rest:ty)*) => {
+        1usize + $crate::count_items!($($rest),*)
+    };
+    ($first:expr $(, $rest:expr)*) => {
+        1usize + $crate::count_items!($($rest),*)
+    };
+}
+
+/// Helper macro to count the systems of a state transition map. An entry given
+/// as a single expression (e.g. `(a, b).chain()`) counts as one.
+#[cfg(feature = "introspection")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! count_state_systems {
+    ($(,)?) => { 0usize };
+    ($($state:ident)::+ if $condition:expr => $($entry:tt)*) => {
+        $crate::count_state_systems!($($state)::+ => $($entry)*)
+    };
+    ($state:expr => [$($system:expr),* $(,)?] $(.$method:ident($($args:tt)*))* $(, $($more:tt)*)?) => {
+        $crate::count_items!($($system),*) + $crate::count_state_systems!($($($more)*)?)
+    };
+    ($state:expr => $systems:expr $(, $($more:tt)*)?) => {
+        1usize + $crate::count_state_systems!($($($more)*)?)
+    };
+}
+
+/// Adds the entries of a startup/update/fixed update list to the metadata
+/// accumulator named by `$field`. Each entry is stored as `([attrs] system)`,
+/// keeping the attributes in front of it so `define_plugin_system_names!`
+/// leaves a `#[cfg]`-gated system out when it is compiled out.
+#[cfg(feature = "introspection")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_metadata_systems {
+    ({ $($before:tt)* } $field:ident: [$($old:tt),*] { $($after:tt)* } [$($attrs:tt)*] [# [$attr:meta] $($list:tt)*] { $($rest:tt)* }) => {
+        $crate::define_plugin_metadata_systems!(
+            { $($before)* } $field: [$($old),*] { $($after)* } [$($attrs)* #[$attr]] [$($list)*] { $($rest)* }
+        );
+    };
+    ({ $($before:tt)* } $field:ident: [$($old:tt),*] { $($after:tt)* } [$($attrs:tt)*] [$sys:expr $(, $($list:tt)*)?] { $($rest:tt)* }) => {
+        $crate::define_plugin_metadata_systems!(
+            { $($before)* } $field: [$($old,)* ([$($attrs)*] $sys)] { $($after)* } [] [$($($list)*)?] { $($rest)* }
+        );
+    };
+    ({ $($before:tt)* } $field:ident: [$($old:tt),*] { $($after:tt)* } [] [] { $($rest:tt)* }) => {
+        $crate::define_plugin_metadata_internal!(
+            $($before)*
+            $field: [$($old),*],
+            $($after)*
+            config: { $($rest)* }
+        );
+    };
+}
+
+/// Expands `([attrs] system)` entries to an array of system names, each
+/// behind its own attributes, so the names of `#[cfg]`-gated systems that are
+/// compiled out are left out too.
+#[cfg(feature = "introspection")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_system_names {
+    ([$($names:tt)*] ([$(#[$attr:meta])*] $system:expr) $($rest:tt)*) => {
+        $crate::define_plugin_system_names!([$($names)* $(#[$attr])* stringify!($system),] $($rest)*)
+    };
+    ([$($names:tt)*]) => {
+        [$($names)*]
+    };
+}
+
+/// Internal macro to extract metadata from plugin configuration.
+/// This generates static metadata when the introspection feature is enabled.
+#[cfg(feature = "introspection")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_metadata {
+    // Entry point - initialize accumulators and start processing
+    ($plugin_name:ident { $($config:tt)* }) => {
+        $crate::define_plugin_metadata_const!($plugin_name { $($config)* });
+        $crate::define_plugin_state_transition_names!($plugin_name [] { $($config)* });
+        $crate::define_plugin_custom_logic_flags!($plugin_name [false false] { $($config)* });
+        $crate::define_plugin_reflected_components!($plugin_name [] { $($config)* });
+        $crate::define_plugin_metadata_internal!(
+            $plugin_name,
+            // Accumulators: [resources] [messages] [states] [sub_states] [reflected] [plugins] [deps]
+            //               [startup_systems] [update_systems] [fixed_systems] [observers] [on_enter_count] [on_exit_count]
+            //               [version] [description]
+            resources: [],
+            messages: [],
+            states: [],
+            sub_states: [],
+            reflected: [],
+            plugins: [],
+            deps: [],
+            startup: [],
+            update: [],
+            fixed: [],
+            observers: [],
+            on_enter: 0,
+            on_exit: 0,
+            version: None,
+            description: None,
+            config: { $($config)* }
+        );
+    };
+}
+
+/// Without introspection no metadata is generated, so the configuration is
+/// not walked at all
+#[cfg(not(feature = "introspection"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_metadata {
+    ($plugin_name:ident { $($config:tt)* }) => {};
+}
+
+/// Internal recursive macro for accumulating metadata from configuration
+#[cfg(feature = "introspection")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_metadata_internal {
+    // ========================================================================
+    // Terminal case - generate the metadata structures
+    // ========================================================================
+    ($plugin_name:ident,
+        resources: [$($res:ty),*],
+        messages: [$($msg:ty),*],
+        states: [$($state:ty),*],
+        sub_states: [$($sub:ty),*],
+        reflected: [$($refl:ty),*],
+        plugins: [$($plug:expr),*],
+        deps: [$($dep:ty),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($update_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
+        on_enter: $on_enter_count:expr,
+        on_exit: $on_exit_count:expr,
+        version: $version:expr,
+        description: $description:expr,
+        config: {}
+    ) => {
+        // Every static lives in this anonymous const, so plugins declared in
+        // the same module (or by a wrapper macro) never share item names
+        const _: () = {
+            use $crate::{TypeInfo, PluginMetadata, PluginSystems, PluginInfo};
+
+            static RESOURCES: &[TypeInfo] = &[
+                $(TypeInfo::new::<$res>(stringify!($res)),)*
+            ];
+
+            static MESSAGES: &[TypeInfo] = &[
+                $(TypeInfo::new::<$msg>(stringify!($msg)),)*
+            ];
+
+            static STATES: &[TypeInfo] = &[
+                $(TypeInfo::new::<$state>(stringify!($state)),)*
+            ];
+
+            static SUB_STATES: &[TypeInfo] = &[
+                $(TypeInfo::new::<$sub>(stringify!($sub)),)*
+            ];
+
+            static REFLECTED: &[TypeInfo] = &[
+                $(TypeInfo::new::<$refl>(stringify!($refl)),)*
+            ];
+
+            static SUB_PLUGINS: &[&str] = &[
+                $(stringify!($plug),)*
+            ];
+
+            static DEPENDENCIES: &[&str] = &[
+                $(stringify!($dep),)*
+            ];
+
+            static STARTUP_SYSTEMS: &[&str] =
+                &$crate::define_plugin_system_names!([] $($startup_sys)*);
+
+            static UPDATE_SYSTEMS: &[&str] =
+                &$crate::define_plugin_system_names!([] $($update_sys)*);
+
+            static FIXED_SYSTEMS: &[&str] =
+                &$crate::define_plugin_system_names!([] $($fixed_sys)*);
+
+            static STATE_TRANSITION_SYSTEMS: &[&str] =
+                $plugin_name::__STATE_TRANSITION_SYSTEMS;
+
+            static OBSERVERS: &[&str] = &[
+                $(stringify!($observer),)*
+            ];
+
+            static METADATA: PluginMetadata = PluginMetadata {
+                name: stringify!($plugin_name),
+                display_name: $plugin_name::__DISPLAY_NAME,
+                version: $version,
+                description: $description,
+                resources: RESOURCES,
+                messages: MESSAGES,
+                states: STATES,
+                sub_states: SUB_STATES,
+                reflected_types: REFLECTED,
+                reflected_components: $plugin_name::__REFLECTED_COMPONENTS,
+                sub_plugins: SUB_PLUGINS,
+                dependencies: DEPENDENCIES,
+                observers: OBSERVERS,
+                has_custom_build: $plugin_name::__HAS_CUSTOM_BUILD,
+                has_custom_finish: $plugin_name::__HAS_CUSTOM_FINISH,
+                systems: PluginSystems {
+                    startup: STARTUP_SYSTEMS,
+                    update: UPDATE_SYSTEMS,
+                    fixed_update: FIXED_SYSTEMS,
+                    state_transition: STATE_TRANSITION_SYSTEMS,
+                    on_enter_count: $on_enter_count,
+                    on_exit_count: $on_exit_count,
+                },
+            };
+
+            // Read by the const that `metadata_const_name:` declares
+            impl $plugin_name {
+                #[doc(hidden)]
+                pub const __PLUGIN_METADATA: &'static PluginMetadata = &METADATA;
+            }
+
+            impl PluginInfo for $plugin_name {
+                const NAME: &'static str = stringify!($plugin_name);
+                const VERSION: Option<&'static str> = $version;
+                const STARTUP_SYSTEM_COUNT: usize =
+                    <[&str]>::len(&$crate::define_plugin_system_names!([] $($startup_sys)*));
+                const UPDATE_SYSTEM_COUNT: usize =
+                    <[&str]>::len(&$crate::define_plugin_system_names!([] $($update_sys)*));
+                const FIXED_SYSTEM_COUNT: usize =
+                    <[&str]>::len(&$crate::define_plugin_system_names!([] $($fixed_sys)*));
+
+                fn metadata() -> &'static PluginMetadata {
+                    &METADATA
+                }
+            }
+
+            // Higher-ranked bounds, so a non-plugin dependency is reported by
+            // assert_plugin_marker instead of here
+            impl $crate::PluginDependencyTree for $plugin_name
+            where
+                $(for<'a> $dep: $crate::PluginDependencyTree,)*
+            {
+                fn register_with_dependencies(registry: &mut $crate::PluginRegistry) {
+                    $(<$dep as $crate::PluginDependencyTree>::register_with_dependencies(registry);)*
+                    registry.register::<Self>();
+                }
+            }
+        };
+    };
+
+    // ========================================================================
+    // Parsing cases - extract metadata from each configuration option
+    // ========================================================================
+
+    // meta: version (entries are read one at a time, in any order)
+    ($plugin_name:ident,
+        resources: [$($res:ty),*],
+        messages: [$($msg:ty),*],
+        states: [$($state:ty),*],
+        sub_states: [$($sub:ty),*],
+        reflected: [$($refl:ty),*],
+        plugins: [$($plug:expr),*],
+        deps: [$($dep:ty),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($update_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
+        on_enter: $on_enter_count:expr,
+        on_exit: $on_exit_count:expr,
+        version: $_old_ver:expr,
+        description: $desc:expr,
+        config: { meta: { version: $ver:literal $(, $($meta_rest:tt)*)? } $(, $($rest:tt)*)? }
+    ) => {
+        $crate::define_plugin_metadata_internal!(
+            $plugin_name,
+            resources: [$($res),*],
+            messages: [$($msg),*],
+            states: [$($state),*],
+            sub_states: [$($sub),*],
+            reflected: [$($refl),*],
+            plugins: [$($plug),*],
+            deps: [$($dep),*],
+            startup: [$($startup_sys),*],
+            update: [$($update_sys),*],
+            fixed: [$($fixed_sys),*],
+            observers: [$($observer),*],
+            on_enter: $on_enter_count,
+            on_exit: $on_exit_count,
+            version: Some($ver),
+            description: $desc,
+            config: { meta: { $($($meta_rest)*)? } $(, $($rest)*)? }
+        );
+    };
+
+    // meta: description
+    ($plugin_name:ident,
+        resources: [$($res:ty),*],
+        messages: [$($msg:ty),*],
+        states: [$($state:ty),*],
+        sub_states: [$($sub:ty),*],
+        reflected: [$($refl:ty),*],
+        plugins: [$($plug:expr),*],
+        deps: [$($dep:ty),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($update_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
+        on_enter: $on_enter_count:expr,
+        on_exit: $on_exit_count:expr,
+        version: $ver:expr,
+        description: $_old_desc:expr,
+        config: { meta: { description: $desc:literal $(, $($meta_rest:tt)*)? } $(, $($rest:tt)*)? }
+    ) => {
+        $crate::define_plugin_metadata_internal!(
+            $plugin_name,
+            resources: [$($res),*],
+            messages: [$($msg),*],
+            states: [$($state),*],
+            sub_states: [$($sub),*],
+            reflected: [$($refl),*],
+            plugins: [$($plug),*],
+            deps: [$($dep),*],
+            startup: [$($startup_sys),*],
+            update: [$($update_sys),*],
+            fixed: [$($fixed_sys),*],
+            observers: [$($observer),*],
+            on_enter: $on_enter_count,
+            on_exit: $on_exit_count,
+            version: $ver,
+            description: Some($desc),
+            config: { meta: { $($($meta_rest)*)? } $(, $($rest)*)? }
+        );
+    };
+
+    // meta: default_settings (inserted in build; the type can't be read from the expr)
+    ($plugin_name:ident,
+        resources: [$($res:ty),*],
+        messages: [$($msg:ty),*],
+        states: [$($state:ty),*],
+        sub_states: [$($sub:ty),*],
+        reflected: [$($refl:ty),*],
+        plugins: [$($plug:expr),*],
+        deps: [$($dep:ty),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($update_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
+        on_enter: $on_enter_count:expr,
+        on_exit: $on_exit_count:expr,
+        version: $ver:expr,
+        description: $desc:expr,
+        config: { meta: { default_settings: $settings:expr $(, $($meta_rest:tt)*)? } $(, $($rest:tt)*)? }
+    ) => {
+        $crate::define_plugin_metadata_internal!(
+            $plugin_name,
+            resources: [$($res),*],
+            messages: [$($msg),*],
+            states: [$($state),*],
+            sub_states: [$($sub),*],
+            reflected: [$($refl),*],
+            plugins: [$($plug),*],
+            deps: [$($dep),*],
+            startup: [$($startup_sys),*],
+            update: [$($update_sys),*],
+            fixed: [$($fixed_sys),*],
+            observers: [$($observer),*],
+            on_enter: $on_enter_count,
+            on_exit: $on_exit_count,
+            version: $ver,
+            description: $desc,
+            config: { meta: { $($($meta_rest)*)? } $(, $($rest)*)? }
+        );
+    };
+
+    // meta: display_name (read by define_plugin_display_name!)
+    ($plugin_name:ident,
+        resources: [$($res:ty),*],
+        messages: [$($msg:ty),*],
+        states: [$($state:ty),*],
+        sub_states: [$($sub:ty),*],
+        reflected: [$($refl:ty),*],
+        plugins: [$($plug:expr),*],
+        deps: [$($dep:ty),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($update_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
+        on_enter: $on_enter_count:expr,
+        on_exit: $on_exit_count:expr,
+        version: $ver:expr,
+        description: $desc:expr,
+        config: { meta: { display_name: $display_name:literal $(, $($meta_rest:tt)*)? } $(, $($rest:tt)*)? }
+    ) => {
+        $crate::define_plugin_metadata_internal!(
+            $plugin_name,
+            resources: [$($res),*],
+            messages: [$($msg),*],
+            states: [$($state),*],
+            sub_states: [$($sub),*],
+            reflected: [$($refl),*],
+            plugins: [$($plug),*],
+            deps: [$($dep),*],
+            startup: [$($startup_sys),*],
+            update: [$($update_sys),*],
+            fixed: [$($fixed_sys),*],
+            observers: [$($observer),*],
+            on_enter: $on_enter_count,
+            on_exit: $on_exit_count,
+            version: $ver,
+            description: $desc,
+            config: { meta: { $($($meta_rest)*)? } $(, $($rest)*)? }
+        );
+    };
+
+    // meta: block fully read
+    ($plugin_name:ident,
+        resources: [$($res:ty),*],
+        messages: [$($msg:ty),*],
+        states: [$($state:ty),*],
+        sub_states: [$($sub:ty),*],
+        reflected: [$($refl:ty),*],
+        plugins: [$($plug:expr),*],
+        deps: [$($dep:ty),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($update_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
+        on_enter: $on_enter_count:expr,
+        on_exit: $on_exit_count:expr,
+        version: $ver:expr,
+        description: $desc:expr,
+        config: { meta: {} $(, $($rest:tt)*)? }
+    ) => {
+        $crate::define_plugin_metadata_internal!(
+            $plugin_name,
+            resources: [$($res),*],
+            messages: [$($msg),*],
+            states: [$($state),*],
+            sub_states: [$($sub),*],
+            reflected: [$($refl),*],
+            plugins: [$($plug),*],
+            deps: [$($dep),*],
+            startup: [$($startup_sys),*],
+            update: [$($update_sys),*],
+            fixed: [$($fixed_sys),*],
+            observers: [$($observer),*],
+            on_enter: $on_enter_count,
+            on_exit: $on_exit_count,
+            version: $ver,
+            description: $desc,
+            config: { $($($rest)*)? }
+        );
+    };
+
+    // Skip unknown meta formats
+    ($plugin_name:ident,
+        resources: [$($res:ty),*],
+        messages: [$($msg:ty),*],
+        states: [$($state:ty),*],
+        sub_states: [$($sub:ty),*],
+        reflected: [$($refl:ty),*],
+        plugins: [$($plug:expr),*],
+        deps: [$($dep:ty),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($update_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
+        on_enter: $on_enter_count:expr,
+        on_exit: $on_exit_count:expr,
+        version: $ver:expr,
+        description: $desc:expr,
+        config: { meta: { $($meta_contents:tt)* } $(, $($rest:tt)*)? }
+    ) => {
+        $crate::define_plugin_metadata_internal!(
+            $plugin_name,
+            resources: [$($res),*],
+            messages: [$($msg),*],
+            states: [$($state),*],
+            sub_states: [$($sub),*],
+            reflected: [$($refl),*],
+            plugins: [$($plug),*],
+            deps: [$($dep),*],
+            startup: [$($startup_sys),*],
+            update: [$($update_sys),*],
+            fixed: [$($fixed_sys),*],
+            observers: [$($observer),*],
+            on_enter: $on_enter_count,
+            on_exit: $on_exit_count,
+            version: $ver,
+            description: $desc,
+            config: { $($($rest)*)? }
+        );
+    };
+
+    // depends_on:
+    ($plugin_name:ident,
+        resources: [$($res:ty),*],
+        messages: [$($msg:ty),*],
+        states: [$($state:ty),*],
+        sub_states: [$($sub:ty),*],
+        reflected: [$($refl:ty),*],
+        plugins: [$($plug:expr),*],
+        deps: [$($old_dep:ty),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($update_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
+        on_enter: $on_enter_count:expr,
+        on_exit: $on_exit_count:expr,
+        version: $ver:expr,
+        description: $desc:expr,
+        config: { depends_on: [$($dep:ty),* $(,)?] $(, $($rest:tt)*)? }
+    ) => {
+        $crate::define_plugin_metadata_internal!(
+            $plugin_name,
+            resources: [$($res),*],
+            messages: [$($msg),*],
+            states: [$($state),*],
+            sub_states: [$($sub),*],
+            reflected: [$($refl),*],
+            plugins: [$($plug),*],
+            deps: [$($old_dep,)* $($dep),*],
+            startup: [$($startup_sys),*],
+            update: [$($update_sys),*],
+            fixed: [$($fixed_sys),*],
+            observers: [$($observer),*],
+            on_enter: $on_enter_count,
+            on_exit: $on_exit_count,
+            version: $ver,
+            description: $desc,
+            config: { $($($rest)*)? }
+        );
+    };
+
+    // init_resource: / resources:
+    ($plugin_name:ident,
+        resources: [$($old_res:ty),*],
+        messages: [$($msg:ty),*],
+        states: [$($state:ty),*],
+        sub_states: [$($sub:ty),*],
+        reflected: [$($refl:ty),*],
+        plugins: [$($plug:expr),*],
+        deps: [$($dep:ty),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($update_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
+        on_enter: $on_enter_count:expr,
+        on_exit: $on_exit_count:expr,
+        version: $ver:expr,
+        description: $desc:expr,
+        config: { init_resource: [$($res:ty),* $(,)?] $(, $($rest:tt)*)? }
+    ) => {
+        $crate::define_plugin_metadata_internal!(
+            $plugin_name,
+            resources: [$($old_res,)* $($res),*],
+            messages: [$($msg),*],
+            states: [$($state),*],
+            sub_states: [$($sub),*],
+            reflected: [$($refl),*],
+            plugins: [$($plug),*],
+            deps: [$($dep),*],
+            startup: [$($startup_sys),*],
+            update: [$($update_sys),*],
+            fixed: [$($fixed_sys),*],
+            observers: [$($observer),*],
+            on_enter: $on_enter_count,
+            on_exit: $on_exit_count,
+            version: $ver,
+            description: $desc,
+            config: { $($($rest)*)? }
+        );
+    };
+
+    // inspectable_resources: - recorded as both resources and reflected types
+    ($plugin_name:ident,
+        resources: [$($old_res:ty),*],
+        messages: [$($msg:ty),*],
+        states: [$($state:ty),*],
+        sub_states: [$($sub:ty),*],
+        reflected: [$($old_refl:ty),*],
+        plugins: [$($plug:expr),*],
+        deps: [$($dep:ty),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($update_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
+        on_enter: $on_enter_count:expr,
+        on_exit: $on_exit_count:expr,
+        version: $ver:expr,
+        description: $desc:expr,
+        config: { inspectable_resources: [$($res:ty),* $(,)?] $(, $($rest:tt)*)? }
+    ) => {
+        $crate::define_plugin_metadata_internal!(
+            $plugin_name,
+            resources: [$($old_res,)* $($res),*],
+            messages: [$($msg),*],
+            states: [$($state),*],
+            sub_states: [$($sub),*],
+            reflected: [$($old_refl,)* $($res),*],
+            plugins: [$($plug),*],
+            deps: [$($dep),*],
+            startup: [$($startup_sys),*],
+            update: [$($update_sys),*],
+            fixed: [$($fixed_sys),*],
+            observers: [$($observer),*],
+            on_enter: $on_enter_count,
+            on_exit: $on_exit_count,
+            version: $ver,
+            description: $desc,
+            config: { $($($rest)*)? }
+        );
+    };
+
+    // init_resource_with: - the types are known, the closures are not recorded
+    ($plugin_name:ident,
+        resources: [$($old_res:ty),*],
+        messages: [$($msg:ty),*],
+        states: [$($state:ty),*],
+        sub_states: [$($sub:ty),*],
+        reflected: [$($refl:ty),*],
+        plugins: [$($plug:expr),*],
+        deps: [$($dep:ty),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($update_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
+        on_enter: $on_enter_count:expr,
+        on_exit: $on_exit_count:expr,
+        version: $ver:expr,
+        description: $desc:expr,
+        config: { init_resource_with: { $($res:ty => $init:expr),* $(,)? } $(, $($rest:tt)*)? }
+    ) => {
+        $crate::define_plugin_metadata_internal!(
+            $plugin_name,
+            resources: [$($old_res,)* $($res),*],
+            messages: [$($msg),*],
+            states: [$($state),*],
+            sub_states: [$($sub),*],
+            reflected: [$($refl),*],
+            plugins: [$($plug),*],
+            deps: [$($dep),*],
+            startup: [$($startup_sys),*],
+            update: [$($update_sys),*],
+            fixed: [$($fixed_sys),*],
+            observers: [$($observer),*],
+            on_enter: $on_enter_count,
+            on_exit: $on_exit_count,
+            version: $ver,
+            description: $desc,
+            config: { $($($rest)*)? }
+        );
+    };
+
+    // finish_init_resource: - inserted in finish(), listed like init_resource_with
+    ($plugin_name:ident,
+        resources: [$($old_res:ty),*],
+        messages: [$($msg:ty),*],
+        states: [$($state:ty),*],
+        sub_states: [$($sub:ty),*],
+        reflected: [$($refl:ty),*],
+        plugins: [$($plug:expr),*],
+        deps: [$($dep:ty),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($update_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
+        on_enter: $on_enter_count:expr,
+        on_exit: $on_exit_count:expr,
+        version: $ver:expr,
+        description: $desc:expr,
+        config: { finish_init_resource: { $($res:ty => $init:expr),* $(,)? } $(, $($rest:tt)*)? }
+    ) => {
+        $crate::define_plugin_metadata_internal!(
+            $plugin_name,
+            resources: [$($old_res,)* $($res),*],
+            messages: [$($msg),*],
+            states: [$($state),*],
+            sub_states: [$($sub),*],
+            reflected: [$($refl),*],
+            plugins: [$($plug),*],
+            deps: [$($dep),*],
+            startup: [$($startup_sys),*],
+            update: [$($update_sys),*],
+            fixed: [$($fixed_sys),*],
+            observers: [$($observer),*],
+            on_enter: $on_enter_count,
+            on_exit: $on_exit_count,
+            version: $ver,
+            description: $desc,
+            config: { $($($rest)*)? }
+        );
+    };
+
+    // settings_resource: - built from the plugin's fields
+    ($plugin_name:ident,
+        resources: [$($old_res:ty),*],
+        messages: [$($msg:ty),*],
+        states: [$($state:ty),*],
+        sub_states: [$($sub:ty),*],
+        reflected: [$($refl:ty),*],
+        plugins: [$($plug:expr),*],
+        deps: [$($dep:ty),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($update_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
+        on_enter: $on_enter_count:expr,
+        on_exit: $on_exit_count:expr,
+        version: $ver:expr,
+        description: $desc:expr,
+        config: { settings_resource: $settings:ty $(, $($rest:tt)*)? }
+    ) => {
+        $crate::define_plugin_metadata_internal!(
+            $plugin_name,
+            resources: [$($old_res,)* $settings],
+            messages: [$($msg),*],
+            states: [$($state),*],
+            sub_states: [$($sub),*],
+            reflected: [$($refl),*],
+            plugins: [$($plug),*],
+            deps: [$($dep),*],
+            startup: [$($startup_sys),*],
+            update: [$($update_sys),*],
+            fixed: [$($fixed_sys),*],
+            observers: [$($observer),*],
+            on_enter: $on_enter_count,
+            on_exit: $on_exit_count,
+            version: $ver,
+            description: $desc,
+            config: { $($($rest)*)? }
+        );
+    };
+
+    // insert_resource: (skip - we can't easily get type from expr)
+    ($plugin_name:ident,
+        resources: [$($res:ty),*],
+        messages: [$($msg:ty),*],
+        states: [$($state:ty),*],
+        sub_states: [$($sub:ty),*],
+        reflected: [$($refl:ty),*],
+        plugins: [$($plug:expr),*],
+        deps: [$($dep:ty),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($update_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
+        on_enter: $on_enter_count:expr,
+        on_exit: $on_exit_count:expr,
+        version: $ver:expr,
+        description: $desc:expr,
+        config: { insert_resource: [$($resource:expr),* $(,)?] $(, $($rest:tt)*)? }
+    ) => {
+        $crate::define_plugin_metadata_internal!(
+            $plugin_name,
+            resources: [$($res),*],
+            messages: [$($msg),*],
+            states: [$($state),*],
+            sub_states: [$($sub),*],
+            reflected: [$($refl),*],
+            plugins: [$($plug),*],
+            deps: [$($dep),*],
+            startup: [$($startup_sys),*],
+            update: [$($update_sys),*],
+            fixed: [$($fixed_sys),*],
+            observers: [$($observer),*],
+            on_enter: $on_enter_count,
+            on_exit: $on_exit_count,
+            version: $ver,
+            description: $desc,
+            config: { $($($rest)*)? }
+        );
+    };
+
+    // add_message: / messages:
+    ($plugin_name:ident,
+        resources: [$($res:ty),*],
+        messages: [$($old_msg:ty),*],
+        states: [$($state:ty),*],
+        sub_states: [$($sub:ty),*],
+        reflected: [$($refl:ty),*],
+        plugins: [$($plug:expr),*],
+        deps: [$($dep:ty),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($update_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
+        on_enter: $on_enter_count:expr,
+        on_exit: $on_exit_count:expr,
+        version: $ver:expr,
+        description: $desc:expr,
+        config: { add_message: [$($msg:ty),* $(,)?] $(, $($rest:tt)*)? }
+    ) => {
+        $crate::define_plugin_metadata_internal!(
+            $plugin_name,
+            resources: [$($res),*],
+            messages: [$($old_msg,)* $($msg),*],
+            states: [$($state),*],
+            sub_states: [$($sub),*],
+            reflected: [$($refl),*],
+            plugins: [$($plug),*],
+            deps: [$($dep),*],
+            startup: [$($startup_sys),*],
+            update: [$($update_sys),*],
+            fixed: [$($fixed_sys),*],
+            observers: [$($observer),*],
+            on_enter: $on_enter_count,
+            on_exit: $on_exit_count,
+            version: $ver,
+            description: $desc,
+            config: { $($($rest)*)? }
+        );
+    };
+
+    // add_message_with:
+    ($plugin_name:ident,
+        resources: [$($res:ty),*],
+        messages: [$($old_msg:ty),*],
+        states: [$($state:ty),*],
+        sub_states: [$($sub:ty),*],
+        reflected: [$($refl:ty),*],
+        plugins: [$($plug:expr),*],
+        deps: [$($dep:ty),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($update_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
+        on_enter: $on_enter_count:expr,
+        on_exit: $on_exit_count:expr,
+        version: $ver:expr,
+        description: $desc:expr,
+        config: { add_message_with: { $($msg:ty => $policy:ident),* $(,)? } $(, $($rest:tt)*)? }
+    ) => {
+        $crate::define_plugin_metadata_internal!(
+            $plugin_name,
+            resources: [$($res),*],
+            messages: [$($old_msg,)* $($msg),*],
+            states: [$($state),*],
+            sub_states: [$($sub),*],
+            reflected: [$($refl),*],
+            plugins: [$($plug),*],
+            deps: [$($dep),*],
+            startup: [$($startup_sys),*],
+            update: [$($update_sys),*],
+            fixed: [$($fixed_sys),*],
+            observers: [$($observer),*],
+            on_enter: $on_enter_count,
+            on_exit: $on_exit_count,
+            version: $ver,
+            description: $desc,
+            config: { $($($rest)*)? }
+        );
+    };
+
+    // add_message_buffered:
+    ($plugin_name:ident,
+        resources: [$($res:ty),*],
+        messages: [$($old_msg:ty),*],
+        states: [$($state:ty),*],
+        sub_states: [$($sub:ty),*],
+        reflected: [$($refl:ty),*],
+        plugins: [$($plug:expr),*],
+        deps: [$($dep:ty),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($update_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
+        on_enter: $on_enter_count:expr,
+        on_exit: $on_exit_count:expr,
+        version: $ver:expr,
+        description: $desc:expr,
+        config: { add_message_buffered: { $($msg:ty => $frames:literal),* $(,)? } $(, $($rest:tt)*)? }
+    ) => {
+        $crate::define_plugin_metadata_internal!(
+            $plugin_name,
+            resources: [$($res),*],
+            messages: [$($old_msg,)* $($msg),*],
+            states: [$($state),*],
+            sub_states: [$($sub),*],
+            reflected: [$($refl),*],
+            plugins: [$($plug),*],
+            deps: [$($dep),*],
+            startup: [$($startup_sys),*],
+            update: [$($update_sys),*],
+            fixed: [$($fixed_sys),*],
+            observers: [$($observer),*],
+            on_enter: $on_enter_count,
+            on_exit: $on_exit_count,
+            version: $ver,
+            description: $desc,
+            config: { $($($rest)*)? }
+        );
+    };
+
+    // add_plugins: / plugins:
+    ($plugin_name:ident,
+        resources: [$($res:ty),*],
+        messages: [$($msg:ty),*],
+        states: [$($state:ty),*],
+        sub_states: [$($sub:ty),*],
+        reflected: [$($refl:ty),*],
+        plugins: [$($old_plug:expr),*],
+        deps: [$($dep:ty),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($update_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
+        on_enter: $on_enter_count:expr,
+        on_exit: $on_exit_count:expr,
+        version: $ver:expr,
+        description: $desc:expr,
+        config: { add_plugins: [$($plug:expr),* $(,)?] $(, $($rest:tt)*)? }
+    ) => {
+        $crate::define_plugin_metadata_internal!(
+            $plugin_name,
+            resources: [$($res),*],
+            messages: [$($msg),*],
+            states: [$($state),*],
+            sub_states: [$($sub),*],
+            reflected: [$($refl),*],
+            plugins: [$($old_plug,)* $($plug),*],
+            deps: [$($dep),*],
+            startup: [$($startup_sys),*],
+            update: [$($update_sys),*],
+            fixed: [$($fixed_sys),*],
+            observers: [$($observer),*],
+            on_enter: $on_enter_count,
+            on_exit: $on_exit_count,
+            version: $ver,
+            description: $desc,
+            config: { $($($rest)*)? }
+        );
     };
-}
 
-/// Internal macro to extract metadata from plugin configuration.
-/// This generates static metadata when the introspection feature is enabled.
-#[macro_export]
-#[doc(hidden)]
-macro_rules! define_plugin_metadata {
-    // Entry point - initialize accumulators and start processing
-    ($plugin_name:ident { $($config:tt)* }) => {
+    // add_observers:
+    ($plugin_name:ident,
+        resources: [$($res:ty),*],
+        messages: [$($msg:ty),*],
+        states: [$($state:ty),*],
+        sub_states: [$($sub:ty),*],
+        reflected: [$($refl:ty),*],
+        plugins: [$($plug:expr),*],
+        deps: [$($dep:ty),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($update_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($old_observer:expr),*],
+        on_enter: $on_enter_count:expr,
+        on_exit: $on_exit_count:expr,
+        version: $ver:expr,
+        description: $desc:expr,
+        config: { add_observers: [$($observer:expr),* $(,)?] $(, $($rest:tt)*)? }
+    ) => {
         $crate::define_plugin_metadata_internal!(
             $plugin_name,
-            // Accumulators: [resources] [messages] [states] [sub_states] [reflected] [plugins] [deps]
-            //               [startup_systems] [update_systems] [fixed_systems] [on_enter_count] [on_exit_count]
-            //               [version] [description]
-            resources: [],
-            messages: [],
-            states: [],
-            sub_states: [],
-            reflected: [],
-            plugins: [],
-            deps: [],
-            startup: [],
-            update: [],
-            fixed: [],
-            on_enter: 0,
-            on_exit: 0,
-            version: None,
-            description: None,
-            config: { $($config)* }
+            resources: [$($res),*],
+            messages: [$($msg),*],
+            states: [$($state),*],
+            sub_states: [$($sub),*],
+            reflected: [$($refl),*],
+            plugins: [$($plug),*],
+            deps: [$($dep),*],
+            startup: [$($startup_sys),*],
+            update: [$($update_sys),*],
+            fixed: [$($fixed_sys),*],
+            observers: [$($old_observer,)* $($observer),*],
+            on_enter: $on_enter_count,
+            on_exit: $on_exit_count,
+            version: $ver,
+            description: $desc,
+            config: { $($($rest)*)? }
         );
     };
-}
 
-/// Internal recursive macro for accumulating metadata from configuration
-#[macro_export]
-#[doc(hidden)]
-macro_rules! define_plugin_metadata_internal {
-    // ========================================================================
-    // Terminal case - generate the metadata structures
-    // ========================================================================
+    // add_plugins_configured: - each group counts as one sub-plugin
     ($plugin_name:ident,
         resources: [$($res:ty),*],
         messages: [$($msg:ty),*],
         states: [$($state:ty),*],
         sub_states: [$($sub:ty),*],
         reflected: [$($refl:ty),*],
-        plugins: [$($plug:expr),*],
+        plugins: [$($old_plug:expr),*],
         deps: [$($dep:ty),*],
-        startup: [$($startup_sys:expr),*],
-        update: [$($update_sys:expr),*],
-        fixed: [$($fixed_sys:expr),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($update_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
         on_enter: $on_enter_count:expr,
         on_exit: $on_exit_count:expr,
-        version: $version:expr,
-        description: $description:expr,
-        config: {}
+        version: $ver:expr,
+        description: $desc:expr,
+        config: { add_plugins_configured: { $($plug:expr => $(.$method:ident $(::<$($generic:ty),* $(,)?>)? ($($args:tt)*))+),* $(,)? } $(, $($rest:tt)*)? }
     ) => {
-        // Static arrays for type information
-        #[cfg(feature = "introspection")]
-        const _: () = {
-            use $crate::{TypeInfo, PluginMetadata, PluginSystems, PluginInfo};
-
-            static RESOURCES: &[TypeInfo] = &[
-                $(TypeInfo::new::<$res>(stringify!($res)),)*
-            ];
-
-            static MESSAGES: &[TypeInfo] = &[
-                $(TypeInfo::new::<$msg>(stringify!($msg)),)*
-            ];
-
-            static STATES: &[TypeInfo] = &[
-                $(TypeInfo::new::<$state>(stringify!($state)),)*
-            ];
-
-            static SUB_STATES: &[TypeInfo] = &[
-                $(TypeInfo::new::<$sub>(stringify!($sub)),)*
-            ];
-
-            static REFLECTED: &[TypeInfo] = &[
-                $(TypeInfo::new::<$refl>(stringify!($refl)),)*
-            ];
-
-            static SUB_PLUGINS: &[&str] = &[
-                $(stringify!($plug),)*
-            ];
-
-            static DEPENDENCIES: &[&str] = &[
-                $(stringify!($dep),)*
-            ];
-
-            static STARTUP_SYSTEMS: &[&str] = &[
-                $(stringify!($startup_sys),)*
-            ];
-
-            static UPDATE_SYSTEMS: &[&str] = &[
-                $(stringify!($update_sys),)*
-            ];
-
-            static FIXED_SYSTEMS: &[&str] = &[
-                $(stringify!($fixed_sys),)*
-            ];
-
-            static METADATA: PluginMetadata = PluginMetadata {
-                name: stringify!($plugin_name),
-                version: $version,
-                description: $description,
-                resources: RESOURCES,
-                messages: MESSAGES,
-                states: STATES,
-                sub_states: SUB_STATES,
-                reflected_types: REFLECTED,
-                sub_plugins: SUB_PLUGINS,
-                dependencies: DEPENDENCIES,
-                systems: PluginSystems {
-                    startup: STARTUP_SYSTEMS,
-                    update: UPDATE_SYSTEMS,
-                    fixed_update: FIXED_SYSTEMS,
-                    on_enter_count: $on_enter_count,
-                    on_exit_count: $on_exit_count,
-                },
-            };
-
-            impl PluginInfo for $plugin_name {
-                const NAME: &'static str = stringify!($plugin_name);
-                const VERSION: Option<&'static str> = $version;
-
-                fn metadata() -> &'static PluginMetadata {
-                    &METADATA
-                }
-            }
-        };
+        $crate::define_plugin_metadata_internal!(
+            $plugin_name,
+            resources: [$($res),*],
+            messages: [$($msg),*],
+            states: [$($state),*],
+            sub_states: [$($sub),*],
+            reflected: [$($refl),*],
+            plugins: [$($old_plug,)* $($plug),*],
+            deps: [$($dep),*],
+            startup: [$($startup_sys),*],
+            update: [$($update_sys),*],
+            fixed: [$($fixed_sys),*],
+            observers: [$($observer),*],
+            on_enter: $on_enter_count,
+            on_exit: $on_exit_count,
+            version: $ver,
+            description: $desc,
+            config: { $($($rest)*)? }
+        );
     };
 
-    // ========================================================================
-    // Parsing cases - extract metadata from each configuration option
-    // ========================================================================
-
-    // meta: block with version and/or description
+    // init_state: / states:
     ($plugin_name:ident,
         resources: [$($res:ty),*],
         messages: [$($msg:ty),*],
-        states: [$($state:ty),*],
+        states: [$($old_state:ty),*],
         sub_states: [$($sub:ty),*],
         reflected: [$($refl:ty),*],
         plugins: [$($plug:expr),*],
         deps: [$($dep:ty),*],
-        startup: [$($startup_sys:expr),*],
-        update: [$($update_sys:expr),*],
-        fixed: [$($fixed_sys:expr),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($update_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
         on_enter: $on_enter_count:expr,
         on_exit: $on_exit_count:expr,
-        version: $_old_ver:expr,
-        description: $_old_desc:expr,
-        config: { meta: { version: $ver:literal, description: $desc:literal } $(, $($rest:tt)*)? }
+        version: $ver:expr,
+        description: $desc:expr,
+        config: { init_state: [$($state:ty),* $(,)?] $(, $($rest:tt)*)? }
     ) => {
         $crate::define_plugin_metadata_internal!(
             $plugin_name,
             resources: [$($res),*],
             messages: [$($msg),*],
-            states: [$($state),*],
+            states: [$($old_state,)* $($state),*],
             sub_states: [$($sub),*],
             reflected: [$($refl),*],
             plugins: [$($plug),*],
@@ -592,69 +3904,73 @@ macro_rules! define_plugin_metadata_internal {
             startup: [$($startup_sys),*],
             update: [$($update_sys),*],
             fixed: [$($fixed_sys),*],
+            observers: [$($observer),*],
             on_enter: $on_enter_count,
             on_exit: $on_exit_count,
-            version: Some($ver),
-            description: Some($desc),
+            version: $ver,
+            description: $desc,
             config: { $($($rest)*)? }
         );
     };
 
-    // meta: block with version only
+    // add_sub_state: / sub_states:
     ($plugin_name:ident,
         resources: [$($res:ty),*],
         messages: [$($msg:ty),*],
         states: [$($state:ty),*],
-        sub_states: [$($sub:ty),*],
+        sub_states: [$($old_sub:ty),*],
         reflected: [$($refl:ty),*],
         plugins: [$($plug:expr),*],
         deps: [$($dep:ty),*],
-        startup: [$($startup_sys:expr),*],
-        update: [$($update_sys:expr),*],
-        fixed: [$($fixed_sys:expr),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($update_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
         on_enter: $on_enter_count:expr,
         on_exit: $on_exit_count:expr,
-        version: $_old_ver:expr,
+        version: $ver:expr,
         description: $desc:expr,
-        config: { meta: { version: $ver:literal } $(, $($rest:tt)*)? }
+        config: { add_sub_state: [$($sub:ty),* $(,)?] $(, $($rest:tt)*)? }
     ) => {
         $crate::define_plugin_metadata_internal!(
             $plugin_name,
             resources: [$($res),*],
             messages: [$($msg),*],
             states: [$($state),*],
-            sub_states: [$($sub),*],
+            sub_states: [$($old_sub,)* $($sub),*],
             reflected: [$($refl),*],
             plugins: [$($plug),*],
             deps: [$($dep),*],
             startup: [$($startup_sys),*],
             update: [$($update_sys),*],
             fixed: [$($fixed_sys),*],
+            observers: [$($observer),*],
             on_enter: $on_enter_count,
             on_exit: $on_exit_count,
-            version: Some($ver),
+            version: $ver,
             description: $desc,
             config: { $($($rest)*)? }
         );
     };
 
-    // meta: block with description only
+    // register_type: / reflect:
     ($plugin_name:ident,
         resources: [$($res:ty),*],
         messages: [$($msg:ty),*],
         states: [$($state:ty),*],
         sub_states: [$($sub:ty),*],
-        reflected: [$($refl:ty),*],
+        reflected: [$($old_refl:ty),*],
         plugins: [$($plug:expr),*],
         deps: [$($dep:ty),*],
-        startup: [$($startup_sys:expr),*],
-        update: [$($update_sys:expr),*],
-        fixed: [$($fixed_sys:expr),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($update_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
         on_enter: $on_enter_count:expr,
         on_exit: $on_exit_count:expr,
         version: $ver:expr,
-        description: $_old_desc:expr,
-        config: { meta: { description: $desc:literal } $(, $($rest:tt)*)? }
+        description: $desc:expr,
+        config: { register_type: [$($refl:ty),* $(,)?] $(, $($rest:tt)*)? }
     ) => {
         $crate::define_plugin_metadata_internal!(
             $plugin_name,
@@ -662,21 +3978,22 @@ macro_rules! define_plugin_metadata_internal {
             messages: [$($msg),*],
             states: [$($state),*],
             sub_states: [$($sub),*],
-            reflected: [$($refl),*],
+            reflected: [$($old_refl,)* $($refl),*],
             plugins: [$($plug),*],
             deps: [$($dep),*],
             startup: [$($startup_sys),*],
             update: [$($update_sys),*],
             fixed: [$($fixed_sys),*],
+            observers: [$($observer),*],
             on_enter: $on_enter_count,
             on_exit: $on_exit_count,
             version: $ver,
-            description: Some($desc),
+            description: $desc,
             config: { $($($rest)*)? }
         );
     };
 
-    // Skip unknown meta formats
+    // add_systems_startup: / startup:
     ($plugin_name:ident,
         resources: [$($res:ty),*],
         messages: [$($msg:ty),*],
@@ -685,14 +4002,107 @@ macro_rules! define_plugin_metadata_internal {
         reflected: [$($refl:ty),*],
         plugins: [$($plug:expr),*],
         deps: [$($dep:ty),*],
-        startup: [$($startup_sys:expr),*],
-        update: [$($update_sys:expr),*],
-        fixed: [$($fixed_sys:expr),*],
+        startup: [$($old_sys:tt),*],
+        update: [$($update_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
         on_enter: $on_enter_count:expr,
         on_exit: $on_exit_count:expr,
         version: $ver:expr,
         description: $desc:expr,
-        config: { meta: { $($meta_contents:tt)* } $(, $($rest:tt)*)? }
+        config: { add_systems_startup: [$($list:tt)*] $(, $($rest:tt)*)? }
+    ) => {
+        $crate::define_plugin_metadata_systems!(
+            {
+                $plugin_name,
+                resources: [$($res),*],
+                messages: [$($msg),*],
+                states: [$($state),*],
+                sub_states: [$($sub),*],
+                reflected: [$($refl),*],
+                plugins: [$($plug),*],
+                deps: [$($dep),*],
+            }
+            startup: [$($old_sys),*]
+            {
+                update: [$($update_sys),*],
+                fixed: [$($fixed_sys),*],
+                observers: [$($observer),*],
+                on_enter: $on_enter_count,
+                on_exit: $on_exit_count,
+                version: $ver,
+                description: $desc,
+            }
+            []
+            [$($list)*]
+            { $($($rest)*)? }
+        );
+    };
+
+    // add_systems_update: / update:
+    ($plugin_name:ident,
+        resources: [$($res:ty),*],
+        messages: [$($msg:ty),*],
+        states: [$($state:ty),*],
+        sub_states: [$($sub:ty),*],
+        reflected: [$($refl:ty),*],
+        plugins: [$($plug:expr),*],
+        deps: [$($dep:ty),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($old_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
+        on_enter: $on_enter_count:expr,
+        on_exit: $on_exit_count:expr,
+        version: $ver:expr,
+        description: $desc:expr,
+        config: { add_systems_update: [$($list:tt)*] $(, $($rest:tt)*)? }
+    ) => {
+        $crate::define_plugin_metadata_systems!(
+            {
+                $plugin_name,
+                resources: [$($res),*],
+                messages: [$($msg),*],
+                states: [$($state),*],
+                sub_states: [$($sub),*],
+                reflected: [$($refl),*],
+                plugins: [$($plug),*],
+                deps: [$($dep),*],
+                startup: [$($startup_sys),*],
+            }
+            update: [$($old_sys),*]
+            {
+                fixed: [$($fixed_sys),*],
+                observers: [$($observer),*],
+                on_enter: $on_enter_count,
+                on_exit: $on_exit_count,
+                version: $ver,
+                description: $desc,
+            }
+            []
+            [$($list)*]
+            { $($($rest)*)? }
+        );
+    };
+
+    // add_systems_startup_raw: - the whole expression counts as one startup entry
+    ($plugin_name:ident,
+        resources: [$($res:ty),*],
+        messages: [$($msg:ty),*],
+        states: [$($state:ty),*],
+        sub_states: [$($sub:ty),*],
+        reflected: [$($refl:ty),*],
+        plugins: [$($plug:expr),*],
+        deps: [$($dep:ty),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($old_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
+        on_enter: $on_enter_count:expr,
+        on_exit: $on_exit_count:expr,
+        version: $ver:expr,
+        description: $desc:expr,
+        config: { add_systems_startup_raw: $systems:expr $(, $($rest:tt)*)? }
     ) => {
         $crate::define_plugin_metadata_internal!(
             $plugin_name,
@@ -703,9 +4113,10 @@ macro_rules! define_plugin_metadata_internal {
             reflected: [$($refl),*],
             plugins: [$($plug),*],
             deps: [$($dep),*],
-            startup: [$($startup_sys),*],
-            update: [$($update_sys),*],
+            startup: [$($startup_sys,)* ([] $systems)],
+            update: [$($old_sys),*],
             fixed: [$($fixed_sys),*],
+            observers: [$($observer),*],
             on_enter: $on_enter_count,
             on_exit: $on_exit_count,
             version: $ver,
@@ -714,7 +4125,7 @@ macro_rules! define_plugin_metadata_internal {
         );
     };
 
-    // depends_on:
+    // add_systems_update_raw: - the whole expression counts as one update entry
     ($plugin_name:ident,
         resources: [$($res:ty),*],
         messages: [$($msg:ty),*],
@@ -722,15 +4133,16 @@ macro_rules! define_plugin_metadata_internal {
         sub_states: [$($sub:ty),*],
         reflected: [$($refl:ty),*],
         plugins: [$($plug:expr),*],
-        deps: [$($old_dep:ty),*],
-        startup: [$($startup_sys:expr),*],
-        update: [$($update_sys:expr),*],
-        fixed: [$($fixed_sys:expr),*],
+        deps: [$($dep:ty),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($old_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
         on_enter: $on_enter_count:expr,
         on_exit: $on_exit_count:expr,
         version: $ver:expr,
         description: $desc:expr,
-        config: { depends_on: [$($dep:ty),* $(,)?] $(, $($rest:tt)*)? }
+        config: { add_systems_update_raw: $systems:expr $(, $($rest:tt)*)? }
     ) => {
         $crate::define_plugin_metadata_internal!(
             $plugin_name,
@@ -740,10 +4152,11 @@ macro_rules! define_plugin_metadata_internal {
             sub_states: [$($sub),*],
             reflected: [$($refl),*],
             plugins: [$($plug),*],
-            deps: [$($old_dep,)* $($dep),*],
+            deps: [$($dep),*],
             startup: [$($startup_sys),*],
-            update: [$($update_sys),*],
+            update: [$($old_sys,)* ([] $systems)],
             fixed: [$($fixed_sys),*],
+            observers: [$($observer),*],
             on_enter: $on_enter_count,
             on_exit: $on_exit_count,
             version: $ver,
@@ -752,27 +4165,28 @@ macro_rules! define_plugin_metadata_internal {
         );
     };
 
-    // init_resource: / resources:
+    // add_systems_fixed_update_raw: - the whole expression counts as one fixed update entry
     ($plugin_name:ident,
-        resources: [$($old_res:ty),*],
+        resources: [$($res:ty),*],
         messages: [$($msg:ty),*],
         states: [$($state:ty),*],
         sub_states: [$($sub:ty),*],
         reflected: [$($refl:ty),*],
         plugins: [$($plug:expr),*],
         deps: [$($dep:ty),*],
-        startup: [$($startup_sys:expr),*],
-        update: [$($update_sys:expr),*],
-        fixed: [$($fixed_sys:expr),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($old_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
         on_enter: $on_enter_count:expr,
         on_exit: $on_exit_count:expr,
         version: $ver:expr,
         description: $desc:expr,
-        config: { init_resource: [$($res:ty),* $(,)?] $(, $($rest:tt)*)? }
+        config: { add_systems_fixed_update_raw: $systems:expr $(, $($rest:tt)*)? }
     ) => {
         $crate::define_plugin_metadata_internal!(
             $plugin_name,
-            resources: [$($old_res,)* $($res),*],
+            resources: [$($res),*],
             messages: [$($msg),*],
             states: [$($state),*],
             sub_states: [$($sub),*],
@@ -780,8 +4194,9 @@ macro_rules! define_plugin_metadata_internal {
             plugins: [$($plug),*],
             deps: [$($dep),*],
             startup: [$($startup_sys),*],
-            update: [$($update_sys),*],
-            fixed: [$($fixed_sys),*],
+            update: [$($old_sys),*],
+            fixed: [$($fixed_sys,)* ([] $systems)],
+            observers: [$($observer),*],
             on_enter: $on_enter_count,
             on_exit: $on_exit_count,
             version: $ver,
@@ -790,7 +4205,7 @@ macro_rules! define_plugin_metadata_internal {
         );
     };
 
-    // insert_resource: (skip - we can't easily get type from expr)
+    // ambiguous_with_all_update: - counted as update systems
     ($plugin_name:ident,
         resources: [$($res:ty),*],
         messages: [$($msg:ty),*],
@@ -799,14 +4214,15 @@ macro_rules! define_plugin_metadata_internal {
         reflected: [$($refl:ty),*],
         plugins: [$($plug:expr),*],
         deps: [$($dep:ty),*],
-        startup: [$($startup_sys:expr),*],
-        update: [$($update_sys:expr),*],
-        fixed: [$($fixed_sys:expr),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($old_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
         on_enter: $on_enter_count:expr,
         on_exit: $on_exit_count:expr,
         version: $ver:expr,
         description: $desc:expr,
-        config: { insert_resource: [$($resource:expr),* $(,)?] $(, $($rest:tt)*)? }
+        config: { ambiguous_with_all_update: [$($sys:expr),* $(,)?] $(, $($rest:tt)*)? }
     ) => {
         $crate::define_plugin_metadata_internal!(
             $plugin_name,
@@ -818,8 +4234,9 @@ macro_rules! define_plugin_metadata_internal {
             plugins: [$($plug),*],
             deps: [$($dep),*],
             startup: [$($startup_sys),*],
-            update: [$($update_sys),*],
+            update: [$($old_sys,)* $(([] $sys)),*],
             fixed: [$($fixed_sys),*],
+            observers: [$($observer),*],
             on_enter: $on_enter_count,
             on_exit: $on_exit_count,
             version: $ver,
@@ -828,36 +4245,38 @@ macro_rules! define_plugin_metadata_internal {
         );
     };
 
-    // add_message: / messages:
+    // add_systems_update_exclusive: - counted as update systems
     ($plugin_name:ident,
         resources: [$($res:ty),*],
-        messages: [$($old_msg:ty),*],
+        messages: [$($msg:ty),*],
         states: [$($state:ty),*],
         sub_states: [$($sub:ty),*],
         reflected: [$($refl:ty),*],
         plugins: [$($plug:expr),*],
         deps: [$($dep:ty),*],
-        startup: [$($startup_sys:expr),*],
-        update: [$($update_sys:expr),*],
-        fixed: [$($fixed_sys:expr),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($old_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
         on_enter: $on_enter_count:expr,
         on_exit: $on_exit_count:expr,
         version: $ver:expr,
         description: $desc:expr,
-        config: { add_message: [$($msg:ty),* $(,)?] $(, $($rest:tt)*)? }
+        config: { add_systems_update_exclusive: [$($sys:expr),* $(,)?] $(, $($rest:tt)*)? }
     ) => {
         $crate::define_plugin_metadata_internal!(
             $plugin_name,
             resources: [$($res),*],
-            messages: [$($old_msg,)* $($msg),*],
+            messages: [$($msg),*],
             states: [$($state),*],
             sub_states: [$($sub),*],
             reflected: [$($refl),*],
             plugins: [$($plug),*],
             deps: [$($dep),*],
             startup: [$($startup_sys),*],
-            update: [$($update_sys),*],
+            update: [$($old_sys,)* $(([] $sys)),*],
             fixed: [$($fixed_sys),*],
+            observers: [$($observer),*],
             on_enter: $on_enter_count,
             on_exit: $on_exit_count,
             version: $ver,
@@ -866,23 +4285,24 @@ macro_rules! define_plugin_metadata_internal {
         );
     };
 
-    // add_plugins: / plugins:
+    // update_if_windowed: - counted as update systems
     ($plugin_name:ident,
         resources: [$($res:ty),*],
         messages: [$($msg:ty),*],
         states: [$($state:ty),*],
         sub_states: [$($sub:ty),*],
         reflected: [$($refl:ty),*],
-        plugins: [$($old_plug:expr),*],
+        plugins: [$($plug:expr),*],
         deps: [$($dep:ty),*],
-        startup: [$($startup_sys:expr),*],
-        update: [$($update_sys:expr),*],
-        fixed: [$($fixed_sys:expr),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($old_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
         on_enter: $on_enter_count:expr,
         on_exit: $on_exit_count:expr,
         version: $ver:expr,
         description: $desc:expr,
-        config: { add_plugins: [$($plug:expr),* $(,)?] $(, $($rest:tt)*)? }
+        config: { update_if_windowed: [$($sys:expr),* $(,)?] $(, $($rest:tt)*)? }
     ) => {
         $crate::define_plugin_metadata_internal!(
             $plugin_name,
@@ -891,11 +4311,12 @@ macro_rules! define_plugin_metadata_internal {
             states: [$($state),*],
             sub_states: [$($sub),*],
             reflected: [$($refl),*],
-            plugins: [$($old_plug,)* $($plug),*],
+            plugins: [$($plug),*],
             deps: [$($dep),*],
             startup: [$($startup_sys),*],
-            update: [$($update_sys),*],
+            update: [$($old_sys,)* $(([] $sys)),*],
             fixed: [$($fixed_sys),*],
+            observers: [$($observer),*],
             on_enter: $on_enter_count,
             on_exit: $on_exit_count,
             version: $ver,
@@ -904,99 +4325,104 @@ macro_rules! define_plugin_metadata_internal {
         );
     };
 
-    // init_state: / states:
+    // on_substate_update: - counted as update systems, one entry at a time
     ($plugin_name:ident,
         resources: [$($res:ty),*],
         messages: [$($msg:ty),*],
-        states: [$($old_state:ty),*],
+        states: [$($state:ty),*],
         sub_states: [$($sub:ty),*],
         reflected: [$($refl:ty),*],
         plugins: [$($plug:expr),*],
         deps: [$($dep:ty),*],
-        startup: [$($startup_sys:expr),*],
-        update: [$($update_sys:expr),*],
-        fixed: [$($fixed_sys:expr),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($old_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
         on_enter: $on_enter_count:expr,
         on_exit: $on_exit_count:expr,
         version: $ver:expr,
         description: $desc:expr,
-        config: { init_state: [$($state:ty),* $(,)?] $(, $($rest:tt)*)? }
+        config: { on_substate_update: { $substate:expr => [$($sys:expr),* $(,)?] $(, $($more:tt)*)? } $(, $($rest:tt)*)? }
     ) => {
         $crate::define_plugin_metadata_internal!(
             $plugin_name,
             resources: [$($res),*],
             messages: [$($msg),*],
-            states: [$($old_state,)* $($state),*],
+            states: [$($state),*],
             sub_states: [$($sub),*],
             reflected: [$($refl),*],
             plugins: [$($plug),*],
             deps: [$($dep),*],
             startup: [$($startup_sys),*],
-            update: [$($update_sys),*],
+            update: [$($old_sys,)* $(([] $sys)),*],
             fixed: [$($fixed_sys),*],
+            observers: [$($observer),*],
             on_enter: $on_enter_count,
             on_exit: $on_exit_count,
             version: $ver,
             description: $desc,
-            config: { $($($rest)*)? }
+            config: { on_substate_update: { $($($more)*)? } $(, $($rest)*)? }
         );
     };
 
-    // add_sub_state: / sub_states:
+    // update_if_resource_exists: - counted as update systems, one entry at a time
     ($plugin_name:ident,
         resources: [$($res:ty),*],
         messages: [$($msg:ty),*],
         states: [$($state:ty),*],
-        sub_states: [$($old_sub:ty),*],
+        sub_states: [$($sub:ty),*],
         reflected: [$($refl:ty),*],
         plugins: [$($plug:expr),*],
         deps: [$($dep:ty),*],
-        startup: [$($startup_sys:expr),*],
-        update: [$($update_sys:expr),*],
-        fixed: [$($fixed_sys:expr),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($old_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
         on_enter: $on_enter_count:expr,
         on_exit: $on_exit_count:expr,
         version: $ver:expr,
         description: $desc:expr,
-        config: { add_sub_state: [$($sub:ty),* $(,)?] $(, $($rest:tt)*)? }
+        config: { update_if_resource_exists: { $resource:ty => [$($sys:expr),* $(,)?] $(, $($more:tt)*)? } $(, $($rest:tt)*)? }
     ) => {
         $crate::define_plugin_metadata_internal!(
             $plugin_name,
             resources: [$($res),*],
             messages: [$($msg),*],
             states: [$($state),*],
-            sub_states: [$($old_sub,)* $($sub),*],
+            sub_states: [$($sub),*],
             reflected: [$($refl),*],
             plugins: [$($plug),*],
             deps: [$($dep),*],
             startup: [$($startup_sys),*],
-            update: [$($update_sys),*],
+            update: [$($old_sys,)* $(([] $sys)),*],
             fixed: [$($fixed_sys),*],
+            observers: [$($observer),*],
             on_enter: $on_enter_count,
             on_exit: $on_exit_count,
             version: $ver,
             description: $desc,
-            config: { $($($rest)*)? }
+            config: { update_if_resource_exists: { $($($more)*)? } $(, $($rest)*)? }
         );
     };
 
-    // register_type: / reflect:
+    // update_every_n_frames: - counted as update systems, one entry at a time
     ($plugin_name:ident,
         resources: [$($res:ty),*],
         messages: [$($msg:ty),*],
         states: [$($state:ty),*],
         sub_states: [$($sub:ty),*],
-        reflected: [$($old_refl:ty),*],
+        reflected: [$($refl:ty),*],
         plugins: [$($plug:expr),*],
         deps: [$($dep:ty),*],
-        startup: [$($startup_sys:expr),*],
-        update: [$($update_sys:expr),*],
-        fixed: [$($fixed_sys:expr),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($old_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
         on_enter: $on_enter_count:expr,
         on_exit: $on_exit_count:expr,
         version: $ver:expr,
         description: $desc:expr,
-        config: { register_type: [$($refl:ty),* $(,)?] $(, $($rest:tt)*)? }
+        config: { update_every_n_frames: { $frames:literal => [$($sys:expr),* $(,)?] $(, $($more:tt)*)? } $(, $($rest:tt)*)? }
     ) => {
         $crate::define_plugin_metadata_internal!(
             $plugin_name,
@@ -1004,21 +4430,22 @@ macro_rules! define_plugin_metadata_internal {
             messages: [$($msg),*],
             states: [$($state),*],
             sub_states: [$($sub),*],
-            reflected: [$($old_refl,)* $($refl),*],
+            reflected: [$($refl),*],
             plugins: [$($plug),*],
             deps: [$($dep),*],
             startup: [$($startup_sys),*],
-            update: [$($update_sys),*],
+            update: [$($old_sys,)* $(([] $sys)),*],
             fixed: [$($fixed_sys),*],
+            observers: [$($observer),*],
             on_enter: $on_enter_count,
             on_exit: $on_exit_count,
             version: $ver,
             description: $desc,
-            config: { $($($rest)*)? }
+            config: { update_every_n_frames: { $($($more)*)? } $(, $($rest)*)? }
         );
     };
 
-    // add_systems_startup: / startup:
+    // add_systems_update_if: - counted as update systems whether or not the condition holds
     ($plugin_name:ident,
         resources: [$($res:ty),*],
         messages: [$($msg:ty),*],
@@ -1027,14 +4454,15 @@ macro_rules! define_plugin_metadata_internal {
         reflected: [$($refl:ty),*],
         plugins: [$($plug:expr),*],
         deps: [$($dep:ty),*],
-        startup: [$($old_sys:expr),*],
-        update: [$($update_sys:expr),*],
-        fixed: [$($fixed_sys:expr),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($old_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
         on_enter: $on_enter_count:expr,
         on_exit: $on_exit_count:expr,
         version: $ver:expr,
         description: $desc:expr,
-        config: { add_systems_startup: [$($sys:expr),* $(,)?] $(, $($rest:tt)*)? }
+        config: { add_systems_update_if: { $condition:expr => [$($sys:expr),* $(,)?] $(, $($more:tt)*)? } $(, $($rest:tt)*)? }
     ) => {
         $crate::define_plugin_metadata_internal!(
             $plugin_name,
@@ -1045,18 +4473,65 @@ macro_rules! define_plugin_metadata_internal {
             reflected: [$($refl),*],
             plugins: [$($plug),*],
             deps: [$($dep),*],
-            startup: [$($old_sys,)* $($sys),*],
-            update: [$($update_sys),*],
+            startup: [$($startup_sys),*],
+            update: [$($old_sys,)* $(([] $sys)),*],
             fixed: [$($fixed_sys),*],
+            observers: [$($observer),*],
             on_enter: $on_enter_count,
             on_exit: $on_exit_count,
             version: $ver,
             description: $desc,
-            config: { $($($rest)*)? }
+            config: { add_systems_update_if: { $($($more)*)? } $(, $($rest)*)? }
         );
     };
 
-    // add_systems_update: / update:
+    // add_systems_fixed_update: / fixed_update:
+    ($plugin_name:ident,
+        resources: [$($res:ty),*],
+        messages: [$($msg:ty),*],
+        states: [$($state:ty),*],
+        sub_states: [$($sub:ty),*],
+        reflected: [$($refl:ty),*],
+        plugins: [$($plug:expr),*],
+        deps: [$($dep:ty),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($update_sys:tt),*],
+        fixed: [$($old_sys:tt),*],
+        observers: [$($observer:expr),*],
+        on_enter: $on_enter_count:expr,
+        on_exit: $on_exit_count:expr,
+        version: $ver:expr,
+        description: $desc:expr,
+        config: { add_systems_fixed_update: [$($list:tt)*] $(, $($rest:tt)*)? }
+    ) => {
+        $crate::define_plugin_metadata_systems!(
+            {
+                $plugin_name,
+                resources: [$($res),*],
+                messages: [$($msg),*],
+                states: [$($state),*],
+                sub_states: [$($sub),*],
+                reflected: [$($refl),*],
+                plugins: [$($plug),*],
+                deps: [$($dep),*],
+                startup: [$($startup_sys),*],
+                update: [$($update_sys),*],
+            }
+            fixed: [$($old_sys),*]
+            {
+                observers: [$($observer),*],
+                on_enter: $on_enter_count,
+                on_exit: $on_exit_count,
+                version: $ver,
+                description: $desc,
+            }
+            []
+            [$($list)*]
+            { $($($rest)*)? }
+        );
+    };
+
+    // add_systems_on_enter: / on_enter: (count entries for metadata)
     ($plugin_name:ident,
         resources: [$($res:ty),*],
         messages: [$($msg:ty),*],
@@ -1065,14 +4540,15 @@ macro_rules! define_plugin_metadata_internal {
         reflected: [$($refl:ty),*],
         plugins: [$($plug:expr),*],
         deps: [$($dep:ty),*],
-        startup: [$($startup_sys:expr),*],
-        update: [$($old_sys:expr),*],
-        fixed: [$($fixed_sys:expr),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($update_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
         on_enter: $on_enter_count:expr,
         on_exit: $on_exit_count:expr,
         version: $ver:expr,
         description: $desc:expr,
-        config: { add_systems_update: [$($sys:expr),* $(,)?] $(, $($rest:tt)*)? }
+        config: { add_systems_on_enter: { $($entries:tt)* } $(, $($rest:tt)*)? }
     ) => {
         $crate::define_plugin_metadata_internal!(
             $plugin_name,
@@ -1084,9 +4560,10 @@ macro_rules! define_plugin_metadata_internal {
             plugins: [$($plug),*],
             deps: [$($dep),*],
             startup: [$($startup_sys),*],
-            update: [$($old_sys,)* $($sys),*],
+            update: [$($update_sys),*],
             fixed: [$($fixed_sys),*],
-            on_enter: $on_enter_count,
+            observers: [$($observer),*],
+            on_enter: $on_enter_count + $crate::count_state_systems!($($entries)*),
             on_exit: $on_exit_count,
             version: $ver,
             description: $desc,
@@ -1094,7 +4571,7 @@ macro_rules! define_plugin_metadata_internal {
         );
     };
 
-    // add_systems_fixed_update: / fixed_update:
+    // on_enter_any: - each system counts once per state
     ($plugin_name:ident,
         resources: [$($res:ty),*],
         messages: [$($msg:ty),*],
@@ -1103,14 +4580,15 @@ macro_rules! define_plugin_metadata_internal {
         reflected: [$($refl:ty),*],
         plugins: [$($plug:expr),*],
         deps: [$($dep:ty),*],
-        startup: [$($startup_sys:expr),*],
-        update: [$($update_sys:expr),*],
-        fixed: [$($old_sys:expr),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($update_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
         on_enter: $on_enter_count:expr,
         on_exit: $on_exit_count:expr,
         version: $ver:expr,
         description: $desc:expr,
-        config: { add_systems_fixed_update: [$($sys:expr),* $(,)?] $(, $($rest:tt)*)? }
+        config: { on_enter_any: { $([$($st:expr),* $(,)?] => [$($sys:expr),* $(,)?]),* $(,)? } $(, $($rest:tt)*)? }
     ) => {
         $crate::define_plugin_metadata_internal!(
             $plugin_name,
@@ -1123,8 +4601,9 @@ macro_rules! define_plugin_metadata_internal {
             deps: [$($dep),*],
             startup: [$($startup_sys),*],
             update: [$($update_sys),*],
-            fixed: [$($old_sys,)* $($sys),*],
-            on_enter: $on_enter_count,
+            fixed: [$($fixed_sys),*],
+            observers: [$($observer),*],
+            on_enter: $on_enter_count $(+ $crate::count_items!($($st),*) * $crate::count_items!($($sys),*))*,
             on_exit: $on_exit_count,
             version: $ver,
             description: $desc,
@@ -1132,7 +4611,7 @@ macro_rules! define_plugin_metadata_internal {
         );
     };
 
-    // add_systems_on_enter: / on_enter: (count entries for metadata)
+    // add_systems_on_exit: / on_exit:
     ($plugin_name:ident,
         resources: [$($res:ty),*],
         messages: [$($msg:ty),*],
@@ -1141,14 +4620,15 @@ macro_rules! define_plugin_metadata_internal {
         reflected: [$($refl:ty),*],
         plugins: [$($plug:expr),*],
         deps: [$($dep:ty),*],
-        startup: [$($startup_sys:expr),*],
-        update: [$($update_sys:expr),*],
-        fixed: [$($fixed_sys:expr),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($update_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
         on_enter: $on_enter_count:expr,
         on_exit: $on_exit_count:expr,
         version: $ver:expr,
         description: $desc:expr,
-        config: { add_systems_on_enter: { $($state_val:expr => [$($sys:expr),* $(,)?]),* $(,)? } $(, $($rest:tt)*)? }
+        config: { add_systems_on_exit: { $($entries:tt)* } $(, $($rest:tt)*)? }
     ) => {
         $crate::define_plugin_metadata_internal!(
             $plugin_name,
@@ -1162,15 +4642,16 @@ macro_rules! define_plugin_metadata_internal {
             startup: [$($startup_sys),*],
             update: [$($update_sys),*],
             fixed: [$($fixed_sys),*],
-            on_enter: $on_enter_count + $crate::count_items!($($($sys),*),*),
-            on_exit: $on_exit_count,
+            observers: [$($observer),*],
+            on_enter: $on_enter_count,
+            on_exit: $on_exit_count + $crate::count_state_systems!($($entries)*),
             version: $ver,
             description: $desc,
             config: { $($($rest)*)? }
         );
     };
 
-    // add_systems_on_exit: / on_exit:
+    // custom_build: / custom_init: (skip for metadata)
     ($plugin_name:ident,
         resources: [$($res:ty),*],
         messages: [$($msg:ty),*],
@@ -1179,14 +4660,15 @@ macro_rules! define_plugin_metadata_internal {
         reflected: [$($refl:ty),*],
         plugins: [$($plug:expr),*],
         deps: [$($dep:ty),*],
-        startup: [$($startup_sys:expr),*],
-        update: [$($update_sys:expr),*],
-        fixed: [$($fixed_sys:expr),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($update_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
         on_enter: $on_enter_count:expr,
         on_exit: $on_exit_count:expr,
         version: $ver:expr,
         description: $desc:expr,
-        config: { add_systems_on_exit: { $($state_val:expr => [$($sys:expr),* $(,)?]),* $(,)? } $(, $($rest:tt)*)? }
+        config: { custom_build: $build_fn:expr $(, $($rest:tt)*)? }
     ) => {
         $crate::define_plugin_metadata_internal!(
             $plugin_name,
@@ -1200,15 +4682,16 @@ macro_rules! define_plugin_metadata_internal {
             startup: [$($startup_sys),*],
             update: [$($update_sys),*],
             fixed: [$($fixed_sys),*],
+            observers: [$($observer),*],
             on_enter: $on_enter_count,
-            on_exit: $on_exit_count + $crate::count_items!($($($sys),*),*),
+            on_exit: $on_exit_count,
             version: $ver,
             description: $desc,
             config: { $($($rest)*)? }
         );
     };
 
-    // custom_build: / custom_init: (skip for metadata)
+    // distributive_run_if_update: (skip for metadata, the systems follow)
     ($plugin_name:ident,
         resources: [$($res:ty),*],
         messages: [$($msg:ty),*],
@@ -1217,14 +4700,15 @@ macro_rules! define_plugin_metadata_internal {
         reflected: [$($refl:ty),*],
         plugins: [$($plug:expr),*],
         deps: [$($dep:ty),*],
-        startup: [$($startup_sys:expr),*],
-        update: [$($update_sys:expr),*],
-        fixed: [$($fixed_sys:expr),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($update_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
         on_enter: $on_enter_count:expr,
         on_exit: $on_exit_count:expr,
         version: $ver:expr,
         description: $desc:expr,
-        config: { custom_build: $build_fn:expr $(, $($rest:tt)*)? }
+        config: { distributive_run_if_update: $condition:expr $(, $($rest:tt)*)? }
     ) => {
         $crate::define_plugin_metadata_internal!(
             $plugin_name,
@@ -1238,6 +4722,7 @@ macro_rules! define_plugin_metadata_internal {
             startup: [$($startup_sys),*],
             update: [$($update_sys),*],
             fixed: [$($fixed_sys),*],
+            observers: [$($observer),*],
             on_enter: $on_enter_count,
             on_exit: $on_exit_count,
             version: $ver,
@@ -1255,9 +4740,10 @@ macro_rules! define_plugin_metadata_internal {
         reflected: [$($refl:ty),*],
         plugins: [$($plug:expr),*],
         deps: [$($dep:ty),*],
-        startup: [$($startup_sys:expr),*],
-        update: [$($update_sys:expr),*],
-        fixed: [$($fixed_sys:expr),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($update_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
         on_enter: $on_enter_count:expr,
         on_exit: $on_exit_count:expr,
         version: $ver:expr,
@@ -1276,6 +4762,7 @@ macro_rules! define_plugin_metadata_internal {
             startup: [$($startup_sys),*],
             update: [$($update_sys),*],
             fixed: [$($fixed_sys),*],
+            observers: [$($observer),*],
             on_enter: $on_enter_count,
             on_exit: $on_exit_count,
             version: $ver,
@@ -1293,9 +4780,10 @@ macro_rules! define_plugin_metadata_internal {
         reflected: [$($refl:ty),*],
         plugins: [$($plug:expr),*],
         deps: [$($dep:ty),*],
-        startup: [$($startup_sys:expr),*],
-        update: [$($update_sys:expr),*],
-        fixed: [$($fixed_sys:expr),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($update_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
         on_enter: $on_enter_count:expr,
         on_exit: $on_exit_count:expr,
         version: $ver:expr,
@@ -1314,6 +4802,7 @@ macro_rules! define_plugin_metadata_internal {
             startup: [$($startup_sys),*],
             update: [$($update_sys),*],
             fixed: [$($fixed_sys),*],
+            observers: [$($observer),*],
             on_enter: $on_enter_count,
             on_exit: $on_exit_count,
             version: $ver,
@@ -1322,8 +4811,8 @@ macro_rules! define_plugin_metadata_internal {
         );
     };
 
-    // Catch-all for unknown options - skip them silently for metadata
-    // (the main macro will report errors for truly unknown options)
+    // Known options without metadata are skipped; an unknown key stops the
+    // walk so no partial metadata is generated
     ($plugin_name:ident,
         resources: [$($res:ty),*],
         messages: [$($msg:ty),*],
@@ -1332,16 +4821,17 @@ macro_rules! define_plugin_metadata_internal {
         reflected: [$($refl:ty),*],
         plugins: [$($plug:expr),*],
         deps: [$($dep:ty),*],
-        startup: [$($startup_sys:expr),*],
-        update: [$($update_sys:expr),*],
-        fixed: [$($fixed_sys:expr),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($update_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
         on_enter: $on_enter_count:expr,
         on_exit: $on_exit_count:expr,
         version: $ver:expr,
         description: $desc:expr,
-        config: { $unknown:ident : $value:tt $(, $($rest:tt)*)? }
+        config: { $key:ident : $value:tt $(, $($rest:tt)*)? }
     ) => {
-        $crate::define_plugin_metadata_internal!(
+        $crate::define_plugin_if_known_option!($key, { $crate::define_plugin_metadata_internal!(
             $plugin_name,
             resources: [$($res),*],
             messages: [$($msg),*],
@@ -1353,13 +4843,226 @@ macro_rules! define_plugin_metadata_internal {
             startup: [$($startup_sys),*],
             update: [$($update_sys),*],
             fixed: [$($fixed_sys),*],
+            observers: [$($observer),*],
             on_enter: $on_enter_count,
             on_exit: $on_exit_count,
             version: $ver,
             description: $desc,
             config: { $($($rest)*)? }
+        ); } else { $crate::define_plugin_metadata_placeholder!($plugin_name); });
+    };
+
+    // Anything else is an unknown option or a malformed value, which
+    // define_plugin_internal! reports - generate no metadata
+    ($plugin_name:ident,
+        resources: [$($res:ty),*],
+        messages: [$($msg:ty),*],
+        states: [$($state:ty),*],
+        sub_states: [$($sub:ty),*],
+        reflected: [$($refl:ty),*],
+        plugins: [$($plug:expr),*],
+        deps: [$($dep:ty),*],
+        startup: [$($startup_sys:tt),*],
+        update: [$($update_sys:tt),*],
+        fixed: [$($fixed_sys:tt),*],
+        observers: [$($observer:expr),*],
+        on_enter: $on_enter_count:expr,
+        on_exit: $on_exit_count:expr,
+        version: $ver:expr,
+        description: $desc:expr,
+        config: { $($unknown:tt)* }
+    ) => {
+        $crate::define_plugin_metadata_placeholder!($plugin_name);
+    };
+}
+
+/// Empty `PluginInfo` impl for a plugin whose configuration failed to parse.
+/// `define_plugin_internal!` already reports the error; this keeps generated
+/// code that relies on `PluginInfo` from adding a second, confusing one.
+#[cfg(feature = "introspection")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_metadata_placeholder {
+    ($plugin_name:ident) => {
+        impl $plugin_name {
+            #[doc(hidden)]
+            pub const __PLUGIN_METADATA: &'static $crate::PluginMetadata =
+                &$crate::PluginMetadata::empty(stringify!($plugin_name));
+        }
+
+        impl $crate::PluginInfo for $plugin_name {
+            const NAME: &'static str = stringify!($plugin_name);
+
+            fn metadata() -> &'static $crate::PluginMetadata {
+                Self::__PLUGIN_METADATA
+            }
+        }
+    };
+}
+
+/// Declare the `pub const` named by `metadata_const_name:`, pointing at the
+/// plugin's metadata static. Scans the configuration like
+/// `define_plugin_system_set!`.
+#[cfg(feature = "introspection")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_metadata_const {
+    ($plugin_name:ident { metadata_const_name: $name:ident $(, $($rest:tt)*)? }) => {
+        pub const $name: &$crate::PluginMetadata = $plugin_name::__PLUGIN_METADATA;
+    };
+
+    // Skip other configs and keep looking
+    ($plugin_name:ident { $key:ident : [$($value:tt)*] $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_metadata_const!($plugin_name { $($($rest)*)? });
+    };
+    ($plugin_name:ident { $key:ident : { $($value:tt)* } $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_metadata_const!($plugin_name { $($($rest)*)? });
+    };
+    ($plugin_name:ident { $key:ident : $value:expr $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_metadata_const!($plugin_name { $($($rest)*)? });
+    };
+
+    // End of config, or a malformed entry that define_plugin_internal! reports
+    ($plugin_name:ident { $($rest:tt)* }) => {};
+}
+
+/// The const itself is declared by define_plugin_metadata_const!
+#[cfg(feature = "introspection")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_metadata_const_enabled {
+    ($name:ident) => {};
+}
+
+/// Without introspection there is no metadata static to point the const at
+#[cfg(not(feature = "introspection"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_metadata_const_enabled {
+    ($name:ident) => {
+        compile_error!(
+            "metadata_const_name requires the `introspection` feature of bevy-plugin-builder"
+        );
+    };
+}
+
+/// Collect the names of every `add_systems_state_transition:` list into a
+/// hidden const, read by the metadata static. Scans the configuration like
+/// `define_plugin_metadata_const!`.
+#[cfg(feature = "introspection")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_state_transition_names {
+    // Entries of one list, stored as `([attrs] system)` like
+    // define_plugin_metadata_systems! does
+    (@list $plugin_name:ident [$($names:tt)*] [$($attrs:tt)*] [# [$attr:meta] $($list:tt)*] $rest:tt) => {
+        $crate::define_plugin_state_transition_names!(
+            @list $plugin_name [$($names)*] [$($attrs)* #[$attr]] [$($list)*] $rest
+        );
+    };
+    (@list $plugin_name:ident [$($names:tt)*] [$($attrs:tt)*] [$system:expr $(, $($list:tt)*)?] $rest:tt) => {
+        $crate::define_plugin_state_transition_names!(
+            @list $plugin_name [$($names)* ([$($attrs)*] $system)] [] [$($($list)*)?] $rest
+        );
+    };
+    (@list $plugin_name:ident [$($names:tt)*] [] [] { $($rest:tt)* }) => {
+        $crate::define_plugin_state_transition_names!($plugin_name [$($names)*] { $($rest)* });
+    };
+
+    ($plugin_name:ident [$($names:tt)*] { add_systems_state_transition: [$($list:tt)*] $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_state_transition_names!(
+            @list $plugin_name [$($names)*] [] [$($list)*] { $($($rest)*)? }
+        );
+    };
+
+    // Skip other configs and keep looking
+    ($plugin_name:ident [$($names:tt)*] { $key:ident : [$($value:tt)*] $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_state_transition_names!($plugin_name [$($names)*] { $($($rest)*)? });
+    };
+    ($plugin_name:ident [$($names:tt)*] { $key:ident : { $($value:tt)* } $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_state_transition_names!($plugin_name [$($names)*] { $($($rest)*)? });
+    };
+    ($plugin_name:ident [$($names:tt)*] { $key:ident : $value:expr $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_state_transition_names!($plugin_name [$($names)*] { $($($rest)*)? });
+    };
+
+    // End of config, or a malformed entry that define_plugin_internal! reports
+    ($plugin_name:ident [$($names:tt)*] { $($rest:tt)* }) => {
+        impl $plugin_name {
+            #[doc(hidden)]
+            pub const __STATE_TRANSITION_SYSTEMS: &'static [&'static str] =
+                &$crate::define_plugin_system_names!([] $($names)*);
+        }
+    };
+}
+
+/// Collect the types of every `register_components:` list into a hidden
+/// const, read by the metadata static. Scans the configuration like
+/// `define_plugin_state_transition_names!`.
+#[cfg(feature = "introspection")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_reflected_components {
+    ($plugin_name:ident [$($infos:expr),*] { register_components: [$($component:ty),* $(,)?] $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_reflected_components!(
+            $plugin_name [$($infos,)* $($crate::TypeInfo::new::<$component>(stringify!($component))),*] { $($($rest)*)? }
         );
     };
+
+    // Skip other configs and keep looking
+    ($plugin_name:ident [$($infos:expr),*] { $key:ident : [$($value:tt)*] $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_reflected_components!($plugin_name [$($infos),*] { $($($rest)*)? });
+    };
+    ($plugin_name:ident [$($infos:expr),*] { $key:ident : { $($value:tt)* } $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_reflected_components!($plugin_name [$($infos),*] { $($($rest)*)? });
+    };
+    ($plugin_name:ident [$($infos:expr),*] { $key:ident : $value:expr $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_reflected_components!($plugin_name [$($infos),*] { $($($rest)*)? });
+    };
+
+    // End of config, or a malformed entry that define_plugin_internal! reports
+    ($plugin_name:ident [$($infos:expr),*] { $($rest:tt)* }) => {
+        impl $plugin_name {
+            #[doc(hidden)]
+            pub const __REFLECTED_COMPONENTS: &'static [$crate::TypeInfo] = &[$($infos),*];
+        }
+    };
+}
+
+/// Record whether `custom_build:` and `custom_finish:` are present in hidden
+/// consts, read by the metadata static. Scans the configuration like
+/// `define_plugin_state_transition_names!`.
+#[cfg(feature = "introspection")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_custom_logic_flags {
+    ($plugin_name:ident [$build:tt $finish:tt] { custom_build: $value:expr $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_custom_logic_flags!($plugin_name [true $finish] { $($($rest)*)? });
+    };
+    ($plugin_name:ident [$build:tt $finish:tt] { custom_finish: $value:expr $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_custom_logic_flags!($plugin_name [$build true] { $($($rest)*)? });
+    };
+
+    // Skip other configs and keep looking
+    ($plugin_name:ident [$build:tt $finish:tt] { $key:ident : [$($value:tt)*] $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_custom_logic_flags!($plugin_name [$build $finish] { $($($rest)*)? });
+    };
+    ($plugin_name:ident [$build:tt $finish:tt] { $key:ident : { $($value:tt)* } $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_custom_logic_flags!($plugin_name [$build $finish] { $($($rest)*)? });
+    };
+    ($plugin_name:ident [$build:tt $finish:tt] { $key:ident : $value:expr $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_custom_logic_flags!($plugin_name [$build $finish] { $($($rest)*)? });
+    };
+
+    // End of config, or a malformed entry that define_plugin_internal! reports
+    ($plugin_name:ident [$build:tt $finish:tt] { $($rest:tt)* }) => {
+        impl $plugin_name {
+            #[doc(hidden)]
+            pub const __HAS_CUSTOM_BUILD: bool = $build;
+            #[doc(hidden)]
+            pub const __HAS_CUSTOM_FINISH: bool = $finish;
+        }
+    };
 }
 
 // ============================================================================
@@ -1374,7 +5077,7 @@ macro_rules! define_plugin_metadata_internal {
 macro_rules! define_plugin_tests {
     // Entry point - scan for generate_tests: block
     ($plugin_name:ident { $($config:tt)* }) => {
-        $crate::define_plugin_tests_scan!($plugin_name, config: { $($config)* });
+        $crate::define_plugin_tests_scan!($plugin_name, full: { $($config)* }, config: { $($config)* });
     };
 }
 
@@ -1383,31 +5086,32 @@ macro_rules! define_plugin_tests {
 #[doc(hidden)]
 macro_rules! define_plugin_tests_scan {
     // Found generate_tests: block - pass to generator
-    ($plugin_name:ident, config: { generate_tests: { $($test_opts:tt)* } $(, $($rest:tt)*)? }) => {
-        $crate::define_plugin_tests_generate!($plugin_name, test_opts: { $($test_opts)* }, config: { $($($rest)*)? });
+    // Types are collected from the full config, including keys before the block
+    ($plugin_name:ident, full: { $($full:tt)* }, config: { generate_tests: { $($test_opts:tt)* } $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_tests_generate!($plugin_name, test_opts: { $($test_opts)* }, config: { $($full)* });
     };
 
     // Skip other configs and keep looking
-    ($plugin_name:ident, config: { $key:ident : [$($value:tt)*] $(, $($rest:tt)*)? }) => {
-        $crate::define_plugin_tests_scan!($plugin_name, config: { $($($rest)*)? });
+    ($plugin_name:ident, full: { $($full:tt)* }, config: { $key:ident : [$($value:tt)*] $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_tests_scan!($plugin_name, full: { $($full)* }, config: { $($($rest)*)? });
     };
-    ($plugin_name:ident, config: { $key:ident : { $($value:tt)* } $(, $($rest:tt)*)? }) => {
-        $crate::define_plugin_tests_scan!($plugin_name, config: { $($($rest)*)? });
+    ($plugin_name:ident, full: { $($full:tt)* }, config: { $key:ident : { $($value:tt)* } $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_tests_scan!($plugin_name, full: { $($full)* }, config: { $($($rest)*)? });
     };
     // Handle closures like custom_init: |app| { ... } followed by more config
-    ($plugin_name:ident, config: { $key:ident : | $param:ident $(: $param_ty:ty)? | { $($body:tt)* } $(, $($rest:tt)*)? }) => {
-        $crate::define_plugin_tests_scan!($plugin_name, config: { $($($rest)*)? });
+    ($plugin_name:ident, full: { $($full:tt)* }, config: { $key:ident : | $param:ident $(: $param_ty:ty)? | { $($body:tt)* } $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_tests_scan!($plugin_name, full: { $($full)* }, config: { $($($rest)*)? });
     };
     // Handle closures as trailing item (no comma after)
-    ($plugin_name:ident, config: { $key:ident : | $param:ident $(: $param_ty:ty)? | { $($body:tt)* } }) => {
+    ($plugin_name:ident, full: { $($full:tt)* }, config: { $key:ident : | $param:ident $(: $param_ty:ty)? | { $($body:tt)* } }) => {
         // No more config - no generate_tests found
     };
-    ($plugin_name:ident, config: { $key:ident : $value:expr $(, $($rest:tt)*)? }) => {
-        $crate::define_plugin_tests_scan!($plugin_name, config: { $($($rest)*)? });
+    ($plugin_name:ident, full: { $($full:tt)* }, config: { $key:ident : $value:expr $(, $($rest:tt)*)? }) => {
+        $crate::define_plugin_tests_scan!($plugin_name, full: { $($full)* }, config: { $($($rest)*)? });
     };
 
     // End of config - no generate_tests: found, do nothing
-    ($plugin_name:ident, config: {}) => {};
+    ($plugin_name:ident, full: { $($full:tt)* }, config: {}) => {};
 }
 
 /// Generator macro that creates test based on test_opts
@@ -1419,66 +5123,160 @@ macro_rules! define_plugin_tests_generate {
         $crate::define_plugin_tests_accumulate!(
             $plugin_name,
             test_opts: { $($test_opts)* },
-            resources: [],
-            messages: [],
-            states: [],
-            deps: [],
-            config: { $($config)* }
+            resources: [],
+            messages: [],
+            states: [],
+            deps: [],
+            reflected: [],
+            config: { $($config)* }
+        );
+    };
+}
+
+/// Accumulator that collects types from config for test generation
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_tests_accumulate {
+    // Terminal - generate tests
+    ($plugin_name:ident,
+        test_opts: { $($test_opts:tt)* },
+        resources: [$($res:ty),*],
+        messages: [$($msg:ty),*],
+        states: [$($state:ty),*],
+        deps: [$($dep:ty),*],
+        reflected: [$($refl:ty),*],
+        config: {}
+    ) => {
+        $crate::define_plugin_tests_emit!(
+            $plugin_name,
+            test_opts: { $($test_opts)* },
+            resources: [$($res),*],
+            messages: [$($msg),*],
+            states: [$($state),*],
+            deps: [$($dep),*],
+            reflected: [$($refl),*]
+        );
+    };
+
+    // init_resource:
+    ($plugin_name:ident,
+        test_opts: { $($test_opts:tt)* },
+        resources: [$($old_res:ty),*],
+        messages: [$($msg:ty),*],
+        states: [$($state:ty),*],
+        deps: [$($dep:ty),*],
+        reflected: [$($refl:ty),*],
+        config: { init_resource: [$($res:ty),* $(,)?] $(, $($rest:tt)*)? }
+    ) => {
+        $crate::define_plugin_tests_accumulate!(
+            $plugin_name,
+            test_opts: { $($test_opts)* },
+            resources: [$($old_res,)* $($res),*],
+            messages: [$($msg),*],
+            states: [$($state),*],
+            deps: [$($dep),*],
+            reflected: [$($refl),*],
+            config: { $($($rest)*)? }
+        );
+    };
+
+    // init_resource_with:
+    ($plugin_name:ident,
+        test_opts: { $($test_opts:tt)* },
+        resources: [$($old_res:ty),*],
+        messages: [$($msg:ty),*],
+        states: [$($state:ty),*],
+        deps: [$($dep:ty),*],
+        reflected: [$($refl:ty),*],
+        config: { init_resource_with: { $($res:ty => $init:expr),* $(,)? } $(, $($rest:tt)*)? }
+    ) => {
+        $crate::define_plugin_tests_accumulate!(
+            $plugin_name,
+            test_opts: { $($test_opts)* },
+            resources: [$($old_res,)* $($res),*],
+            messages: [$($msg),*],
+            states: [$($state),*],
+            deps: [$($dep),*],
+            reflected: [$($refl),*],
+            config: { $($($rest)*)? }
+        );
+    };
+
+    // inspectable_resources: - checked by both test_resources and test_reflect
+    ($plugin_name:ident,
+        test_opts: { $($test_opts:tt)* },
+        resources: [$($old_res:ty),*],
+        messages: [$($msg:ty),*],
+        states: [$($state:ty),*],
+        deps: [$($dep:ty),*],
+        reflected: [$($old_refl:ty),*],
+        config: { inspectable_resources: [$($res:ty),* $(,)?] $(, $($rest:tt)*)? }
+    ) => {
+        $crate::define_plugin_tests_accumulate!(
+            $plugin_name,
+            test_opts: { $($test_opts)* },
+            resources: [$($old_res,)* $($res),*],
+            messages: [$($msg),*],
+            states: [$($state),*],
+            deps: [$($dep),*],
+            reflected: [$($old_refl,)* $($res),*],
+            config: { $($($rest)*)? }
         );
     };
-}
 
-/// Accumulator that collects types from config for test generation
-#[macro_export]
-#[doc(hidden)]
-macro_rules! define_plugin_tests_accumulate {
-    // Terminal - generate tests
+    // add_message:
     ($plugin_name:ident,
         test_opts: { $($test_opts:tt)* },
         resources: [$($res:ty),*],
-        messages: [$($msg:ty),*],
+        messages: [$($old_msg:ty),*],
         states: [$($state:ty),*],
         deps: [$($dep:ty),*],
-        config: {}
+        reflected: [$($refl:ty),*],
+        config: { add_message: [$($msg:ty),* $(,)?] $(, $($rest:tt)*)? }
     ) => {
-        $crate::define_plugin_tests_emit!(
+        $crate::define_plugin_tests_accumulate!(
             $plugin_name,
             test_opts: { $($test_opts)* },
             resources: [$($res),*],
-            messages: [$($msg),*],
+            messages: [$($old_msg,)* $($msg),*],
             states: [$($state),*],
-            deps: [$($dep),*]
+            deps: [$($dep),*],
+            reflected: [$($refl),*],
+            config: { $($($rest)*)? }
         );
     };
 
-    // init_resource:
+    // add_message_with:
     ($plugin_name:ident,
         test_opts: { $($test_opts:tt)* },
-        resources: [$($old_res:ty),*],
-        messages: [$($msg:ty),*],
+        resources: [$($res:ty),*],
+        messages: [$($old_msg:ty),*],
         states: [$($state:ty),*],
         deps: [$($dep:ty),*],
-        config: { init_resource: [$($res:ty),* $(,)?] $(, $($rest:tt)*)? }
+        reflected: [$($refl:ty),*],
+        config: { add_message_with: { $($msg:ty => $policy:ident),* $(,)? } $(, $($rest:tt)*)? }
     ) => {
         $crate::define_plugin_tests_accumulate!(
             $plugin_name,
             test_opts: { $($test_opts)* },
-            resources: [$($old_res,)* $($res),*],
-            messages: [$($msg),*],
+            resources: [$($res),*],
+            messages: [$($old_msg,)* $($msg),*],
             states: [$($state),*],
             deps: [$($dep),*],
+            reflected: [$($refl),*],
             config: { $($($rest)*)? }
         );
     };
 
-    // add_message:
+    // add_message_buffered:
     ($plugin_name:ident,
         test_opts: { $($test_opts:tt)* },
         resources: [$($res:ty),*],
         messages: [$($old_msg:ty),*],
         states: [$($state:ty),*],
         deps: [$($dep:ty),*],
-        config: { add_message: [$($msg:ty),* $(,)?] $(, $($rest:tt)*)? }
+        reflected: [$($refl:ty),*],
+        config: { add_message_buffered: { $($msg:ty => $frames:literal),* $(,)? } $(, $($rest:tt)*)? }
     ) => {
         $crate::define_plugin_tests_accumulate!(
             $plugin_name,
@@ -1487,6 +5285,7 @@ macro_rules! define_plugin_tests_accumulate {
             messages: [$($old_msg,)* $($msg),*],
             states: [$($state),*],
             deps: [$($dep),*],
+            reflected: [$($refl),*],
             config: { $($($rest)*)? }
         );
     };
@@ -1498,6 +5297,7 @@ macro_rules! define_plugin_tests_accumulate {
         messages: [$($msg:ty),*],
         states: [$($old_state:ty),*],
         deps: [$($dep:ty),*],
+        reflected: [$($refl:ty),*],
         config: { init_state: [$($state:ty),* $(,)?] $(, $($rest:tt)*)? }
     ) => {
         $crate::define_plugin_tests_accumulate!(
@@ -1507,6 +5307,7 @@ macro_rules! define_plugin_tests_accumulate {
             messages: [$($msg),*],
             states: [$($old_state,)* $($state),*],
             deps: [$($dep),*],
+            reflected: [$($refl),*],
             config: { $($($rest)*)? }
         );
     };
@@ -1518,6 +5319,7 @@ macro_rules! define_plugin_tests_accumulate {
         messages: [$($msg:ty),*],
         states: [$($state:ty),*],
         deps: [$($old_dep:ty),*],
+        reflected: [$($refl:ty),*],
         config: { depends_on: [$($dep:ty),* $(,)?] $(, $($rest:tt)*)? }
     ) => {
         $crate::define_plugin_tests_accumulate!(
@@ -1527,18 +5329,20 @@ macro_rules! define_plugin_tests_accumulate {
             messages: [$($msg),*],
             states: [$($state),*],
             deps: [$($old_dep,)* $($dep),*],
+            reflected: [$($refl),*],
             config: { $($($rest)*)? }
         );
     };
 
-    // Skip other options
+    // register_type:
     ($plugin_name:ident,
         test_opts: { $($test_opts:tt)* },
         resources: [$($res:ty),*],
         messages: [$($msg:ty),*],
         states: [$($state:ty),*],
         deps: [$($dep:ty),*],
-        config: { $key:ident : [$($value:tt)*] $(, $($rest:tt)*)? }
+        reflected: [$($old_refl:ty),*],
+        config: { register_type: [$($refl:ty),* $(,)?] $(, $($rest:tt)*)? }
     ) => {
         $crate::define_plugin_tests_accumulate!(
             $plugin_name,
@@ -1547,16 +5351,20 @@ macro_rules! define_plugin_tests_accumulate {
             messages: [$($msg),*],
             states: [$($state),*],
             deps: [$($dep),*],
+            reflected: [$($old_refl,)* $($refl),*],
             config: { $($($rest)*)? }
         );
     };
+
+    // register_components: - checked by test_reflect like register_type
     ($plugin_name:ident,
         test_opts: { $($test_opts:tt)* },
         resources: [$($res:ty),*],
         messages: [$($msg:ty),*],
         states: [$($state:ty),*],
         deps: [$($dep:ty),*],
-        config: { $key:ident : { $($value:tt)* } $(, $($rest:tt)*)? }
+        reflected: [$($old_refl:ty),*],
+        config: { register_components: [$($refl:ty),* $(,)?] $(, $($rest:tt)*)? }
     ) => {
         $crate::define_plugin_tests_accumulate!(
             $plugin_name,
@@ -1565,213 +5373,307 @@ macro_rules! define_plugin_tests_accumulate {
             messages: [$($msg),*],
             states: [$($state),*],
             deps: [$($dep),*],
+            reflected: [$($old_refl,)* $($refl),*],
             config: { $($($rest)*)? }
         );
     };
+
+    // is_unique: false - passed on with the test options for test_unique
     ($plugin_name:ident,
         test_opts: { $($test_opts:tt)* },
         resources: [$($res:ty),*],
         messages: [$($msg:ty),*],
         states: [$($state:ty),*],
         deps: [$($dep:ty),*],
-        config: { $key:ident : | $($value:tt)* }
+        reflected: [$($refl:ty),*],
+        config: { is_unique: false $(, $($rest:tt)*)? }
     ) => {
-        // Handle trailing closure - no more config after this
         $crate::define_plugin_tests_accumulate!(
             $plugin_name,
-            test_opts: { $($test_opts)* },
+            test_opts: { is_unique: false, $($test_opts)* },
             resources: [$($res),*],
             messages: [$($msg),*],
             states: [$($state),*],
             deps: [$($dep),*],
-            config: {}
+            reflected: [$($refl),*],
+            config: { $($($rest)*)? }
         );
     };
-}
 
-/// Emit the actual test code based on test_opts
-/// Tests are generated in a module named after the plugin to ensure unique test names
-/// and proper test discovery by the test harness.
-#[macro_export]
-#[doc(hidden)]
-macro_rules! define_plugin_tests_emit {
-    // Collect all test flags and emit a single module with all tests
+    // Skip other options
     ($plugin_name:ident,
-        test_opts: { $($opt_key:ident : $opt_val:tt),* $(,)? },
+        test_opts: { $($test_opts:tt)* },
         resources: [$($res:ty),*],
         messages: [$($msg:ty),*],
         states: [$($state:ty),*],
-        deps: [$($dep:ty),*]
+        deps: [$($dep:ty),*],
+        reflected: [$($refl:ty),*],
+        config: { $key:ident : [$($value:tt)*] $(, $($rest:tt)*)? }
     ) => {
-        $crate::define_plugin_tests_emit_module!(
+        $crate::define_plugin_tests_accumulate!(
             $plugin_name,
+            test_opts: { $($test_opts)* },
             resources: [$($res),*],
             messages: [$($msg),*],
             states: [$($state),*],
             deps: [$($dep),*],
-            test_resources: false,
-            test_messages: false,
-            test_states: false,
-            test_dependencies: false
-            $(, $opt_key : $opt_val)*
+            reflected: [$($refl),*],
+            config: { $($($rest)*)? }
         );
     };
-
-    // Terminal - empty test_opts
-    ($plugin_name:ident,
-        test_opts: {},
-        resources: [$($res:ty),*],
-        messages: [$($msg:ty),*],
-        states: [$($state:ty),*],
-        deps: [$($dep:ty),*]
-    ) => {};
-}
-
-/// Helper macro to emit the test module with accumulated flags
-#[macro_export]
-#[doc(hidden)]
-macro_rules! define_plugin_tests_emit_module {
-    // Base case - emit the module
-    // We wrap in const _: () to avoid name collision between the module and struct
     ($plugin_name:ident,
+        test_opts: { $($test_opts:tt)* },
         resources: [$($res:ty),*],
         messages: [$($msg:ty),*],
         states: [$($state:ty),*],
         deps: [$($dep:ty),*],
-        test_resources: $test_res:tt,
-        test_messages: $test_msg:tt,
-        test_states: $test_states:tt,
-        test_dependencies: $test_deps:tt
+        reflected: [$($refl:ty),*],
+        config: { $key:ident : { $($value:tt)* } $(, $($rest:tt)*)? }
     ) => {
-        // Generate test module wrapped in const to avoid name collision with struct
-        #[cfg(all(test, feature = "testing"))]
-        const _: () = {
-            #[allow(non_snake_case)]
-            mod tests {
-                // Import from two levels up (through const, then through parent module)
-                #[allow(unused_imports)]
-                use super::super::*;
-
-                $crate::define_plugin_test_resource!($plugin_name, $test_res, [$($res),*]);
-                $crate::define_plugin_test_messages!($plugin_name, $test_msg, [$($msg),*]);
-                $crate::define_plugin_test_states!($plugin_name, $test_states, [$($state),*]);
-                $crate::define_plugin_test_dependencies!($plugin_name, $test_deps, [$($dep),*]);
-            }
-        };
+        $crate::define_plugin_tests_accumulate!(
+            $plugin_name,
+            test_opts: { $($test_opts)* },
+            resources: [$($res),*],
+            messages: [$($msg),*],
+            states: [$($state),*],
+            deps: [$($dep),*],
+            reflected: [$($refl),*],
+            config: { $($($rest)*)? }
+        );
     };
-
-    // Override test_resources
+    // Handle expression values like custom_build: |app| { ... } or custom_build: setup_fn
     ($plugin_name:ident,
+        test_opts: { $($test_opts:tt)* },
         resources: [$($res:ty),*],
         messages: [$($msg:ty),*],
         states: [$($state:ty),*],
         deps: [$($dep:ty),*],
-        test_resources: $_old:tt,
-        test_messages: $test_msg:tt,
-        test_states: $test_states:tt,
-        test_dependencies: $test_deps:tt,
-        test_resources: $new_val:tt
-        $(, $rest_key:ident : $rest_val:tt)*
+        reflected: [$($refl:ty),*],
+        config: { $key:ident : $value:expr $(, $($rest:tt)*)? }
     ) => {
-        $crate::define_plugin_tests_emit_module!(
+        $crate::define_plugin_tests_accumulate!(
             $plugin_name,
+            test_opts: { $($test_opts)* },
             resources: [$($res),*],
             messages: [$($msg),*],
             states: [$($state),*],
             deps: [$($dep),*],
-            test_resources: $new_val,
-            test_messages: $test_msg,
-            test_states: $test_states,
-            test_dependencies: $test_deps
-            $(, $rest_key : $rest_val)*
+            reflected: [$($refl),*],
+            config: { $($($rest)*)? }
         );
     };
-
-    // Override test_messages
     ($plugin_name:ident,
+        test_opts: { $($test_opts:tt)* },
         resources: [$($res:ty),*],
         messages: [$($msg:ty),*],
         states: [$($state:ty),*],
         deps: [$($dep:ty),*],
-        test_resources: $test_res:tt,
-        test_messages: $_old:tt,
-        test_states: $test_states:tt,
-        test_dependencies: $test_deps:tt,
-        test_messages: $new_val:tt
-        $(, $rest_key:ident : $rest_val:tt)*
+        reflected: [$($refl:ty),*],
+        config: { $key:ident : | $($value:tt)* }
     ) => {
-        $crate::define_plugin_tests_emit_module!(
+        // Handle trailing closure - no more config after this
+        $crate::define_plugin_tests_accumulate!(
             $plugin_name,
+            test_opts: { $($test_opts)* },
             resources: [$($res),*],
             messages: [$($msg),*],
             states: [$($state),*],
             deps: [$($dep),*],
-            test_resources: $test_res,
-            test_messages: $new_val,
-            test_states: $test_states,
-            test_dependencies: $test_deps
-            $(, $rest_key : $rest_val)*
+            reflected: [$($refl),*],
+            config: {}
         );
     };
+}
 
-    // Override test_states
+/// Emit the actual test code based on test_opts
+/// Tests are generated in a named module so the test harness discovers them and
+/// failures report which plugin they belong to.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_tests_emit {
+    // Bundle the accumulated types and emit a single module with all tests
     ($plugin_name:ident,
+        test_opts: { $($opt_key:ident : $opt_val:tt),* $(,)? },
         resources: [$($res:ty),*],
         messages: [$($msg:ty),*],
         states: [$($state:ty),*],
         deps: [$($dep:ty),*],
-        test_resources: $test_res:tt,
-        test_messages: $test_msg:tt,
-        test_states: $_old:tt,
-        test_dependencies: $test_deps:tt,
-        test_states: $new_val:tt
-        $(, $rest_key:ident : $rest_val:tt)*
+        reflected: [$($refl:ty),*]
     ) => {
         $crate::define_plugin_tests_emit_module!(
             $plugin_name,
-            resources: [$($res),*],
-            messages: [$($msg),*],
-            states: [$($state),*],
-            deps: [$($dep),*],
-            test_resources: $test_res,
-            test_messages: $test_msg,
-            test_states: $new_val,
-            test_dependencies: $test_deps
-            $(, $rest_key : $rest_val)*
+            module: __missing_module,
+            unique: true,
+            opts: { $($opt_key : $opt_val),* },
+            scan: { $($opt_key : $opt_val),* },
+            types: {
+                resources: [$($res),*],
+                messages: [$($msg),*],
+                states: [$($state),*],
+                deps: [$($dep),*],
+                reflected: [$($refl),*]
+            }
         );
     };
+}
+
+/// Helper macro to emit the test module
+///
+/// Scans the options for `module:` and `is_unique:` first, then dispatches
+/// every option to `define_plugin_test_option!` inside the module.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_tests_emit_module {
+    // No module: given. A fixed default would clash as soon as two plugins in
+    // one scope generate tests, and the plugin's own name is taken by the struct
+    ($plugin_name:ident,
+        module: __missing_module,
+        unique: $unique:tt,
+        opts: $opts:tt,
+        scan: {},
+        types: $types:tt
+    ) => {
+        compile_error!(concat!(
+            "generate_tests: for ",
+            stringify!($plugin_name),
+            " needs a `module: <name>` entry naming the generated test module, ",
+            "unique among the plugins in this scope, for example `module: my_plugin_tests`"
+        ));
+    };
 
-    // Override test_dependencies
+    // Base case - emit the module
     ($plugin_name:ident,
-        resources: [$($res:ty),*],
-        messages: [$($msg:ty),*],
-        states: [$($state:ty),*],
-        deps: [$($dep:ty),*],
-        test_resources: $test_res:tt,
-        test_messages: $test_msg:tt,
-        test_states: $test_states:tt,
-        test_dependencies: $_old:tt,
-        test_dependencies: $new_val:tt
-        $(, $rest_key:ident : $rest_val:tt)*
+        module: $module:ident,
+        unique: $unique:tt,
+        opts: { $($opt_key:ident : $opt_val:tt),* },
+        scan: {},
+        types: $types:tt
+    ) => {
+        $crate::define_plugin_if_testing! {
+            #[cfg(test)]
+            #[allow(non_snake_case)]
+            mod $module {
+                #[allow(unused_imports)]
+                use super::*;
+
+                $(
+                    $crate::define_plugin_test_option!($plugin_name, $opt_key: $opt_val, $types, $unique);
+                )*
+            }
+        }
+    };
+
+    // module: names the test module
+    ($plugin_name:ident,
+        module: $_old:ident,
+        unique: $unique:tt,
+        opts: $opts:tt,
+        scan: { module: $module:ident $(, $($rest:tt)*)? },
+        types: $types:tt
     ) => {
         $crate::define_plugin_tests_emit_module!(
             $plugin_name,
-            resources: [$($res),*],
-            messages: [$($msg),*],
-            states: [$($state),*],
-            deps: [$($dep),*],
-            test_resources: $test_res,
-            test_messages: $test_msg,
-            test_states: $test_states,
-            test_dependencies: $new_val
-            $(, $rest_key : $rest_val)*
+            module: $module,
+            unique: $unique,
+            opts: $opts,
+            scan: { $($($rest)*)? },
+            types: $types
+        );
+    };
+
+    // is_unique: false, added by define_plugin_tests_accumulate! from the plugin config
+    ($plugin_name:ident,
+        module: $module:ident,
+        unique: $_old:tt,
+        opts: $opts:tt,
+        scan: { is_unique: false $(, $($rest:tt)*)? },
+        types: $types:tt
+    ) => {
+        $crate::define_plugin_tests_emit_module!(
+            $plugin_name,
+            module: $module,
+            unique: false,
+            opts: $opts,
+            scan: { $($($rest)*)? },
+            types: $types
+        );
+    };
+
+    // Any other option is handled by define_plugin_test_option!
+    ($plugin_name:ident,
+        module: $module:ident,
+        unique: $unique:tt,
+        opts: $opts:tt,
+        scan: { $key:ident : $value:tt $(, $($rest:tt)*)? },
+        types: $types:tt
+    ) => {
+        $crate::define_plugin_tests_emit_module!(
+            $plugin_name,
+            module: $module,
+            unique: $unique,
+            opts: $opts,
+            scan: { $($($rest)*)? },
+            types: $types
         );
     };
 }
 
+/// Dispatch a single generate_tests option to its test generator
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_test_option {
+    ($plugin_name:ident, module: $module:ident, $types:tt, $unique:tt) => {};
+    ($plugin_name:ident, is_unique: $value:tt, $types:tt, $unique:tt) => {};
+    ($plugin_name:ident, test_resources: $enabled:tt, {
+        resources: $res:tt, messages: $msg:tt, states: $states:tt, deps: $deps:tt, reflected: $refl:tt
+    }, $unique:tt) => {
+        $crate::define_plugin_test_resource!($plugin_name, $enabled, $res);
+    };
+    ($plugin_name:ident, test_messages: $enabled:tt, {
+        resources: $res:tt, messages: $msg:tt, states: $states:tt, deps: $deps:tt, reflected: $refl:tt
+    }, $unique:tt) => {
+        $crate::define_plugin_test_messages!($plugin_name, $enabled, $msg);
+    };
+    ($plugin_name:ident, test_states: $enabled:tt, {
+        resources: $res:tt, messages: $msg:tt, states: $states:tt, deps: $deps:tt, reflected: $refl:tt
+    }, $unique:tt) => {
+        $crate::define_plugin_test_states!($plugin_name, $enabled, $states);
+    };
+    ($plugin_name:ident, test_dependencies: $enabled:tt, {
+        resources: $res:tt, messages: $msg:tt, states: $states:tt, deps: $deps:tt, reflected: $refl:tt
+    }, $unique:tt) => {
+        $crate::define_plugin_test_dependencies!($plugin_name, $enabled, $deps);
+    };
+    ($plugin_name:ident, test_reflect: $enabled:tt, {
+        resources: $res:tt, messages: $msg:tt, states: $states:tt, deps: $deps:tt, reflected: $refl:tt
+    }, $unique:tt) => {
+        $crate::define_plugin_test_reflect!($plugin_name, $enabled, $refl);
+    };
+    ($plugin_name:ident, test_no_duplicate_resources: $enabled:tt, {
+        resources: $res:tt, messages: $msg:tt, states: $states:tt, deps: $deps:tt, reflected: $refl:tt
+    }, $unique:tt) => {
+        $crate::define_plugin_test_no_duplicate_resources!($plugin_name, $enabled);
+    };
+    ($plugin_name:ident, test_unique: $enabled:tt, $types:tt, $unique:tt) => {
+        $crate::define_plugin_test_unique!($plugin_name, $enabled, $unique);
+    };
+    ($plugin_name:ident, test_app_boots: $enabled:tt, {
+        resources: $res:tt, messages: $msg:tt, states: $states:tt, deps: $deps:tt, reflected: $refl:tt
+    }, $unique:tt) => {
+        $crate::define_plugin_test_app_boots!($plugin_name, $enabled, $states);
+    };
+    ($plugin_name:ident, $unknown:ident : $value:tt, $types:tt, $unique:tt) => {
+        compile_error!(concat!(
+            "Unknown generate_tests option: ",
+            stringify!($unknown),
+            "\nSupported options: module, test_resources, test_messages, test_states, test_dependencies, test_reflect, test_no_duplicate_resources, test_unique, test_app_boots"
+        ));
+    };
+}
+
 /// Generate resource tests if enabled
-/// Note: Tests are generated inside const _: () = { mod tests { ... } }
-/// so we need super::super to reach the plugin type
+/// Note: Tests are generated inside the plugin's test module, so `super::`
+/// reaches the plugin type
 #[macro_export]
 #[doc(hidden)]
 macro_rules! define_plugin_test_resource {
@@ -1779,7 +5681,7 @@ macro_rules! define_plugin_test_resource {
         #[test]
         fn test_resources() {
             let mut app = ::bevy::prelude::App::new();
-            app.add_plugins(super::super::$plugin_name);
+            app.add_plugins(super::$plugin_name);
             $(
                 assert!(
                     app.world().contains_resource::<$res>(),
@@ -1800,7 +5702,7 @@ macro_rules! define_plugin_test_messages {
         #[test]
         fn test_messages() {
             let mut app = ::bevy::prelude::App::new();
-            app.add_plugins(super::super::$plugin_name);
+            app.add_plugins(super::$plugin_name);
             $(
                 assert!(
                     app.world().contains_resource::<::bevy::prelude::Messages<$msg>>(),
@@ -1822,7 +5724,7 @@ macro_rules! define_plugin_test_states {
         fn test_states() {
             let mut app = ::bevy::prelude::App::new();
             app.add_plugins(::bevy::state::app::StatesPlugin);
-            app.add_plugins(super::super::$plugin_name);
+            app.add_plugins(super::$plugin_name);
             $(
                 assert!(
                     app.world().contains_resource::<::bevy::prelude::State<$state>>(),
@@ -1845,11 +5747,147 @@ macro_rules! define_plugin_test_dependencies {
         fn test_dependencies_panic_when_missing() {
             let mut app = ::bevy::prelude::App::new();
             // Intentionally not adding dependencies - should panic
-            app.add_plugins(super::super::$plugin_name);
+            app.add_plugins(super::$plugin_name);
         }
     };
     ($plugin_name:ident, true, []) => {}; // No dependencies to test
-    ($plugin_name:ident, false, [$($dep:ty),*]) => {}; // Testing disabled
+    ($plugin_name:ident, false) => {};    // Testing disabled
+}
+
+/// Generate reflection registration tests if enabled
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_test_reflect {
+    ($plugin_name:ident, true, [$($refl:ty),+]) => {
+        #[test]
+        fn test_reflect() {
+            let mut app = ::bevy::prelude::App::new();
+            app.add_plugins(super::$plugin_name);
+            let registry = app.world().resource::<::bevy::prelude::AppTypeRegistry>().read();
+            $(
+                assert!(
+                    registry.contains(::std::any::TypeId::of::<$refl>()),
+                    concat!(stringify!($plugin_name), " should register reflected type: ", stringify!($refl))
+                );
+            )+
+        }
+    };
+    ($plugin_name:ident, true, []) => {}; // No reflected types to test
+    ($plugin_name:ident, false, [$($refl:ty),*]) => {}; // Testing disabled
+}
+
+/// Generate a test of what happens when the plugin is added twice: Bevy panics
+/// for unique plugins, while `is_unique: false` plugins can be added again
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_test_unique {
+    ($plugin_name:ident, true, true) => {
+        #[test]
+        #[should_panic(expected = "already added")]
+        fn test_unique() {
+            let mut app = ::bevy::prelude::App::new();
+            app.add_plugins(super::$plugin_name);
+            app.add_plugins(super::$plugin_name);
+        }
+    };
+    ($plugin_name:ident, true, false) => {
+        #[test]
+        fn test_unique() {
+            let mut app = ::bevy::prelude::App::new();
+            app.add_plugins(super::$plugin_name);
+            app.add_plugins(super::$plugin_name);
+            assert_eq!(
+                app.get_added_plugins::<super::$plugin_name>().len(),
+                2,
+                concat!(
+                    stringify!($plugin_name),
+                    " sets is_unique: false and should be added twice"
+                )
+            );
+        }
+    };
+    ($plugin_name:ident, false, $unique:tt) => {}; // Testing disabled
+}
+
+/// Generate a test that boots a headless app with the plugin and runs a few
+/// updates. `StatesPlugin` is added when the plugin declares states, and its
+/// dependencies, including theirs, are added first through `BootTestPlugin`.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_test_app_boots {
+    ($plugin_name:ident, true, []) => {
+        #[test]
+        fn test_app_boots() {
+            let mut app = ::bevy::prelude::App::new();
+            app.add_plugins(::bevy::prelude::MinimalPlugins);
+            <super::$plugin_name as $crate::BootTestPlugin>::add_for_boot_test(&mut app);
+            app.finish();
+            app.cleanup();
+            for _ in 0..3 {
+                app.update();
+            }
+        }
+    };
+    ($plugin_name:ident, true, [$($state:ty),+]) => {
+        #[test]
+        fn test_app_boots() {
+            let mut app = ::bevy::prelude::App::new();
+            app.add_plugins((
+                ::bevy::prelude::MinimalPlugins,
+                ::bevy::state::app::StatesPlugin,
+            ));
+            <super::$plugin_name as $crate::BootTestPlugin>::add_for_boot_test(&mut app);
+            app.finish();
+            app.cleanup();
+            for _ in 0..3 {
+                app.update();
+            }
+        }
+    };
+    ($plugin_name:ident, false, $states:tt) => {}; // Testing disabled
+}
+
+/// Generate a test that no resource is registered by more than one plugin
+/// among this plugin, its declared dependencies and theirs (requires
+/// introspection)
+#[cfg(feature = "introspection")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_test_no_duplicate_resources {
+    ($plugin_name:ident, true) => {
+        #[test]
+        fn test_no_duplicate_resources() {
+            let mut registry = $crate::PluginRegistry::new();
+            registry.register_with_dependencies::<super::$plugin_name>();
+
+            let duplicates = registry.duplicate_resources();
+            assert!(
+                duplicates.is_empty(),
+                "{}",
+                duplicates
+                    .iter()
+                    .map(|(resource, plugins)| format!(
+                        "Resource '{}' is initialized by more than one plugin: {}",
+                        resource,
+                        plugins.join(", ")
+                    ))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            );
+        }
+    };
+    ($plugin_name:ident, false) => {}; // Testing disabled
+}
+
+/// Without introspection there is no per-plugin resource list to compare
+#[cfg(not(feature = "introspection"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! define_plugin_test_no_duplicate_resources {
+    ($plugin_name:ident, true) => {
+        compile_error!("test_no_duplicate_resources requires the `introspection` feature of bevy-plugin-builder");
+    };
+    ($plugin_name:ident, false) => {}; // Testing disabled
 }
 
 // The macro is exported at crate root via #[macro_export]
@@ -0,0 +1,81 @@
+//! Message retention used by the `add_message_buffered:` key.
+//!
+//! Bevy keeps a message for two updates by swapping the double buffer of
+//! `Messages<M>` every update. A buffered message type instead swaps its
+//! buffers every `frames - 1` updates, so messages stay readable by systems
+//! that poll at a lower rate.
+
+use bevy::ecs::message::{Message, Messages};
+use bevy::prelude::*;
+use std::marker::PhantomData;
+
+/// Update counter for a message type registered with `add_message_buffered:`.
+///
+/// A message stays readable for at least `frames` updates, counting the
+/// update it was sent in, and for at most `2 * frames - 2` updates.
+#[derive(Resource)]
+pub struct MessageRetention<M: Message> {
+    frames: u32,
+    updates_since_swap: u32,
+    _message: PhantomData<fn() -> M>,
+}
+
+impl<M: Message> MessageRetention<M> {
+    /// Keep messages of type `M` for `frames` updates.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `frames` is less than 2, the retention Bevy already provides.
+    pub fn new(frames: u32) -> Self {
+        assert!(
+            frames >= 2,
+            "add_message_buffered requires at least 2 frames, got {}",
+            frames
+        );
+        Self {
+            frames,
+            updates_since_swap: 0,
+            _message: PhantomData,
+        }
+    }
+
+    /// Number of updates messages are kept for
+    pub fn frames(&self) -> u32 {
+        self.frames
+    }
+}
+
+/// Swap the buffers of `Messages<M>` once every `frames - 1` updates.
+///
+/// Runs in `First`, where Bevy updates its automatically cleared messages.
+pub fn update_retained_messages<M: Message>(
+    mut retention: ResMut<MessageRetention<M>>,
+    mut messages: ResMut<Messages<M>>,
+) {
+    retention.updates_since_swap += 1;
+    if retention.updates_since_swap >= retention.frames - 1 {
+        retention.updates_since_swap = 0;
+        messages.update();
+    }
+}
+
+/// Register message `M` to be kept for `frames` updates.
+///
+/// Called from the generated `build()` for `add_message_buffered:` entries.
+///
+/// # Panics
+///
+/// Panics if `M` is already registered, for example with `add_message`: Bevy
+/// would then also update its buffers every frame, clearing messages before
+/// the retention is up. Registering `M` with `add_message` after this is fine,
+/// as Bevy skips message types that already have a `Messages<M>` resource.
+pub fn add_buffered_message<M: Message>(app: &mut App, frames: u32) {
+    assert!(
+        !app.world().contains_resource::<Messages<M>>(),
+        "add_message_buffered: `{}` is already registered, and Bevy clears a message registered with add_message every other update; register it with add_message_buffered only",
+        std::any::type_name::<M>()
+    );
+    app.init_resource::<Messages<M>>()
+        .insert_resource(MessageRetention::<M>::new(frames))
+        .add_systems(First, update_retained_messages::<M>);
+}
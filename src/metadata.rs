@@ -47,6 +47,8 @@ pub struct PluginSystems {
     pub update: &'static [&'static str],
     /// Names of fixed update systems
     pub fixed_update: &'static [&'static str],
+    /// Names of StateTransition systems
+    pub state_transition: &'static [&'static str],
     /// Number of on_enter state systems
     pub on_enter_count: usize,
     /// Number of on_exit state systems
@@ -61,6 +63,8 @@ pub struct PluginSystems {
 pub struct PluginMetadata {
     /// Plugin name (usually the struct name)
     pub name: &'static str,
+    /// Human-readable name from `meta: { display_name }` (if provided)
+    pub display_name: Option<&'static str>,
     /// Version string from meta block (if provided)
     pub version: Option<&'static str>,
     /// Description from meta block (if provided)
@@ -75,10 +79,18 @@ pub struct PluginMetadata {
     pub sub_states: &'static [TypeInfo],
     /// Types registered for reflection
     pub reflected_types: &'static [TypeInfo],
+    /// Components registered for reflection with register_components
+    pub reflected_components: &'static [TypeInfo],
     /// Sub-plugins added
     pub sub_plugins: &'static [&'static str],
     /// Plugin dependencies
     pub dependencies: &'static [&'static str],
+    /// Observers added with add_observers, as written in the macro
+    pub observers: &'static [&'static str],
+    /// Whether the plugin has a `custom_build` closure or function
+    pub has_custom_build: bool,
+    /// Whether the plugin has a `custom_finish` closure or function
+    pub has_custom_finish: bool,
     /// System information
     pub systems: PluginSystems,
 }
@@ -88,6 +100,7 @@ impl PluginMetadata {
     pub const fn empty(name: &'static str) -> Self {
         Self {
             name,
+            display_name: None,
             version: None,
             description: None,
             resources: &[],
@@ -95,12 +108,17 @@ impl PluginMetadata {
             states: &[],
             sub_states: &[],
             reflected_types: &[],
+            reflected_components: &[],
             sub_plugins: &[],
             dependencies: &[],
+            observers: &[],
+            has_custom_build: false,
+            has_custom_finish: false,
             systems: PluginSystems {
                 startup: &[],
                 update: &[],
                 fixed_update: &[],
+                state_transition: &[],
                 on_enter_count: 0,
                 on_exit_count: 0,
             },
@@ -127,11 +145,28 @@ impl PluginMetadata {
         self.states.iter().any(|info| info.type_id() == target_id)
     }
 
+    /// Check if this plugin registers a specific type for reflection
+    pub fn has_reflected_type<T: 'static>(&self) -> bool {
+        let target_id = TypeId::of::<T>();
+        self.reflected_types
+            .iter()
+            .any(|info| info.type_id() == target_id)
+    }
+
+    /// Check if this plugin registers a specific component for reflection
+    pub fn has_reflected_component<C: 'static>(&self) -> bool {
+        let target_id = TypeId::of::<C>();
+        self.reflected_components
+            .iter()
+            .any(|info| info.type_id() == target_id)
+    }
+
     /// Get the total number of systems registered by this plugin
     pub fn total_systems(&self) -> usize {
         self.systems.startup.len()
             + self.systems.update.len()
             + self.systems.fixed_update.len()
+            + self.systems.state_transition.len()
             + self.systems.on_enter_count
             + self.systems.on_exit_count
     }
@@ -140,6 +175,330 @@ impl PluginMetadata {
     pub fn depends_on(&self, plugin_name: &str) -> bool {
         self.dependencies.contains(&plugin_name)
     }
+
+    /// Compare registrations with another metadata snapshot
+    ///
+    /// "Added" entries are present in `other` but not in `self`; "removed"
+    /// entries are present in `self` but not in `other`.
+    pub fn diff(&self, other: &PluginMetadata) -> PluginMetadataDiff {
+        let (added_resources, removed_resources) = diff_types(self.resources, other.resources);
+        let (added_messages, removed_messages) = diff_types(self.messages, other.messages);
+        let (added_states, removed_states) = diff_types(self.states, other.states);
+
+        let old_systems = self.system_names();
+        let new_systems = other.system_names();
+
+        PluginMetadataDiff {
+            added_resources,
+            removed_resources,
+            added_messages,
+            removed_messages,
+            added_states,
+            removed_states,
+            added_systems: missing_from(&new_systems, &old_systems),
+            removed_systems: missing_from(&old_systems, &new_systems),
+        }
+    }
+
+    /// Copy this metadata into an owned snapshot
+    ///
+    /// The snapshot holds `String`s instead of `'static` references, so it
+    /// can be stored or sent to another thread without borrowing plugin statics.
+    pub fn to_owned_snapshot(&self) -> OwnedPluginMetadata {
+        OwnedPluginMetadata::from(self)
+    }
+
+    /// Render the plugin's systems as a text tree, grouped by schedule
+    ///
+    /// Startup, Update, FixedUpdate and StateTransition list their system
+    /// names; OnEnter and OnExit systems are only counted, as their states
+    /// aren't recorded.
+    ///
+    /// ```text
+    /// GamePlugin
+    ///   Startup
+    ///     setup
+    ///   Update
+    ///     movement
+    ///     combat
+    ///   FixedUpdate (none)
+    ///   StateTransition (none)
+    ///   OnEnter: 2 systems
+    ///   OnExit: 0 systems
+    /// ```
+    pub fn schedule_graph(&self) -> String {
+        let mut graph = String::from(self.name);
+        let schedules = [
+            ("Startup", self.systems.startup),
+            ("Update", self.systems.update),
+            ("FixedUpdate", self.systems.fixed_update),
+            ("StateTransition", self.systems.state_transition),
+        ];
+        for (schedule, systems) in schedules {
+            if systems.is_empty() {
+                graph.push_str(&format!("\n  {} (none)", schedule));
+                continue;
+            }
+            graph.push_str(&format!("\n  {}", schedule));
+            for system in systems {
+                graph.push_str(&format!("\n    {}", system));
+            }
+        }
+        graph.push_str(&format!(
+            "\n  OnEnter: {} systems\n  OnExit: {} systems",
+            self.systems.on_enter_count, self.systems.on_exit_count
+        ));
+        graph
+    }
+
+    /// Check the metadata for internal consistency
+    ///
+    /// Returns every problem found: an empty name, a version that isn't
+    /// `MAJOR.MINOR.PATCH` semver, or a type listed twice within the same
+    /// category.
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut problems = Vec::new();
+
+        if self.name.trim().is_empty() {
+            problems.push("plugin name is empty".to_string());
+        }
+
+        if let Some(version) = self.version {
+            if !is_semver(version) {
+                problems.push(format!("version '{}' is not valid semver", version));
+            }
+        }
+
+        let categories = [
+            ("resource", self.resources),
+            ("message", self.messages),
+            ("state", self.states),
+            ("sub state", self.sub_states),
+            ("reflected type", self.reflected_types),
+            ("reflected component", self.reflected_components),
+        ];
+        for (kind, types) in categories {
+            for (index, info) in types.iter().enumerate() {
+                if types[..index].iter().any(|other| other.name == info.name) {
+                    problems.push(format!("{} {} is listed more than once", kind, info.name));
+                }
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems)
+        }
+    }
+
+    /// Names of all startup, update, fixed update and state transition systems
+    fn system_names(&self) -> Vec<&'static str> {
+        self.systems
+            .startup
+            .iter()
+            .chain(self.systems.update)
+            .chain(self.systems.fixed_update)
+            .chain(self.systems.state_transition)
+            .copied()
+            .collect()
+    }
+}
+
+/// Owned copy of a plugin's metadata.
+///
+/// Produced by `PluginMetadata::to_owned_snapshot`. Types are stored by name,
+/// since the `TypeInfo` id functions are only needed for live queries.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "reflect", derive(bevy::reflect::Reflect))]
+pub struct OwnedPluginMetadata {
+    /// Plugin name
+    pub name: String,
+    /// Human-readable name from `meta: { display_name }` (if provided)
+    pub display_name: Option<String>,
+    /// Version string from meta block (if provided)
+    pub version: Option<String>,
+    /// Description from meta block (if provided)
+    pub description: Option<String>,
+    /// Names of resources registered with init_resource
+    pub resources: Vec<String>,
+    /// Names of messages registered with add_message
+    pub messages: Vec<String>,
+    /// Names of states registered with init_state
+    pub states: Vec<String>,
+    /// Names of sub-states registered with add_sub_state
+    pub sub_states: Vec<String>,
+    /// Names of types registered for reflection
+    pub reflected_types: Vec<String>,
+    /// Names of components registered for reflection
+    pub reflected_components: Vec<String>,
+    /// Sub-plugins added
+    pub sub_plugins: Vec<String>,
+    /// Plugin dependencies
+    pub dependencies: Vec<String>,
+    /// Observers added with add_observers
+    pub observers: Vec<String>,
+    /// Whether the plugin has a `custom_build` closure or function
+    pub has_custom_build: bool,
+    /// Whether the plugin has a `custom_finish` closure or function
+    pub has_custom_finish: bool,
+    /// Names of startup systems
+    pub startup_systems: Vec<String>,
+    /// Names of update systems
+    pub update_systems: Vec<String>,
+    /// Names of fixed update systems
+    pub fixed_update_systems: Vec<String>,
+    /// Names of StateTransition systems
+    pub state_transition_systems: Vec<String>,
+    /// Number of on_enter state systems
+    pub on_enter_count: usize,
+    /// Number of on_exit state systems
+    pub on_exit_count: usize,
+}
+
+impl From<&PluginMetadata> for OwnedPluginMetadata {
+    fn from(metadata: &PluginMetadata) -> Self {
+        Self {
+            name: metadata.name.to_string(),
+            display_name: metadata.display_name.map(str::to_string),
+            version: metadata.version.map(str::to_string),
+            description: metadata.description.map(str::to_string),
+            resources: type_names(metadata.resources),
+            messages: type_names(metadata.messages),
+            states: type_names(metadata.states),
+            sub_states: type_names(metadata.sub_states),
+            reflected_types: type_names(metadata.reflected_types),
+            reflected_components: type_names(metadata.reflected_components),
+            sub_plugins: owned_names(metadata.sub_plugins),
+            dependencies: owned_names(metadata.dependencies),
+            observers: owned_names(metadata.observers),
+            has_custom_build: metadata.has_custom_build,
+            has_custom_finish: metadata.has_custom_finish,
+            startup_systems: owned_names(metadata.systems.startup),
+            update_systems: owned_names(metadata.systems.update),
+            fixed_update_systems: owned_names(metadata.systems.fixed_update),
+            state_transition_systems: owned_names(metadata.systems.state_transition),
+            on_enter_count: metadata.systems.on_enter_count,
+            on_exit_count: metadata.systems.on_exit_count,
+        }
+    }
+}
+
+/// Owned names of the types in a type list
+fn type_names(types: &[TypeInfo]) -> Vec<String> {
+    types.iter().map(|info| info.name.to_string()).collect()
+}
+
+/// Owned copies of a list of names
+fn owned_names(names: &[&'static str]) -> Vec<String> {
+    names.iter().map(|name| name.to_string()).collect()
+}
+
+/// Returns (added, removed) type names between two type lists
+fn diff_types(old: &[TypeInfo], new: &[TypeInfo]) -> (Vec<&'static str>, Vec<&'static str>) {
+    let added = new
+        .iter()
+        .filter(|info| !old.contains(info))
+        .map(|info| info.name)
+        .collect();
+    let removed = old
+        .iter()
+        .filter(|info| !new.contains(info))
+        .map(|info| info.name)
+        .collect();
+    (added, removed)
+}
+
+/// Whether `version` is `MAJOR.MINOR.PATCH` with optional pre-release and
+/// build suffixes
+fn is_semver(version: &str) -> bool {
+    let version = version.split_once('+').map_or(version, |(core, _)| core);
+    let core = version.split_once('-').map_or(version, |(core, _)| core);
+    let parts: Vec<&str> = core.split('.').collect();
+    parts.len() == 3
+        && parts.iter().all(|part| {
+            !part.is_empty()
+                && part.bytes().all(|b| b.is_ascii_digit())
+                && (part.len() == 1 || !part.starts_with('0'))
+        })
+}
+
+/// Names in `names` that don't appear in `other`
+fn missing_from(names: &[&'static str], other: &[&'static str]) -> Vec<&'static str> {
+    names
+        .iter()
+        .filter(|name| !other.contains(name))
+        .copied()
+        .collect()
+}
+
+/// Differences between two `PluginMetadata` snapshots.
+///
+/// Produced by `PluginMetadata::diff`. The `Display` impl lists one change
+/// per line, prefixed with `+` or `-`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PluginMetadataDiff {
+    /// Resources only in the newer metadata
+    pub added_resources: Vec<&'static str>,
+    /// Resources only in the older metadata
+    pub removed_resources: Vec<&'static str>,
+    /// Messages only in the newer metadata
+    pub added_messages: Vec<&'static str>,
+    /// Messages only in the older metadata
+    pub removed_messages: Vec<&'static str>,
+    /// States only in the newer metadata
+    pub added_states: Vec<&'static str>,
+    /// States only in the older metadata
+    pub removed_states: Vec<&'static str>,
+    /// System names only in the newer metadata
+    pub added_systems: Vec<&'static str>,
+    /// System names only in the older metadata
+    pub removed_systems: Vec<&'static str>,
+}
+
+impl PluginMetadataDiff {
+    /// Check if the two snapshots had identical registrations
+    pub fn is_empty(&self) -> bool {
+        self.added_resources.is_empty()
+            && self.removed_resources.is_empty()
+            && self.added_messages.is_empty()
+            && self.removed_messages.is_empty()
+            && self.added_states.is_empty()
+            && self.removed_states.is_empty()
+            && self.added_systems.is_empty()
+            && self.removed_systems.is_empty()
+    }
+}
+
+impl std::fmt::Display for PluginMetadataDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            return write!(f, "no changes");
+        }
+
+        let sections: [(&str, &str, &[&'static str]); 8] = [
+            ("+", "resource", &self.added_resources),
+            ("-", "resource", &self.removed_resources),
+            ("+", "message", &self.added_messages),
+            ("-", "message", &self.removed_messages),
+            ("+", "state", &self.added_states),
+            ("-", "state", &self.removed_states),
+            ("+", "system", &self.added_systems),
+            ("-", "system", &self.removed_systems),
+        ];
+
+        let mut first = true;
+        for (sign, kind, names) in sections {
+            for name in names {
+                if !first {
+                    writeln!(f)?;
+                }
+                write!(f, "{} {} {}", sign, kind, name)?;
+                first = false;
+            }
+        }
+        Ok(())
+    }
 }
 
 /// Trait for plugins that can provide static metadata.
@@ -154,10 +513,29 @@ pub trait PluginInfo: Plugin {
     /// The plugin's version (from meta block)
     const VERSION: Option<&'static str> = None;
 
+    /// Number of `Startup` system entries, usable in const assertions
+    const STARTUP_SYSTEM_COUNT: usize = 0;
+
+    /// Number of `Update` system entries, usable in const assertions
+    const UPDATE_SYSTEM_COUNT: usize = 0;
+
+    /// Number of `FixedUpdate` system entries, usable in const assertions
+    const FIXED_SYSTEM_COUNT: usize = 0;
+
     /// Get the static metadata for this plugin
     fn metadata() -> &'static PluginMetadata;
 }
 
+/// Trait for plugins whose `depends_on` plugins also provide metadata.
+///
+/// This trait is automatically implemented by `define_plugin!` when the
+/// `introspection` feature is enabled, for plugins whose dependencies
+/// implement it too.
+pub trait PluginDependencyTree: PluginInfo {
+    /// Register the plugins this one depends on, and theirs, then this one
+    fn register_with_dependencies(registry: &mut crate::PluginRegistry);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -183,6 +561,7 @@ mod tests {
 
         let metadata = PluginMetadata {
             name: "TestPlugin",
+            display_name: None,
             version: Some("1.0.0"),
             description: Some("A test plugin"),
             resources: &TEST_RESOURCES,
@@ -190,8 +569,12 @@ mod tests {
             states: &[],
             sub_states: &[],
             reflected_types: &[],
+            reflected_components: &[],
             sub_plugins: &[],
             dependencies: &TEST_DEPS,
+            observers: &[],
+            has_custom_build: false,
+            has_custom_finish: false,
             systems: PluginSystems::default(),
         };
 
@@ -205,6 +588,161 @@ mod tests {
         assert_eq!(metadata.total_systems(), 0);
     }
 
+    #[test]
+    fn test_plugin_metadata_diff() {
+        static ONE_RESOURCE: [TypeInfo; 1] = [TypeInfo::new::<String>("String")];
+        static TWO_RESOURCES: [TypeInfo; 2] = [
+            TypeInfo::new::<String>("String"),
+            TypeInfo::new::<i32>("i32"),
+        ];
+
+        let before = PluginMetadata {
+            resources: &ONE_RESOURCE,
+            ..PluginMetadata::empty("DiffPlugin")
+        };
+        let after = PluginMetadata {
+            resources: &TWO_RESOURCES,
+            systems: PluginSystems {
+                update: &["tick"],
+                ..PluginSystems::default()
+            },
+            ..PluginMetadata::empty("DiffPlugin")
+        };
+
+        let diff = before.diff(&after);
+        assert_eq!(diff.added_resources, vec!["i32"]);
+        assert!(diff.removed_resources.is_empty());
+        assert_eq!(diff.added_systems, vec!["tick"]);
+        assert_eq!(diff.to_string(), "+ resource i32\n+ system tick");
+
+        let reverse = after.diff(&before);
+        assert_eq!(reverse.removed_resources, vec!["i32"]);
+        assert_eq!(reverse.removed_systems, vec!["tick"]);
+
+        assert!(before.diff(&before).is_empty());
+        assert_eq!(before.diff(&before).to_string(), "no changes");
+    }
+
+    #[test]
+    fn test_owned_snapshot() {
+        static RESOURCES: [TypeInfo; 1] = [TypeInfo::new::<String>("String")];
+        static MESSAGES: [TypeInfo; 1] = [TypeInfo::new::<u8>("u8")];
+        static STATES: [TypeInfo; 1] = [TypeInfo::new::<u16>("u16")];
+        static SUB_STATES: [TypeInfo; 1] = [TypeInfo::new::<u32>("u32")];
+        static REFLECTED: [TypeInfo; 1] = [TypeInfo::new::<i32>("i32")];
+
+        let metadata = PluginMetadata {
+            name: "SnapshotPlugin",
+            display_name: None,
+            version: Some("2.0.0"),
+            description: Some("Snapshot test"),
+            resources: &RESOURCES,
+            messages: &MESSAGES,
+            states: &STATES,
+            sub_states: &SUB_STATES,
+            reflected_types: &REFLECTED,
+            reflected_components: &[],
+            sub_plugins: &["ChildPlugin"],
+            dependencies: &["OtherPlugin"],
+            observers: &["on_spawn"],
+            has_custom_build: true,
+            has_custom_finish: false,
+            systems: PluginSystems {
+                startup: &["setup"],
+                update: &["tick"],
+                fixed_update: &["physics"],
+                state_transition: &["log_transition"],
+                on_enter_count: 2,
+                on_exit_count: 1,
+            },
+        };
+
+        let snapshot = metadata.to_owned_snapshot();
+        assert_eq!(snapshot.name, "SnapshotPlugin");
+        assert_eq!(snapshot.version.as_deref(), Some("2.0.0"));
+        assert_eq!(snapshot.description.as_deref(), Some("Snapshot test"));
+        assert_eq!(snapshot.resources, vec!["String"]);
+        assert_eq!(snapshot.messages, vec!["u8"]);
+        assert_eq!(snapshot.states, vec!["u16"]);
+        assert_eq!(snapshot.sub_states, vec!["u32"]);
+        assert_eq!(snapshot.reflected_types, vec!["i32"]);
+        assert_eq!(snapshot.sub_plugins, vec!["ChildPlugin"]);
+        assert_eq!(snapshot.dependencies, vec!["OtherPlugin"]);
+        assert_eq!(snapshot.observers, vec!["on_spawn"]);
+        assert!(snapshot.has_custom_build);
+        assert!(!snapshot.has_custom_finish);
+        assert_eq!(snapshot.startup_systems, vec!["setup"]);
+        assert_eq!(snapshot.update_systems, vec!["tick"]);
+        assert_eq!(snapshot.fixed_update_systems, vec!["physics"]);
+        assert_eq!(snapshot.state_transition_systems, vec!["log_transition"]);
+        assert_eq!(snapshot.on_enter_count, 2);
+        assert_eq!(snapshot.on_exit_count, 1);
+
+        // Snapshots can be moved to another thread
+        let name = std::thread::spawn(move || snapshot.name).join().unwrap();
+        assert_eq!(name, "SnapshotPlugin");
+    }
+
+    #[test]
+    fn test_schedule_graph() {
+        let metadata = PluginMetadata {
+            systems: PluginSystems {
+                startup: &["setup"],
+                update: &["movement", "combat"],
+                on_enter_count: 2,
+                ..PluginSystems::default()
+            },
+            ..PluginMetadata::empty("GamePlugin")
+        };
+
+        assert_eq!(
+            metadata.schedule_graph(),
+            "GamePlugin\n  Startup\n    setup\n  Update\n    movement\n    combat\n  \
+             FixedUpdate (none)\n  StateTransition (none)\n  \
+             OnEnter: 2 systems\n  OnExit: 0 systems"
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        static RESOURCES: [TypeInfo; 2] = [
+            TypeInfo::new::<String>("String"),
+            TypeInfo::new::<i32>("i32"),
+        ];
+        static DUPLICATE_RESOURCES: [TypeInfo; 2] = [
+            TypeInfo::new::<String>("String"),
+            TypeInfo::new::<String>("String"),
+        ];
+
+        let valid = PluginMetadata {
+            version: Some("1.2.3-beta.1"),
+            resources: &RESOURCES,
+            systems: PluginSystems {
+                update: &["tick"],
+                on_enter_count: 1,
+                ..PluginSystems::default()
+            },
+            ..PluginMetadata::empty("ValidPlugin")
+        };
+        assert_eq!(valid.validate(), Ok(()));
+        assert_eq!(PluginMetadata::empty("EmptyPlugin").validate(), Ok(()));
+
+        let duplicate = PluginMetadata {
+            resources: &DUPLICATE_RESOURCES,
+            ..PluginMetadata::empty("DuplicatePlugin")
+        };
+        assert_eq!(
+            duplicate.validate(),
+            Err(vec!["resource String is listed more than once".to_string()])
+        );
+
+        let broken = PluginMetadata {
+            version: Some("1.0"),
+            ..PluginMetadata::empty("")
+        };
+        assert_eq!(broken.validate().unwrap_err().len(), 2);
+    }
+
     #[test]
     fn test_empty_metadata() {
         let metadata = PluginMetadata::empty("EmptyPlugin");
@@ -0,0 +1,35 @@
+//! Common imports for plugins built with this crate.
+//!
+//! ```rust
+//! use bevy::prelude::*;
+//! use bevy_plugin_builder::prelude::*;
+//!
+//! #[derive(Resource, Default)]
+//! struct Score(u32);
+//!
+//! define_plugin!(ScorePlugin {
+//!     init_resource: [Score]
+//! });
+//!
+//! let mut app = App::new();
+//! app.add_plugin_checked(ScorePlugin).unwrap();
+//! ```
+//!
+//! Bevy's own types are not included, apart from the `StateTransition`
+//! schedule label used by `add_systems_state_transition:`; import
+//! `bevy::prelude::*` alongside it.
+
+pub use crate::{define_plugin, define_plugins};
+
+pub use crate::StateTransition;
+
+pub use crate::{
+    AppPluginExt, BuiltPlugin, MissingPluginError, PluginBuilder, PluginDependencies, PluginMarker,
+    PluginSet, PluginSystemSet,
+};
+
+#[cfg(feature = "introspection")]
+pub use crate::{
+    OwnedPluginMetadata, PluginDebugReport, PluginDependencyTree, PluginInfo, PluginMetadata,
+    PluginMetadataDiff, PluginRegistry,
+};
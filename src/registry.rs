@@ -4,7 +4,7 @@
 //! registered plugins and allows querying their metadata at runtime.
 //! Enabled by the `introspection` feature.
 
-use crate::metadata::{PluginInfo, PluginMetadata};
+use crate::metadata::{PluginDependencyTree, PluginInfo, PluginMetadata};
 use bevy::prelude::*;
 use std::any::TypeId;
 use std::collections::HashMap;
@@ -28,10 +28,10 @@ use std::collections::HashMap;
 /// ```
 #[derive(Resource, Default)]
 pub struct PluginRegistry {
-    /// Map from plugin TypeId to its metadata
-    plugins: HashMap<TypeId, &'static PluginMetadata>,
-    /// Order in which plugins were registered
-    load_order: Vec<TypeId>,
+    /// Plugin metadata in registration order
+    plugins: Vec<&'static PluginMetadata>,
+    /// Map from plugin TypeId to its position in `plugins`
+    by_type: HashMap<TypeId, usize>,
 }
 
 impl PluginRegistry {
@@ -45,9 +45,35 @@ impl PluginRegistry {
     /// Called automatically by the macro-generated plugin code.
     pub fn register<P: PluginInfo + 'static>(&mut self) {
         let type_id = TypeId::of::<P>();
-        if let std::collections::hash_map::Entry::Vacant(e) = self.plugins.entry(type_id) {
-            e.insert(P::metadata());
-            self.load_order.push(type_id);
+        if let std::collections::hash_map::Entry::Vacant(e) = self.by_type.entry(type_id) {
+            let metadata = P::metadata();
+            #[cfg(debug_assertions)]
+            if let Err(problems) = metadata.validate() {
+                warn!(
+                    "Plugin {} has inconsistent metadata: {}",
+                    metadata.name,
+                    problems.join("; ")
+                );
+            }
+            e.insert(self.plugins.len());
+            self.plugins.push(metadata);
+        }
+    }
+
+    /// Register a plugin after the plugins it declares in `depends_on`, and
+    /// theirs, so queries such as `duplicate_resources` cover its whole
+    /// dependency tree
+    pub fn register_with_dependencies<P: PluginDependencyTree + 'static>(&mut self) {
+        P::register_with_dependencies(self);
+    }
+
+    /// Register metadata that is not tied to a plugin type
+    ///
+    /// Used for plugins created at runtime with `PluginBuilder`. Metadata
+    /// with the same name as an already registered plugin is ignored.
+    pub fn register_metadata(&mut self, metadata: &'static PluginMetadata) {
+        if self.find_by_name(metadata.name).is_none() {
+            self.plugins.push(metadata);
         }
     }
 
@@ -55,12 +81,14 @@ impl PluginRegistry {
     ///
     /// Returns `None` if the plugin wasn't registered with introspection.
     pub fn get<P: PluginInfo + 'static>(&self) -> Option<&'static PluginMetadata> {
-        self.plugins.get(&TypeId::of::<P>()).copied()
+        self.by_type
+            .get(&TypeId::of::<P>())
+            .map(|&index| self.plugins[index])
     }
 
     /// Check if a plugin type is registered
     pub fn is_registered<P: PluginInfo + 'static>(&self) -> bool {
-        self.plugins.contains_key(&TypeId::of::<P>())
+        self.by_type.contains_key(&TypeId::of::<P>())
     }
 
     /// Get the number of registered plugins
@@ -75,24 +103,39 @@ impl PluginRegistry {
 
     /// List all registered plugins in registration order
     pub fn list_all(&self) -> impl Iterator<Item = &'static PluginMetadata> + '_ {
-        self.load_order
-            .iter()
-            .filter_map(|id| self.plugins.get(id).copied())
+        self.plugins.iter().copied()
+    }
+
+    /// List all registered plugins with their zero-based position in
+    /// registration order
+    pub fn enumerate_load_order(
+        &self,
+    ) -> impl Iterator<Item = (usize, &'static PluginMetadata)> + '_ {
+        self.list_all().enumerate()
+    }
+
+    /// Get the zero-based registration position of a plugin by name
+    pub fn load_position(&self, name: &str) -> Option<usize> {
+        self.list_all().position(|meta| meta.name == name)
     }
 
     /// Find plugins that registered a specific resource type
     pub fn plugins_with_resource<R: 'static>(&self) -> Vec<&'static str> {
-        self.plugins
-            .values()
-            .filter(|meta| meta.has_resource::<R>())
+        self.plugins_with_resource_id(TypeId::of::<R>())
+    }
+
+    /// Find plugins that registered the resource type with this `TypeId`,
+    /// for callers that only have a `TypeInfo`
+    pub(crate) fn plugins_with_resource_id(&self, type_id: TypeId) -> Vec<&'static str> {
+        self.list_all()
+            .filter(|meta| meta.resources.iter().any(|info| info.type_id() == type_id))
             .map(|meta| meta.name)
             .collect()
     }
 
     /// Find plugins that registered a specific message type
     pub fn plugins_with_message<M: 'static>(&self) -> Vec<&'static str> {
-        self.plugins
-            .values()
+        self.list_all()
             .filter(|meta| meta.has_message::<M>())
             .map(|meta| meta.name)
             .collect()
@@ -100,45 +143,125 @@ impl PluginRegistry {
 
     /// Find plugins that registered a specific state type
     pub fn plugins_with_state<S: 'static>(&self) -> Vec<&'static str> {
-        self.plugins
-            .values()
+        self.list_all()
             .filter(|meta| meta.has_state::<S>())
             .map(|meta| meta.name)
             .collect()
     }
 
+    /// Find plugins that registered a specific type for reflection
+    pub fn plugins_with_reflected_type<T: 'static>(&self) -> Vec<&'static str> {
+        self.list_all()
+            .filter(|meta| meta.has_reflected_type::<T>())
+            .map(|meta| meta.name)
+            .collect()
+    }
+
+    /// Find plugins that declare a dependency on the named plugin
+    pub fn plugins_depending_on(&self, name: &str) -> Vec<&'static str> {
+        self.list_all()
+            .filter(|meta| meta.depends_on(name))
+            .map(|meta| meta.name)
+            .collect()
+    }
+
+    /// Find plugins that declare no dependencies, the entry points of the
+    /// dependency graph, in registration order
+    pub fn roots(&self) -> Vec<&'static str> {
+        self.list_all()
+            .filter(|meta| meta.dependencies.is_empty())
+            .map(|meta| meta.name)
+            .collect()
+    }
+
+    /// Find plugins that no registered plugin depends on, the terminal nodes
+    /// of the dependency graph, in registration order
+    pub fn leaves(&self) -> Vec<&'static str> {
+        self.list_all()
+            .filter(|meta| self.plugins_depending_on(meta.name).is_empty())
+            .map(|meta| meta.name)
+            .collect()
+    }
+
+    /// Find declared dependencies that no registered plugin satisfies
+    ///
+    /// Returns `(plugin, missing_dependency)` name pairs in registration order,
+    /// so every unsatisfied dependency can be reported at once, e.g. from a
+    /// startup system after all plugins are built.
+    pub fn unsatisfied_dependencies(&self) -> Vec<(&'static str, &'static str)> {
+        self.list_all()
+            .flat_map(|meta| {
+                meta.dependencies
+                    .iter()
+                    .filter(|dependency| self.find_by_name(dependency).is_none())
+                    .map(move |dependency| (meta.name, *dependency))
+            })
+            .collect()
+    }
+
+    /// Find resource types initialized by more than one plugin
+    ///
+    /// Returns each duplicated resource name with the plugins that register it,
+    /// in registration order.
+    pub fn duplicate_resources(&self) -> Vec<(&'static str, Vec<&'static str>)> {
+        let mut owners: Vec<(TypeId, &'static str, Vec<&'static str>)> = Vec::new();
+        for meta in self.list_all() {
+            for resource in meta.resources {
+                let type_id = resource.type_id();
+                match owners.iter_mut().find(|(id, _, _)| *id == type_id) {
+                    Some((_, _, plugins)) => plugins.push(meta.name),
+                    None => owners.push((type_id, resource.name, vec![meta.name])),
+                }
+            }
+        }
+
+        owners
+            .into_iter()
+            .filter(|(_, _, plugins)| plugins.len() > 1)
+            .map(|(_, name, plugins)| (name, plugins))
+            .collect()
+    }
+
     /// Get the total number of resources registered across all plugins
     pub fn total_resources(&self) -> usize {
-        self.plugins.values().map(|meta| meta.resources.len()).sum()
+        self.list_all().map(|meta| meta.resources.len()).sum()
+    }
+
+    /// Get the total number of messages registered across all plugins
+    pub fn total_messages(&self) -> usize {
+        self.list_all().map(|meta| meta.messages.len()).sum()
+    }
+
+    /// Get the total number of states initialized across all plugins
+    pub fn total_states(&self) -> usize {
+        self.list_all().map(|meta| meta.states.len()).sum()
+    }
+
+    /// Get the total number of types registered for reflection across all plugins
+    pub fn total_reflected_types(&self) -> usize {
+        self.list_all().map(|meta| meta.reflected_types.len()).sum()
     }
 
     /// Get the total number of systems registered across all plugins
     pub fn total_systems(&self) -> usize {
-        self.plugins.values().map(|meta| meta.total_systems()).sum()
+        self.list_all().map(|meta| meta.total_systems()).sum()
     }
 
     /// Find a plugin by name
     pub fn find_by_name(&self, name: &str) -> Option<&'static PluginMetadata> {
-        self.plugins
-            .values()
-            .find(|meta| meta.name == name)
-            .copied()
+        self.list_all().find(|meta| meta.name == name)
     }
 
     /// Get all plugin names
     pub fn plugin_names(&self) -> Vec<&'static str> {
-        self.load_order
-            .iter()
-            .filter_map(|id| self.plugins.get(id))
-            .map(|meta| meta.name)
-            .collect()
+        self.list_all().map(|meta| meta.name).collect()
     }
 }
 
 impl std::fmt::Debug for PluginRegistry {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("PluginRegistry")
-            .field("plugin_count", &self.plugins.len())
+            .field("plugin_count", &self.len())
             .field("plugins", &self.plugin_names())
             .finish()
     }
@@ -160,6 +283,7 @@ mod tests {
 
     static MOCK_METADATA: PluginMetadata = PluginMetadata {
         name: "MockPlugin",
+        display_name: None,
         version: Some("1.0.0"),
         description: None,
         resources: &MOCK_RESOURCES,
@@ -167,12 +291,17 @@ mod tests {
         states: &[],
         sub_states: &[],
         reflected_types: &[],
+        reflected_components: &[],
         sub_plugins: &[],
         dependencies: &[],
+        observers: &[],
+        has_custom_build: false,
+        has_custom_finish: false,
         systems: PluginSystems {
             startup: &[],
             update: &[],
             fixed_update: &[],
+            state_transition: &[],
             on_enter_count: 0,
             on_exit_count: 0,
         },
@@ -238,6 +367,115 @@ mod tests {
         assert!(not_found.is_none());
     }
 
+    #[test]
+    fn test_registry_duplicate_resources() {
+        static OTHER_METADATA: PluginMetadata = PluginMetadata {
+            resources: &MOCK_RESOURCES,
+            ..PluginMetadata::empty("OtherMockPlugin")
+        };
+
+        let mut registry = PluginRegistry::new();
+        registry.register::<MockPlugin>();
+        assert!(registry.duplicate_resources().is_empty());
+
+        registry.register_metadata(&OTHER_METADATA);
+        let duplicates = registry.duplicate_resources();
+        assert_eq!(
+            duplicates,
+            vec![("String", vec!["MockPlugin", "OtherMockPlugin"])]
+        );
+    }
+
+    #[test]
+    fn test_registry_totals() {
+        static TYPES: [TypeInfo; 2] = [TypeInfo::new::<i32>("i32"), TypeInfo::new::<u32>("u32")];
+        static FIRST_METADATA: PluginMetadata = PluginMetadata {
+            resources: &MOCK_RESOURCES,
+            messages: &TYPES,
+            states: &TYPES,
+            reflected_types: &TYPES,
+            ..PluginMetadata::empty("FirstPlugin")
+        };
+        static SECOND_METADATA: PluginMetadata = PluginMetadata {
+            resources: &TYPES,
+            messages: &MOCK_RESOURCES,
+            reflected_types: &MOCK_RESOURCES,
+            ..PluginMetadata::empty("SecondPlugin")
+        };
+
+        let mut registry = PluginRegistry::new();
+        registry.register_metadata(&FIRST_METADATA);
+        registry.register_metadata(&SECOND_METADATA);
+
+        assert_eq!(registry.total_resources(), 3);
+        assert_eq!(registry.total_messages(), 3);
+        assert_eq!(registry.total_states(), 2);
+        assert_eq!(registry.total_reflected_types(), 3);
+    }
+
+    #[test]
+    fn test_registry_load_order() {
+        static SECOND_METADATA: PluginMetadata = PluginMetadata::empty("SecondPlugin");
+
+        let mut registry = PluginRegistry::new();
+        registry.register::<MockPlugin>();
+        registry.register_metadata(&SECOND_METADATA);
+
+        let order: Vec<_> = registry
+            .enumerate_load_order()
+            .map(|(index, meta)| (index, meta.name))
+            .collect();
+        assert_eq!(order, vec![(0, "MockPlugin"), (1, "SecondPlugin")]);
+
+        assert_eq!(registry.load_position("MockPlugin"), Some(0));
+        assert_eq!(registry.load_position("SecondPlugin"), Some(1));
+        assert_eq!(registry.load_position("NonExistent"), None);
+    }
+
+    #[test]
+    fn test_registry_unsatisfied_dependencies() {
+        static DEPENDENT_METADATA: PluginMetadata = PluginMetadata {
+            dependencies: &["MockPlugin", "MissingPlugin"],
+            ..PluginMetadata::empty("DependentPlugin")
+        };
+
+        let mut registry = PluginRegistry::new();
+        registry.register::<MockPlugin>();
+        assert!(registry.unsatisfied_dependencies().is_empty());
+
+        registry.register_metadata(&DEPENDENT_METADATA);
+        assert_eq!(
+            registry.unsatisfied_dependencies(),
+            vec![("DependentPlugin", "MissingPlugin")]
+        );
+    }
+
+    #[test]
+    fn test_registry_roots_and_leaves() {
+        static CORE_METADATA: PluginMetadata = PluginMetadata::empty("CorePlugin");
+        static PHYSICS_METADATA: PluginMetadata = PluginMetadata {
+            dependencies: &["CorePlugin"],
+            ..PluginMetadata::empty("PhysicsPlugin")
+        };
+        static GAMEPLAY_METADATA: PluginMetadata = PluginMetadata {
+            dependencies: &["PhysicsPlugin"],
+            ..PluginMetadata::empty("GameplayPlugin")
+        };
+
+        let mut registry = PluginRegistry::new();
+        assert!(registry.roots().is_empty());
+        assert!(registry.leaves().is_empty());
+
+        registry.register_metadata(&CORE_METADATA);
+        registry.register_metadata(&PHYSICS_METADATA);
+        registry.register_metadata(&GAMEPLAY_METADATA);
+        registry.register::<MockPlugin>();
+
+        // A plugin without dependencies that nothing depends on is both
+        assert_eq!(registry.roots(), vec!["CorePlugin", "MockPlugin"]);
+        assert_eq!(registry.leaves(), vec!["GameplayPlugin", "MockPlugin"]);
+    }
+
     #[test]
     fn test_registry_duplicate_registration() {
         let mut registry = PluginRegistry::new();
@@ -0,0 +1,57 @@
+//! Reflectable snapshot of the plugin registry.
+//!
+//! Plugins declared with `generate_reflect_report: true` register themselves
+//! in the `PluginRegistry` resource and fill a `ReflectablePluginReport` in
+//! `finish()`. The report derives `Reflect`, so remote tools such as Bevy
+//! Remote Protocol clients can query the loaded plugins. Enabled by the
+//! `reflect` feature.
+
+use crate::metadata::{OwnedPluginMetadata, PluginInfo, PluginMetadata};
+use crate::registry::PluginRegistry;
+use bevy::prelude::*;
+
+/// Owned metadata of every plugin in the `PluginRegistry`.
+///
+/// Rebuilt in `finish()` by every plugin with `generate_reflect_report: true`,
+/// after all plugins have been built.
+#[derive(Resource, Reflect, Debug, Clone, Default, PartialEq, Eq)]
+#[reflect(Resource, Default)]
+pub struct ReflectablePluginReport {
+    /// One snapshot per registered plugin, in registration order
+    pub plugins: Vec<OwnedPluginMetadata>,
+}
+
+impl ReflectablePluginReport {
+    /// Snapshot every plugin in `registry`
+    pub fn from_registry(registry: &PluginRegistry) -> Self {
+        Self {
+            plugins: registry
+                .list_all()
+                .map(PluginMetadata::to_owned_snapshot)
+                .collect(),
+        }
+    }
+}
+
+/// Register plugin `P` and the report type.
+///
+/// Called from the generated `build()` for `generate_reflect_report: true`.
+pub fn enable_reflect_report<P: PluginInfo + 'static>(app: &mut App) {
+    app.init_resource::<PluginRegistry>();
+    app.world_mut()
+        .resource_mut::<PluginRegistry>()
+        .register::<P>();
+    app.register_type::<ReflectablePluginReport>();
+}
+
+/// Replace the `ReflectablePluginReport` with a snapshot of the registry.
+///
+/// Called from the generated `finish()` for `generate_reflect_report: true`.
+pub fn update_reflect_report(app: &mut App) {
+    let report = app
+        .world()
+        .get_resource::<PluginRegistry>()
+        .map(ReflectablePluginReport::from_registry)
+        .unwrap_or_default();
+    app.insert_resource(report);
+}
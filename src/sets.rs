@@ -0,0 +1,74 @@
+//! System sets generated for plugins.
+//!
+//! Every plugin created with `define_plugin!` places its `Update` and
+//! `FixedUpdate` systems in a `PluginSystemSet` keyed by the plugin type, so
+//! plugin-wide ordering can be configured on the set.
+
+use bevy::ecs::schedule::SystemSet;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+/// System set containing a plugin's `Update` and `FixedUpdate` systems.
+///
+/// The `update_before:`/`update_after:` keys configure this set. It can also
+/// be used directly to order systems against another plugin:
+///
+/// ```rust
+/// use bevy_plugin_builder::{define_plugin, PluginSystemSet};
+/// use bevy::prelude::*;
+///
+/// fn read_input() {}
+/// fn apply_input() {}
+///
+/// define_plugin!(InputPlugin {
+///     add_systems_update: [read_input]
+/// });
+///
+/// let mut app = App::new();
+/// app.add_plugins(InputPlugin);
+/// app.add_systems(Update, apply_input.after(PluginSystemSet::<InputPlugin>::new()));
+/// ```
+#[derive(SystemSet)]
+pub struct PluginSystemSet<P: 'static>(PhantomData<fn() -> P>);
+
+impl<P: 'static> PluginSystemSet<P> {
+    /// Create the system set for plugin `P`
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<P: 'static> Default for PluginSystemSet<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Manual impls so plugin types don't need to derive these traits themselves
+
+impl<P: 'static> Clone for PluginSystemSet<P> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P: 'static> Copy for PluginSystemSet<P> {}
+
+impl<P: 'static> PartialEq for PluginSystemSet<P> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<P: 'static> Eq for PluginSystemSet<P> {}
+
+impl<P: 'static> Hash for PluginSystemSet<P> {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+impl<P: 'static> fmt::Debug for PluginSystemSet<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "PluginSystemSet<{}>", std::any::type_name::<P>())
+    }
+}
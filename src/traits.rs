@@ -3,7 +3,13 @@
 //! This module provides the trait hierarchy that enables compile-time
 //! and runtime validation of plugin dependencies.
 
-use bevy::prelude::{App, Plugin};
+use bevy::ecs::schedule::ScheduleConfigs;
+use bevy::ecs::system::ScheduleSystem;
+use bevy::ecs::world::FromWorld;
+use bevy::prelude::{App, AppExtStates, Component, IntoScheduleConfigs, Plugin, SubStates};
+use bevy::reflect::GetTypeRegistration;
+use bevy::state::state::FreelyMutableState;
+use std::any::TypeId;
 
 /// Error returned when a required plugin is missing.
 #[derive(Debug, Clone)]
@@ -27,6 +33,40 @@ impl std::fmt::Display for MissingPluginError {
 
 impl std::error::Error for MissingPluginError {}
 
+/// Check whether a plugin of type `P` has been added to the App.
+///
+/// Matches by type rather than by `Plugin::name()`, so plugins that override
+/// `name()` are still found (`App::is_plugin_added` compares names against
+/// the full type path).
+pub(crate) fn is_plugin_added<P: Plugin>(app: &App) -> bool {
+    !app.get_added_plugins::<P>().is_empty()
+}
+
+impl MissingPluginError {
+    /// Format a list of missing plugins for the same dependent plugin as one message.
+    ///
+    /// A single error is formatted the same way as its `Display` output.
+    pub fn combined_message(errors: &[MissingPluginError]) -> String {
+        match errors {
+            [] => String::new(),
+            [single] => single.to_string(),
+            [first, ..] => {
+                let missing: Vec<String> = errors
+                    .iter()
+                    .map(|err| format!("'{}'", err.missing))
+                    .collect();
+                format!(
+                    "Plugin '{}' requires {} to be added first. \
+                     Add them before '{}' in your app.add_plugins() call.",
+                    first.required_by,
+                    missing.join(", "),
+                    first.required_by
+                )
+            }
+        }
+    }
+}
+
 /// Marker trait for plugins created with `define_plugin!`.
 ///
 /// This trait is automatically implemented by the macro and enables
@@ -40,6 +80,152 @@ pub trait PluginMarker: Plugin + 'static {
     type Id;
 }
 
+/// Fails to compile unless `P` implements `PluginMarker`.
+///
+/// `define_plugin!` calls this once per `depends_on` entry, so listing a type
+/// that is not a `define_plugin!` plugin reports the missing `PluginMarker`
+/// impl for that exact type.
+pub const fn assert_plugin_marker<P: PluginMarker>() {}
+
+/// Runs `P`'s dependency check from the generated `build()`.
+///
+/// Called as `(&DependencyCheck::<P>(PhantomData)).verify_declared(app)`. When a
+/// `depends_on` entry isn't a plugin, `P`'s `PluginDependencies` impl doesn't
+/// hold and method lookup falls back to `SkipDependencyCheck`, so the only
+/// error is the one from `assert_plugin_marker`.
+pub struct DependencyCheck<P>(pub std::marker::PhantomData<P>);
+
+/// Verifies the dependencies of a plugin whose `PluginDependencies` impl holds.
+pub trait VerifyDependencyCheck {
+    /// Verify every dependency, like `PluginDependencies::verify_all_dependencies`
+    fn verify_declared(&self, app: &App) -> Result<(), Vec<MissingPluginError>>;
+}
+
+impl<P: PluginDependencies> VerifyDependencyCheck for DependencyCheck<P> {
+    fn verify_declared(&self, app: &App) -> Result<(), Vec<MissingPluginError>> {
+        P::verify_all_dependencies(app)
+    }
+}
+
+/// Fallback of `VerifyDependencyCheck`, only reached when compilation has
+/// already failed in `assert_plugin_marker`.
+pub trait SkipDependencyCheck {
+    /// Report no missing dependencies
+    fn verify_declared(&self, _app: &App) -> Result<(), Vec<MissingPluginError>> {
+        Ok(())
+    }
+}
+
+impl<P> SkipDependencyCheck for &DependencyCheck<P> {}
+
+/// Registers an `init_resource` type for reflection under
+/// `auto_reflect_resources: true`.
+///
+/// Called as `(&AutoReflect::<R>(PhantomData)).register_resource_type(app)`.
+/// Method lookup only falls back to `SkipReflectedResource` when `R` does not
+/// implement `Reflect`, so plugins without the flag may still list such types
+/// under `init_resource`.
+pub struct AutoReflect<R>(pub std::marker::PhantomData<R>);
+
+/// Registers a resource type that implements `Reflect`.
+pub trait RegisterReflectedResource {
+    /// Register the resource type with `App::register_type`
+    fn register_resource_type(&self, app: &mut App);
+}
+
+impl<R: GetTypeRegistration> RegisterReflectedResource for AutoReflect<R> {
+    fn register_resource_type(&self, app: &mut App) {
+        app.register_type::<R>();
+    }
+}
+
+/// Fallback of `RegisterReflectedResource` for a resource type that does not
+/// implement `Reflect`.
+pub trait SkipReflectedResource {
+    /// Panic, naming the resource type
+    fn register_resource_type(&self, app: &mut App);
+}
+
+impl<R> SkipReflectedResource for &AutoReflect<R> {
+    fn register_resource_type(&self, _app: &mut App) {
+        panic!(
+            "auto_reflect_resources: `{}` does not implement Reflect; derive it or list the resource without auto_reflect_resources",
+            std::any::type_name::<R>()
+        );
+    }
+}
+
+/// A type that `init_state` can initialize.
+///
+/// `define_plugin!` initializes each `init_state` entry through this trait, so
+/// listing a type that isn't a state reports one error at that entry instead
+/// of a bound deep inside `App::init_state`.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can not be used as a state",
+    label = "invalid state",
+    note = "consider annotating `{Self}` with `#[derive(States)]`"
+)]
+pub trait InitStateEntry {
+    /// Initialize the state in `app`
+    fn init_state(app: &mut App);
+}
+
+impl<S: FreelyMutableState + FromWorld> InitStateEntry for S {
+    fn init_state(app: &mut App) {
+        app.init_state::<S>();
+    }
+}
+
+/// A type that `add_sub_state` can add, like `InitStateEntry`.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can not be used as a sub-state",
+    label = "invalid sub-state",
+    note = "consider annotating `{Self}` with `#[derive(SubStates)]`"
+)]
+pub trait SubStateEntry {
+    /// Add the sub-state to `app`
+    fn add_sub_state(app: &mut App);
+}
+
+impl<S: SubStates> SubStateEntry for S {
+    fn add_sub_state(app: &mut App) {
+        app.add_sub_state::<S>();
+    }
+}
+
+/// Fails to compile unless `C` implements `Component`.
+///
+/// Called once per `register_components` entry, like `assert_plugin_marker` for
+/// `depends_on`.
+pub const fn assert_component<C: Component>() {}
+
+/// Implemented for every value `App::add_systems` accepts: system functions,
+/// closures, and configured systems such as `my_system.run_if(condition)`.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a system",
+    label = "not a system",
+    note = "system lists take functions whose parameters are all system params, or configured systems such as `my_system.run_if(condition)`"
+)]
+pub trait PluginSystem<M> {
+    /// Convert the system into the configs `App::add_systems` takes
+    fn into_plugin_configs(self) -> ScheduleConfigs<ScheduleSystem>;
+}
+
+impl<M, T: IntoScheduleConfigs<ScheduleSystem, M>> PluginSystem<M> for T {
+    fn into_plugin_configs(self) -> ScheduleConfigs<ScheduleSystem> {
+        self.into_configs()
+    }
+}
+
+/// Converts one entry of a system list into its schedule configs.
+///
+/// Every entry goes through this on its own, so a value that isn't a system
+/// is reported at that entry, and the tuple of the list's entries stays a
+/// valid system tuple instead of failing a second time.
+pub fn plugin_system<M>(system: impl PluginSystem<M>) -> ScheduleConfigs<ScheduleSystem> {
+    system.into_plugin_configs()
+}
+
 /// Trait for tuples of plugin markers, enabling dependency verification.
 ///
 /// This is implemented for tuples of increasing sizes (up to 12 elements)
@@ -51,6 +237,21 @@ pub trait PluginSet {
     /// first missing plugin.
     fn verify_registered(app: &App, required_by: &'static str) -> Result<(), MissingPluginError>;
 
+    /// Verify that all plugins in this set are registered in the App.
+    ///
+    /// Unlike `verify_registered`, this checks every plugin in the set and
+    /// returns all missing plugins together instead of stopping at the first.
+    ///
+    /// The default reports only the first missing plugin, from
+    /// `verify_registered`, because Bevy can only look up added plugins by
+    /// type. The tuple impls override it to report every one.
+    fn verify_registered_all(
+        app: &App,
+        required_by: &'static str,
+    ) -> Result<(), Vec<MissingPluginError>> {
+        Self::verify_registered(app, required_by).map_err(|error| vec![error])
+    }
+
     /// Get the type names of all plugins in this set for error messages.
     fn type_names() -> Vec<&'static str>;
 }
@@ -64,6 +265,13 @@ pub trait PluginDependencies: Plugin {
     /// Empty tuple `()` means no dependencies.
     type Required: PluginSet;
 
+    /// `TypeId` getters for the required plugins, in declaration order.
+    ///
+    /// Bevy only checks for added plugins by type parameter, so the runtime
+    /// check still goes through `Required`; this array is for tooling that
+    /// needs the dependencies as plain data.
+    const DEPENDENCY_TYPE_IDS: &'static [fn() -> TypeId] = &[];
+
     /// Verify all dependencies are satisfied.
     ///
     /// This is called at runtime during `build()` to ensure plugins
@@ -74,6 +282,77 @@ pub trait PluginDependencies: Plugin {
     {
         Self::Required::verify_registered(app, std::any::type_name::<Self>())
     }
+
+    /// Verify all dependencies are satisfied, collecting every missing plugin.
+    ///
+    /// Used by the generated `build()` so a single panic lists all
+    /// dependencies that still need to be added.
+    fn verify_all_dependencies(app: &App) -> Result<(), Vec<MissingPluginError>>
+    where
+        Self: Sized,
+    {
+        Self::Required::verify_registered_all(app, std::any::type_name::<Self>())
+    }
+}
+
+/// Extension trait for adding plugins with dependency checks up front.
+///
+/// `app.add_plugins(plugin)` panics inside `build()` when a dependency is
+/// missing; `add_plugin_checked` returns the error instead.
+///
+/// ```rust,ignore
+/// use bevy_plugin_builder::AppPluginExt;
+///
+/// if let Err(err) = app.add_plugin_checked(GamePlugin) {
+///     warn!("Skipping GamePlugin: {err}");
+/// }
+/// ```
+pub trait AppPluginExt {
+    /// Add `plugin` if all of its declared dependencies were added first.
+    ///
+    /// Returns the first missing dependency otherwise, and the plugin is
+    /// not added.
+    fn add_plugin_checked<P: PluginDependencies>(
+        &mut self,
+        plugin: P,
+    ) -> Result<&mut Self, MissingPluginError>;
+}
+
+impl AppPluginExt for App {
+    fn add_plugin_checked<P: PluginDependencies>(
+        &mut self,
+        plugin: P,
+    ) -> Result<&mut Self, MissingPluginError> {
+        P::verify_dependencies(self)?;
+        Ok(self.add_plugins(plugin))
+    }
+}
+
+/// Builds the instance of a plugin that the generated `test_app_boots` test
+/// adds: unit plugins by path, plugins with `fields:` through `Default`.
+///
+/// Implemented by `define_plugin!`; a plugin with fields and no `Default`
+/// impl doesn't implement it, and only the tests that need it fail.
+#[cfg(feature = "testing")]
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` has fields, so `test_app_boots` can't build it",
+    label = "no `Default` impl to build it from",
+    note = "derive or implement `Default` for `{Self}` so the test app can add it"
+)]
+pub trait BootTestInstance: Plugin + Sized {
+    /// The plugin instance added to the test app.
+    fn boot_test_instance() -> Self;
+}
+
+/// Adds a plugin to the `test_app_boots` test app after its declared
+/// dependencies, and theirs, each added once.
+///
+/// Implemented by `define_plugin!` whenever the plugin and every plugin it
+/// depends on implement `BootTestInstance`.
+#[cfg(feature = "testing")]
+pub trait BootTestPlugin: Plugin {
+    /// Add the dependencies that are missing, then the plugin itself.
+    fn add_for_boot_test(app: &mut App);
 }
 
 // ============================================================================
@@ -86,6 +365,13 @@ impl PluginSet for () {
         Ok(())
     }
 
+    fn verify_registered_all(
+        _app: &App,
+        _required_by: &'static str,
+    ) -> Result<(), Vec<MissingPluginError>> {
+        Ok(())
+    }
+
     fn type_names() -> Vec<&'static str> {
         Vec::new()
     }
@@ -97,7 +383,7 @@ where
     P1: PluginMarker,
 {
     fn verify_registered(app: &App, required_by: &'static str) -> Result<(), MissingPluginError> {
-        if !app.is_plugin_added::<P1>() {
+        if !is_plugin_added::<P1>(app) {
             return Err(MissingPluginError {
                 required_by,
                 missing: std::any::type_name::<P1>(),
@@ -106,6 +392,24 @@ where
         Ok(())
     }
 
+    fn verify_registered_all(
+        app: &App,
+        required_by: &'static str,
+    ) -> Result<(), Vec<MissingPluginError>> {
+        let mut missing = Vec::new();
+        if !is_plugin_added::<P1>(app) {
+            missing.push(MissingPluginError {
+                required_by,
+                missing: std::any::type_name::<P1>(),
+            });
+        }
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(missing)
+        }
+    }
+
     fn type_names() -> Vec<&'static str> {
         vec![std::any::type_name::<P1>()]
     }
@@ -118,13 +422,13 @@ where
     P2: PluginMarker,
 {
     fn verify_registered(app: &App, required_by: &'static str) -> Result<(), MissingPluginError> {
-        if !app.is_plugin_added::<P1>() {
+        if !is_plugin_added::<P1>(app) {
             return Err(MissingPluginError {
                 required_by,
                 missing: std::any::type_name::<P1>(),
             });
         }
-        if !app.is_plugin_added::<P2>() {
+        if !is_plugin_added::<P2>(app) {
             return Err(MissingPluginError {
                 required_by,
                 missing: std::any::type_name::<P2>(),
@@ -133,6 +437,30 @@ where
         Ok(())
     }
 
+    fn verify_registered_all(
+        app: &App,
+        required_by: &'static str,
+    ) -> Result<(), Vec<MissingPluginError>> {
+        let mut missing = Vec::new();
+        if !is_plugin_added::<P1>(app) {
+            missing.push(MissingPluginError {
+                required_by,
+                missing: std::any::type_name::<P1>(),
+            });
+        }
+        if !is_plugin_added::<P2>(app) {
+            missing.push(MissingPluginError {
+                required_by,
+                missing: std::any::type_name::<P2>(),
+            });
+        }
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(missing)
+        }
+    }
+
     fn type_names() -> Vec<&'static str> {
         vec![std::any::type_name::<P1>(), std::any::type_name::<P2>()]
     }
@@ -146,19 +474,19 @@ where
     P3: PluginMarker,
 {
     fn verify_registered(app: &App, required_by: &'static str) -> Result<(), MissingPluginError> {
-        if !app.is_plugin_added::<P1>() {
+        if !is_plugin_added::<P1>(app) {
             return Err(MissingPluginError {
                 required_by,
                 missing: std::any::type_name::<P1>(),
             });
         }
-        if !app.is_plugin_added::<P2>() {
+        if !is_plugin_added::<P2>(app) {
             return Err(MissingPluginError {
                 required_by,
                 missing: std::any::type_name::<P2>(),
             });
         }
-        if !app.is_plugin_added::<P3>() {
+        if !is_plugin_added::<P3>(app) {
             return Err(MissingPluginError {
                 required_by,
                 missing: std::any::type_name::<P3>(),
@@ -167,6 +495,36 @@ where
         Ok(())
     }
 
+    fn verify_registered_all(
+        app: &App,
+        required_by: &'static str,
+    ) -> Result<(), Vec<MissingPluginError>> {
+        let mut missing = Vec::new();
+        if !is_plugin_added::<P1>(app) {
+            missing.push(MissingPluginError {
+                required_by,
+                missing: std::any::type_name::<P1>(),
+            });
+        }
+        if !is_plugin_added::<P2>(app) {
+            missing.push(MissingPluginError {
+                required_by,
+                missing: std::any::type_name::<P2>(),
+            });
+        }
+        if !is_plugin_added::<P3>(app) {
+            missing.push(MissingPluginError {
+                required_by,
+                missing: std::any::type_name::<P3>(),
+            });
+        }
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(missing)
+        }
+    }
+
     fn type_names() -> Vec<&'static str> {
         vec![
             std::any::type_name::<P1>(),
@@ -185,25 +543,25 @@ where
     P4: PluginMarker,
 {
     fn verify_registered(app: &App, required_by: &'static str) -> Result<(), MissingPluginError> {
-        if !app.is_plugin_added::<P1>() {
+        if !is_plugin_added::<P1>(app) {
             return Err(MissingPluginError {
                 required_by,
                 missing: std::any::type_name::<P1>(),
             });
         }
-        if !app.is_plugin_added::<P2>() {
+        if !is_plugin_added::<P2>(app) {
             return Err(MissingPluginError {
                 required_by,
                 missing: std::any::type_name::<P2>(),
             });
         }
-        if !app.is_plugin_added::<P3>() {
+        if !is_plugin_added::<P3>(app) {
             return Err(MissingPluginError {
                 required_by,
                 missing: std::any::type_name::<P3>(),
             });
         }
-        if !app.is_plugin_added::<P4>() {
+        if !is_plugin_added::<P4>(app) {
             return Err(MissingPluginError {
                 required_by,
                 missing: std::any::type_name::<P4>(),
@@ -212,6 +570,42 @@ where
         Ok(())
     }
 
+    fn verify_registered_all(
+        app: &App,
+        required_by: &'static str,
+    ) -> Result<(), Vec<MissingPluginError>> {
+        let mut missing = Vec::new();
+        if !is_plugin_added::<P1>(app) {
+            missing.push(MissingPluginError {
+                required_by,
+                missing: std::any::type_name::<P1>(),
+            });
+        }
+        if !is_plugin_added::<P2>(app) {
+            missing.push(MissingPluginError {
+                required_by,
+                missing: std::any::type_name::<P2>(),
+            });
+        }
+        if !is_plugin_added::<P3>(app) {
+            missing.push(MissingPluginError {
+                required_by,
+                missing: std::any::type_name::<P3>(),
+            });
+        }
+        if !is_plugin_added::<P4>(app) {
+            missing.push(MissingPluginError {
+                required_by,
+                missing: std::any::type_name::<P4>(),
+            });
+        }
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(missing)
+        }
+    }
+
     fn type_names() -> Vec<&'static str> {
         vec![
             std::any::type_name::<P1>(),
@@ -232,31 +626,31 @@ where
     P5: PluginMarker,
 {
     fn verify_registered(app: &App, required_by: &'static str) -> Result<(), MissingPluginError> {
-        if !app.is_plugin_added::<P1>() {
+        if !is_plugin_added::<P1>(app) {
             return Err(MissingPluginError {
                 required_by,
                 missing: std::any::type_name::<P1>(),
             });
         }
-        if !app.is_plugin_added::<P2>() {
+        if !is_plugin_added::<P2>(app) {
             return Err(MissingPluginError {
                 required_by,
                 missing: std::any::type_name::<P2>(),
             });
         }
-        if !app.is_plugin_added::<P3>() {
+        if !is_plugin_added::<P3>(app) {
             return Err(MissingPluginError {
                 required_by,
                 missing: std::any::type_name::<P3>(),
             });
         }
-        if !app.is_plugin_added::<P4>() {
+        if !is_plugin_added::<P4>(app) {
             return Err(MissingPluginError {
                 required_by,
                 missing: std::any::type_name::<P4>(),
             });
         }
-        if !app.is_plugin_added::<P5>() {
+        if !is_plugin_added::<P5>(app) {
             return Err(MissingPluginError {
                 required_by,
                 missing: std::any::type_name::<P5>(),
@@ -265,6 +659,48 @@ where
         Ok(())
     }
 
+    fn verify_registered_all(
+        app: &App,
+        required_by: &'static str,
+    ) -> Result<(), Vec<MissingPluginError>> {
+        let mut missing = Vec::new();
+        if !is_plugin_added::<P1>(app) {
+            missing.push(MissingPluginError {
+                required_by,
+                missing: std::any::type_name::<P1>(),
+            });
+        }
+        if !is_plugin_added::<P2>(app) {
+            missing.push(MissingPluginError {
+                required_by,
+                missing: std::any::type_name::<P2>(),
+            });
+        }
+        if !is_plugin_added::<P3>(app) {
+            missing.push(MissingPluginError {
+                required_by,
+                missing: std::any::type_name::<P3>(),
+            });
+        }
+        if !is_plugin_added::<P4>(app) {
+            missing.push(MissingPluginError {
+                required_by,
+                missing: std::any::type_name::<P4>(),
+            });
+        }
+        if !is_plugin_added::<P5>(app) {
+            missing.push(MissingPluginError {
+                required_by,
+                missing: std::any::type_name::<P5>(),
+            });
+        }
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(missing)
+        }
+    }
+
     fn type_names() -> Vec<&'static str> {
         vec![
             std::any::type_name::<P1>(),
@@ -287,37 +723,37 @@ where
     P6: PluginMarker,
 {
     fn verify_registered(app: &App, required_by: &'static str) -> Result<(), MissingPluginError> {
-        if !app.is_plugin_added::<P1>() {
+        if !is_plugin_added::<P1>(app) {
             return Err(MissingPluginError {
                 required_by,
                 missing: std::any::type_name::<P1>(),
             });
         }
-        if !app.is_plugin_added::<P2>() {
+        if !is_plugin_added::<P2>(app) {
             return Err(MissingPluginError {
                 required_by,
                 missing: std::any::type_name::<P2>(),
             });
         }
-        if !app.is_plugin_added::<P3>() {
+        if !is_plugin_added::<P3>(app) {
             return Err(MissingPluginError {
                 required_by,
                 missing: std::any::type_name::<P3>(),
             });
         }
-        if !app.is_plugin_added::<P4>() {
+        if !is_plugin_added::<P4>(app) {
             return Err(MissingPluginError {
                 required_by,
                 missing: std::any::type_name::<P4>(),
             });
         }
-        if !app.is_plugin_added::<P5>() {
+        if !is_plugin_added::<P5>(app) {
             return Err(MissingPluginError {
                 required_by,
                 missing: std::any::type_name::<P5>(),
             });
         }
-        if !app.is_plugin_added::<P6>() {
+        if !is_plugin_added::<P6>(app) {
             return Err(MissingPluginError {
                 required_by,
                 missing: std::any::type_name::<P6>(),
@@ -326,6 +762,54 @@ where
         Ok(())
     }
 
+    fn verify_registered_all(
+        app: &App,
+        required_by: &'static str,
+    ) -> Result<(), Vec<MissingPluginError>> {
+        let mut missing = Vec::new();
+        if !is_plugin_added::<P1>(app) {
+            missing.push(MissingPluginError {
+                required_by,
+                missing: std::any::type_name::<P1>(),
+            });
+        }
+        if !is_plugin_added::<P2>(app) {
+            missing.push(MissingPluginError {
+                required_by,
+                missing: std::any::type_name::<P2>(),
+            });
+        }
+        if !is_plugin_added::<P3>(app) {
+            missing.push(MissingPluginError {
+                required_by,
+                missing: std::any::type_name::<P3>(),
+            });
+        }
+        if !is_plugin_added::<P4>(app) {
+            missing.push(MissingPluginError {
+                required_by,
+                missing: std::any::type_name::<P4>(),
+            });
+        }
+        if !is_plugin_added::<P5>(app) {
+            missing.push(MissingPluginError {
+                required_by,
+                missing: std::any::type_name::<P5>(),
+            });
+        }
+        if !is_plugin_added::<P6>(app) {
+            missing.push(MissingPluginError {
+                required_by,
+                missing: std::any::type_name::<P6>(),
+            });
+        }
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(missing)
+        }
+    }
+
     fn type_names() -> Vec<&'static str> {
         vec![
             std::any::type_name::<P1>(),
@@ -357,11 +841,62 @@ mod tests {
         assert!(msg.contains("add_plugins()"));
     }
 
+    #[test]
+    fn test_combined_missing_plugin_message() {
+        let errors = [
+            MissingPluginError {
+                required_by: "GamePlugin",
+                missing: "PhysicsPlugin",
+            },
+            MissingPluginError {
+                required_by: "GamePlugin",
+                missing: "AudioPlugin",
+            },
+        ];
+        let msg = MissingPluginError::combined_message(&errors);
+        assert!(msg.contains("GamePlugin"));
+        assert!(msg.contains("PhysicsPlugin"));
+        assert!(msg.contains("AudioPlugin"));
+        assert!(msg.contains("requires"));
+
+        // A single error matches the Display output
+        let single = MissingPluginError::combined_message(&errors[..1]);
+        assert_eq!(single, errors[0].to_string());
+    }
+
     #[test]
     fn test_empty_plugin_set() {
         // Empty tuple should always succeed
         let app = App::new();
         assert!(<()>::verify_registered(&app, "TestPlugin").is_ok());
+        assert!(<()>::verify_registered_all(&app, "TestPlugin").is_ok());
         assert!(<()>::type_names().is_empty());
     }
+
+    #[test]
+    fn test_plugin_set_default_verify_registered_all() {
+        // A set written before verify_registered_all existed
+        struct AlwaysMissing;
+
+        impl PluginSet for AlwaysMissing {
+            fn verify_registered(
+                _app: &App,
+                required_by: &'static str,
+            ) -> Result<(), MissingPluginError> {
+                Err(MissingPluginError {
+                    required_by,
+                    missing: "PhysicsPlugin",
+                })
+            }
+
+            fn type_names() -> Vec<&'static str> {
+                vec!["PhysicsPlugin"]
+            }
+        }
+
+        let app = App::new();
+        let errors = AlwaysMissing::verify_registered_all(&app, "GamePlugin").unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].missing, "PhysicsPlugin");
+    }
 }
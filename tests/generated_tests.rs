@@ -0,0 +1,66 @@
+//! Generated tests for several plugins in one file.
+//!
+//! Each plugin's `generate_tests:` block emits its own named module, so the
+//! generated tests don't collide and report which plugin they belong to.
+
+#![cfg(feature = "testing")]
+
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+
+#[derive(Resource, Default)]
+struct Inventory;
+
+#[derive(Message)]
+struct ItemPickedUp;
+
+#[derive(Resource, Default)]
+struct CombatStats;
+
+#[derive(Message)]
+struct DamageDealt;
+
+define_plugin!(InventoryPlugin {
+    init_resource: [Inventory],
+    add_message: [ItemPickedUp],
+    generate_tests: {
+        module: inventory_plugin_tests,
+        test_resources: true,
+        test_messages: true
+    }
+});
+
+define_plugin!(CombatPlugin {
+    init_resource: [CombatStats],
+    add_message: [DamageDealt],
+    generate_tests: {
+        module: combat_plugin_tests,
+        test_resources: true,
+        test_messages: true
+    }
+});
+
+// Generated test_unique expects the duplicate plugin panic
+define_plugin!(SingletonPlugin {
+    generate_tests: {
+        module: singleton_plugin_tests,
+        test_unique: true
+    }
+});
+
+// With is_unique: false, test_unique expects both adds to succeed
+define_plugin!(RepeatablePlugin {
+    is_unique: false,
+    generate_tests: {
+        module: repeatable_plugin_tests,
+        test_unique: true
+    }
+});
+
+#[test]
+fn test_both_plugins_build_together() {
+    let mut app = App::new();
+    app.add_plugins((InventoryPlugin, CombatPlugin));
+    assert!(app.world().contains_resource::<Inventory>());
+    assert!(app.world().contains_resource::<CombatStats>());
+}
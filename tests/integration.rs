@@ -183,6 +183,74 @@ fn test_full_feature_plugin() {
     assert!(registry.contains(std::any::TypeId::of::<TestComponent>()));
 }
 
+// Test register_type with generic and nested generic types
+#[derive(Reflect, Default)]
+struct Wrapper<T>(T);
+
+#[derive(Reflect, Default)]
+struct Health(f32);
+
+define_plugin!(GenericReflectPlugin {
+    register_type: [
+        Wrapper<Health>,
+        Option<Health>,
+        Wrapper<Vec<Health>>,
+        Wrapper<Option<Wrapper<Health>>>
+    ]
+});
+
+#[test]
+fn test_register_generic_types() {
+    let mut app = App::new();
+    app.add_plugins(GenericReflectPlugin);
+
+    let registry = app.world().resource::<AppTypeRegistry>();
+    let registry = registry.read();
+    assert!(registry.contains(std::any::TypeId::of::<Wrapper<Health>>()));
+    assert!(registry.contains(std::any::TypeId::of::<Option<Health>>()));
+    assert!(registry.contains(std::any::TypeId::of::<Wrapper<Vec<Health>>>()));
+    assert!(registry.contains(std::any::TypeId::of::<Wrapper<Option<Wrapper<Health>>>>()));
+}
+
+// Test auto_reflect_resources registering init_resource types for reflection
+#[derive(Resource, Reflect, Default)]
+#[reflect(Resource)]
+struct CameraSettings {
+    zoom: f32,
+}
+
+define_plugin!(CameraSettingsPlugin {
+    init_resource: [CameraSettings],
+    auto_reflect_resources: true
+});
+
+#[test]
+fn test_auto_reflect_resources() {
+    let mut app = App::new();
+    app.add_plugins(CameraSettingsPlugin);
+
+    assert_eq!(app.world().resource::<CameraSettings>().zoom, 0.0);
+    let registry = app.world().resource::<AppTypeRegistry>();
+    let registry = registry.read();
+    assert!(registry.contains(std::any::TypeId::of::<CameraSettings>()));
+}
+
+#[derive(Resource, Default)]
+struct UnreflectedSettings;
+
+define_plugin!(UnreflectedSettingsPlugin {
+    init_resource: [UnreflectedSettings],
+    auto_reflect_resources: true
+});
+
+#[test]
+#[should_panic(
+    expected = "auto_reflect_resources: `integration::UnreflectedSettings` does not implement Reflect"
+)]
+fn test_auto_reflect_resources_without_reflect() {
+    App::new().add_plugins(UnreflectedSettingsPlugin);
+}
+
 #[test]
 fn test_system_execution() {
     let mut app = App::new();
@@ -268,6 +336,46 @@ fn test_nested_plugin_registration() {
     assert!(app.world().contains_resource::<NestedPluginResource>());
 }
 
+// Test wraps: a plugin built around a third-party style plugin
+#[derive(Resource)]
+struct MockGravity(f32);
+
+#[derive(Default)]
+struct MockPhysicsPlugin {
+    gravity: f32,
+}
+
+impl Plugin for MockPhysicsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(MockGravity(self.gravity));
+    }
+}
+
+define_plugin!(WrappedPhysicsPlugin wraps MockPhysicsPlugin::default() {
+    init_resource: [TestResource]
+});
+
+define_plugin!(WrappedMoonPhysicsPlugin wraps MockPhysicsPlugin { gravity: -1.6 } {
+    depends_on: [ParentWithNestedPlugin],
+    init_resource: [AnotherResource]
+});
+
+#[test]
+fn test_wraps_plugin() {
+    let mut app = App::new();
+    app.add_plugins(WrappedPhysicsPlugin);
+
+    assert!(app.is_plugin_added::<MockPhysicsPlugin>());
+    assert_eq!(app.world().resource::<MockGravity>().0, 0.0);
+    assert!(app.world().contains_resource::<TestResource>());
+
+    let mut app = App::new();
+    app.add_plugins((ParentWithNestedPlugin, WrappedMoonPhysicsPlugin));
+
+    assert_eq!(app.world().resource::<MockGravity>().0, -1.6);
+    assert!(app.world().contains_resource::<AnotherResource>());
+}
+
 #[test]
 fn test_sub_state_registration_and_execution() {
     let mut app = App::new();
@@ -291,6 +399,31 @@ fn test_sub_state_registration_and_execution() {
     assert_eq!(counter.0, 1);
 }
 
+// Test that add_sub_state is applied after init_state, even when listed first
+define_plugin!(ReversedSubStatePlugin {
+    add_sub_state: [ActiveSubState],
+    init_state: [RootFlowState]
+});
+
+#[test]
+fn test_sub_state_before_init_state_in_config() {
+    let mut app = App::new();
+    app.add_plugins(StatesPlugin)
+        .add_plugins(ReversedSubStatePlugin);
+    app.update();
+    assert!(!app.world().contains_resource::<State<ActiveSubState>>());
+
+    app.world_mut()
+        .resource_mut::<NextState<RootFlowState>>()
+        .set(RootFlowState::Active);
+    app.update();
+
+    assert_eq!(
+        *app.world().resource::<State<ActiveSubState>>().get(),
+        ActiveSubState::PhaseOne
+    );
+}
+
 #[test]
 fn test_custom_init_and_finish() {
     let mut app = App::new();
@@ -307,6 +440,151 @@ fn test_custom_init_and_finish() {
     // If we reach this point, the assertions in custom_finish passed
 }
 
+// Test remove_resource: dropping a build-time resource in finish()
+#[derive(Resource, Default)]
+struct LoadingState;
+
+define_plugin!(TemporaryResourcePlugin {
+    init_resource: [LoadingState, TestResource],
+    remove_resource: [LoadingState]
+});
+
+#[test]
+fn test_remove_resource_in_finish() {
+    let mut app = App::new();
+    app.add_plugins(TemporaryResourcePlugin);
+    assert!(app.world().contains_resource::<LoadingState>());
+
+    app.finish();
+    assert!(!app.world().contains_resource::<LoadingState>());
+    assert!(app.world().contains_resource::<TestResource>());
+}
+
+// Test assert_resources_present: checked in finish(), after every plugin is built
+#[derive(Resource, Default)]
+struct PlayerStats;
+
+define_plugin!(PlayerStatsPlugin {
+    init_resource: [PlayerStats]
+});
+
+define_plugin!(ScoreboardPlugin {
+    init_resource: [TestResource],
+    assert_resources_present: [TestResource, PlayerStats]
+});
+
+#[test]
+fn test_assert_resources_present_satisfied() {
+    let mut app = App::new();
+    // PlayerStatsPlugin is added after ScoreboardPlugin, but before finish()
+    app.add_plugins((ScoreboardPlugin, PlayerStatsPlugin));
+    app.finish();
+
+    assert!(app.world().contains_resource::<PlayerStats>());
+}
+
+#[test]
+#[should_panic(expected = "Plugin 'ScoreboardPlugin' requires the resource 'PlayerStats'")]
+fn test_assert_resources_present_missing_panics() {
+    let mut app = App::new();
+    app.add_plugins(ScoreboardPlugin);
+    app.finish();
+}
+
+// Test chain_startup: startup systems run in the order they are listed
+#[derive(Resource)]
+struct LoadedTextures(Vec<&'static str>);
+
+#[derive(Resource, Default)]
+struct SpawnedSprites(usize);
+
+fn load_textures(mut commands: Commands) {
+    commands.insert_resource(LoadedTextures(vec!["player", "enemy"]));
+}
+
+fn spawn_sprites(textures: Option<Res<LoadedTextures>>, mut sprites: ResMut<SpawnedSprites>) {
+    if let Some(textures) = textures {
+        sprites.0 = textures.0.len();
+    }
+}
+
+define_plugin!(ChainedSpritePlugin {
+    init_resource: [SpawnedSprites],
+    add_systems_startup: [load_textures, spawn_sprites],
+    chain_startup: true
+});
+
+define_plugin!(UnchainedSpritePlugin {
+    init_resource: [SpawnedSprites],
+    add_systems_startup: [load_textures, spawn_sprites]
+});
+
+#[test]
+fn test_chain_startup() {
+    // Chaining orders spawn_sprites after load_textures and applies its commands
+    let mut app = App::new();
+    app.add_plugins(ChainedSpritePlugin);
+    app.update();
+    assert_eq!(app.world().resource::<SpawnedSprites>().0, 2);
+
+    // Unchained, the inserted textures are only applied at the end of Startup
+    let mut app = App::new();
+    app.add_plugins(UnchainedSpritePlugin);
+    app.update();
+    assert_eq!(app.world().resource::<SpawnedSprites>().0, 0);
+}
+
+// Test finish_init_resource: deriving a resource from a dependency's in finish()
+#[derive(Resource)]
+struct TileSet {
+    tiles: Vec<&'static str>,
+}
+
+impl Default for TileSet {
+    fn default() -> Self {
+        Self {
+            tiles: vec!["grass", "water"],
+        }
+    }
+}
+
+#[derive(Resource)]
+struct TileAtlas {
+    slots: usize,
+}
+
+impl From<&TileSet> for TileAtlas {
+    fn from(tile_set: &TileSet) -> Self {
+        Self {
+            slots: tile_set.tiles.len(),
+        }
+    }
+}
+
+define_plugin!(TileSetPlugin {
+    init_resource: [TileSet]
+});
+
+define_plugin!(TileAtlasPlugin {
+    depends_on: [TileSetPlugin],
+    finish_init_resource: {
+        TileAtlas => |world| TileAtlas::from(world.resource::<TileSet>())
+    }
+});
+
+#[test]
+fn test_finish_init_resource() {
+    let mut app = App::new();
+    app.add_plugins((TileSetPlugin, TileAtlasPlugin));
+    assert!(!app.world().contains_resource::<TileAtlas>());
+
+    // Changes made after build() are seen, since the atlas is built in finish()
+    app.world_mut().resource_mut::<TileSet>().tiles.push("sand");
+    app.finish();
+
+    assert_eq!(app.world().resource::<TileAtlas>().slots, 3);
+}
+
 #[test]
 fn test_conditional_systems() {
     let mut app = App::new();
@@ -362,6 +640,43 @@ fn test_fixed_update_systems() {
     assert_eq!(counter.0, 0);
 }
 
+// Test systems in the StateTransition schedule
+#[derive(Resource, Default)]
+struct ObservedTransitions(Vec<(Option<TestState>, Option<TestState>)>);
+
+fn record_transition(
+    mut transitions: MessageReader<StateTransitionEvent<TestState>>,
+    mut observed: ResMut<ObservedTransitions>,
+) {
+    for transition in transitions.read() {
+        observed
+            .0
+            .push((transition.exited.clone(), transition.entered.clone()));
+    }
+}
+
+define_plugin!(TransitionObserverPlugin {
+    init_resource: [ObservedTransitions],
+    init_state: [TestState],
+    add_systems_state_transition: [record_transition]
+});
+
+#[test]
+fn test_state_transition_systems() {
+    let mut app = App::new();
+    app.add_plugins((StatesPlugin, TransitionObserverPlugin));
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<NextState<TestState>>()
+        .set(TestState::StateB);
+    app.update();
+    app.update();
+
+    let observed = &app.world().resource::<ObservedTransitions>().0;
+    assert!(observed.contains(&(Some(TestState::StateA), Some(TestState::StateB))));
+}
+
 // Test empty plugin
 define_plugin!(EmptyPlugin {});
 
@@ -374,6 +689,96 @@ fn test_empty_plugin() {
     app.update();
 }
 
+// Test several plugins declared in one define_plugins! call
+use bevy_plugin_builder::define_plugins;
+
+#[derive(Resource, Default)]
+struct InventorySlots;
+
+#[derive(Resource, Default)]
+struct CraftingQueue;
+
+define_plugins! {
+    InventoryPlugin {
+        init_resource: [InventorySlots]
+    }
+
+    #[derive(Debug)]
+    CraftingPlugin {
+        depends_on: [InventoryPlugin],
+        init_resource: [CraftingQueue]
+    }
+}
+
+#[test]
+fn test_define_plugins() {
+    let mut app = App::new();
+    app.add_plugins((InventoryPlugin, CraftingPlugin));
+    app.update();
+
+    assert!(app.world().contains_resource::<InventorySlots>());
+    assert!(app.world().contains_resource::<CraftingQueue>());
+    assert_eq!(format!("{:?}", CraftingPlugin), "CraftingPlugin");
+}
+
+// Test attributes before the plugin name landing on the generated struct
+define_plugin!(
+    /// Plugin struct with its own derives
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    DerivedPlugin {
+        init_resource: [AnotherResource]
+    }
+);
+
+define_plugin!(
+    #[derive(Debug, Default)]
+    DerivedWrapperPlugin wraps MockPhysicsPlugin::default() {}
+);
+
+fn default_plugin<P: Default>() -> P {
+    P::default()
+}
+
+#[test]
+fn test_plugin_struct_attributes() {
+    let plugin: DerivedPlugin = default_plugin();
+    assert_eq!(plugin, DerivedPlugin);
+    assert_eq!(format!("{:?}", plugin.clone()), "DerivedPlugin");
+
+    let mut app = App::new();
+    app.add_plugins(plugin);
+    assert!(app.world().contains_resource::<AnotherResource>());
+
+    app.add_plugins(default_plugin::<DerivedWrapperPlugin>());
+    assert!(app.is_plugin_added::<MockPhysicsPlugin>());
+}
+
+// Test is_unique: false allowing the plugin to be added more than once
+#[derive(Resource, Default)]
+struct RepeatBuilds(u32);
+
+define_plugin!(RepeatablePlugin {
+    is_unique: false,
+    custom_build: |app: &mut App| {
+        app.init_resource::<RepeatBuilds>();
+        app.world_mut().resource_mut::<RepeatBuilds>().0 += 1;
+    }
+});
+
+#[test]
+fn test_is_unique_false_added_twice() {
+    assert!(!Plugin::is_unique(&RepeatablePlugin));
+    assert!(Plugin::is_unique(&EmptyPlugin));
+
+    let mut app = App::new();
+    app.add_plugins(RepeatablePlugin);
+    app.add_plugins(RepeatablePlugin);
+
+    // Each added instance ran build()
+    assert_eq!(app.world().resource::<RepeatBuilds>().0, 2);
+    assert_eq!(app.get_added_plugins::<RepeatablePlugin>().len(), 2);
+}
+
 // ============================================================================
 // Dependency checking tests
 // ============================================================================
@@ -425,6 +830,21 @@ define_plugin!(UIPlugin {
     add_systems_update: [ui_system]
 });
 
+#[test]
+fn test_plugin_name_is_type_path() {
+    // Plugin::name() is not overridden with the short identifier, so Bevy's
+    // name-based lookups keep working
+    assert_eq!(
+        Plugin::name(&BasicTestPlugin),
+        std::any::type_name::<BasicTestPlugin>()
+    );
+    assert_eq!(Plugin::name(&UIPlugin), std::any::type_name::<UIPlugin>());
+
+    let mut app = App::new();
+    app.add_plugins(BasicTestPlugin);
+    assert!(app.is_plugin_added::<BasicTestPlugin>());
+}
+
 #[test]
 fn test_plugin_marker_trait() {
     // All plugins should implement PluginMarker
@@ -494,63 +914,225 @@ fn test_multiple_dependency_first_missing_panics() {
     app.add_plugins(UIPlugin); // This should panic because PhysicsPlugin is missing
 }
 
-#[test]
-fn test_dependency_type_checking() {
-    // Verify that PluginDependencies::Required has the correct type
-    type GameDeps = <GamePlugin as PluginDependencies>::Required;
-    type UIDeps = <UIPlugin as PluginDependencies>::Required;
-    type EmptyDeps = <EmptyPlugin as PluginDependencies>::Required;
-
-    // GamePlugin depends on one plugin
-    #[allow(dead_code)]
-    fn assert_single_dep<T: PluginMarker>(_: (T,)) {}
-    let _: GameDeps = (PhysicsPlugin,);
+// Plugin that needs one of Bevy's own plugins, which don't implement PluginMarker
+define_plugin!(TextureLoaderPlugin {
+    depends_on_bevy: [bevy::asset::AssetPlugin],
+    init_resource: [GameConfig]
+});
 
-    // UIPlugin depends on two plugins
-    #[allow(dead_code)]
-    fn assert_double_dep<T1: PluginMarker, T2: PluginMarker>(_: (T1, T2)) {}
-    let _: UIDeps = (PhysicsPlugin, AudioPlugin);
+#[test]
+fn test_depends_on_bevy_satisfied() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, bevy::asset::AssetPlugin::default()));
+    app.add_plugins(TextureLoaderPlugin);
 
-    // EmptyPlugin has no dependencies
-    #[allow(dead_code)]
-    fn assert_no_deps(_: ()) {}
-    let _: EmptyDeps = ();
+    assert!(app.world().contains_resource::<GameConfig>());
 }
 
-// ============================================================================
-// New Bevy-aligned syntax tests
-// ============================================================================
-
-#[derive(Resource, Default)]
-struct NewSyntaxResource;
-
-#[derive(Resource)]
-struct InsertedResource {
-    value: i32,
+#[test]
+#[should_panic(
+    expected = "Plugin 'TextureLoaderPlugin' requires the Bevy plugin 'AssetPlugin' to be added first"
+)]
+fn test_depends_on_bevy_missing_panics() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(TextureLoaderPlugin);
 }
 
-#[derive(Message)]
-struct NewSyntaxMessage;
-
-fn new_syntax_startup() {}
-fn new_syntax_update() {}
-
-// Test plugin using all new Bevy-aligned syntax
-define_plugin!(NewSyntaxPlugin {
-    init_resource: [NewSyntaxResource],
-    insert_resource: [InsertedResource { value: 42 }],
-    add_message: [NewSyntaxMessage],
-    add_systems_startup: [new_syntax_startup],
-    add_systems_update: [new_syntax_update]
+// Marker and Bevy dependencies together, with depends_on after other keys
+define_plugin!(PhysicsAssetsPlugin {
+    depends_on_bevy: [bevy::asset::AssetPlugin],
+    init_resource: [UIConfig],
+    depends_on: [PhysicsPlugin]
 });
 
 #[test]
-fn test_new_bevy_aligned_syntax() {
+fn test_mixed_dependencies_satisfied() {
     let mut app = App::new();
-    app.add_plugins(NewSyntaxPlugin);
+    app.add_plugins((MinimalPlugins, bevy::asset::AssetPlugin::default()));
+    app.add_plugins(PhysicsPlugin);
+    app.add_plugins(PhysicsAssetsPlugin);
 
-    // Verify init_resource worked
-    assert!(app.world().contains_resource::<NewSyntaxResource>());
+    assert!(app.world().contains_resource::<UIConfig>());
+}
+
+#[test]
+#[should_panic(expected = "PhysicsAssetsPlugin' requires 'integration::PhysicsPlugin'")]
+fn test_mixed_dependencies_missing_marker_panics() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, bevy::asset::AssetPlugin::default()));
+    app.add_plugins(PhysicsAssetsPlugin);
+}
+
+#[test]
+#[should_panic(expected = "Plugin 'PhysicsAssetsPlugin' requires the Bevy plugin 'AssetPlugin'")]
+fn test_mixed_dependencies_missing_bevy_panics() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PhysicsPlugin);
+    app.add_plugins(PhysicsAssetsPlugin);
+}
+
+// Plugin with three dependencies, used to check that all missing ones are reported
+define_plugin!(ThreeDependencyPlugin {
+    depends_on: [PhysicsPlugin, AudioPlugin, UIPlugin]
+});
+
+#[test]
+fn test_add_plugin_checked() {
+    use bevy_plugin_builder::AppPluginExt;
+
+    let mut app = App::new();
+    let err = app.add_plugin_checked(GamePlugin).unwrap_err();
+    assert!(err.missing.ends_with("PhysicsPlugin"));
+    assert!(err.required_by.ends_with("GamePlugin"));
+    // The plugin is not added when a dependency is missing
+    assert!(!app.world().contains_resource::<GameConfig>());
+
+    app.add_plugin_checked(PhysicsPlugin)
+        .unwrap()
+        .add_plugin_checked(GamePlugin)
+        .unwrap();
+    assert!(app.world().contains_resource::<GameConfig>());
+}
+
+#[test]
+fn test_all_missing_dependencies_reported() {
+    // None of the three dependencies are added, so all three should be listed
+    let result = std::panic::catch_unwind(|| {
+        let mut app = App::new();
+        app.add_plugins(ThreeDependencyPlugin);
+    });
+
+    let payload = result.expect_err("missing dependencies should panic");
+    let message = payload
+        .downcast_ref::<String>()
+        .expect("panic message should be a String");
+
+    assert!(message.contains("requires"));
+    assert!(message.contains("PhysicsPlugin"));
+    assert!(message.contains("AudioPlugin"));
+    assert!(message.contains("UIPlugin"));
+}
+
+// Plugin that only warns about its missing dependency
+#[derive(Resource, Default)]
+struct PrototypeConfig;
+
+define_plugin!(PrototypePlugin {
+    depends_on: [PhysicsPlugin],
+    dependency_check: warn,
+    init_resource: [PrototypeConfig]
+});
+
+#[test]
+fn test_dependency_check_warn() {
+    let mut app = App::new();
+    // PhysicsPlugin is missing, but build() continues
+    app.add_plugins(PrototypePlugin);
+    assert!(app.world().contains_resource::<PrototypeConfig>());
+    assert!(!app.world().contains_resource::<PhysicsConfig>());
+}
+
+// Plugin that only requires PhysicsPlugin when the `testing` feature is on
+define_plugin!(FeatureGatedDependencyPlugin {
+    #[cfg(feature = "testing")]
+    depends_on: [PhysicsPlugin],
+    init_resource: [GameConfig]
+});
+
+#[test]
+fn test_cfg_gated_dependency() {
+    let result = std::panic::catch_unwind(|| {
+        let mut app = App::new();
+        app.add_plugins(FeatureGatedDependencyPlugin);
+        assert!(app.world().contains_resource::<GameConfig>());
+    });
+
+    // The dependency is only enforced when the cfg predicate holds
+    assert_eq!(result.is_err(), cfg!(feature = "testing"));
+
+    let mut app = App::new();
+    app.add_plugins(PhysicsPlugin);
+    app.add_plugins(FeatureGatedDependencyPlugin);
+    assert!(app.world().contains_resource::<GameConfig>());
+}
+
+#[test]
+fn test_dependency_type_checking() {
+    // Verify that PluginDependencies::Required has the correct type
+    type GameDeps = <GamePlugin as PluginDependencies>::Required;
+    type UIDeps = <UIPlugin as PluginDependencies>::Required;
+    type EmptyDeps = <EmptyPlugin as PluginDependencies>::Required;
+
+    // GamePlugin depends on one plugin
+    #[allow(dead_code)]
+    fn assert_single_dep<T: PluginMarker>(_: (T,)) {}
+    let _: GameDeps = (PhysicsPlugin,);
+
+    // UIPlugin depends on two plugins
+    #[allow(dead_code)]
+    fn assert_double_dep<T1: PluginMarker, T2: PluginMarker>(_: (T1, T2)) {}
+    let _: UIDeps = (PhysicsPlugin, AudioPlugin);
+
+    // EmptyPlugin has no dependencies
+    #[allow(dead_code)]
+    fn assert_no_deps(_: ()) {}
+    let _: EmptyDeps = ();
+}
+
+#[test]
+fn test_dependency_type_ids() {
+    use std::any::TypeId;
+
+    let ids: Vec<TypeId> = UIPlugin::DEPENDENCY_TYPE_IDS
+        .iter()
+        .map(|type_id| type_id())
+        .collect();
+    assert_eq!(ids.len(), 2);
+    assert_eq!(
+        ids,
+        vec![TypeId::of::<PhysicsPlugin>(), TypeId::of::<AudioPlugin>()]
+    );
+
+    assert_eq!(GamePlugin::DEPENDENCY_TYPE_IDS.len(), 1);
+    assert!(EmptyPlugin::DEPENDENCY_TYPE_IDS.is_empty());
+}
+
+// ============================================================================
+// New Bevy-aligned syntax tests
+// ============================================================================
+
+#[derive(Resource, Default)]
+struct NewSyntaxResource;
+
+#[derive(Resource)]
+struct InsertedResource {
+    value: i32,
+}
+
+#[derive(Message)]
+struct NewSyntaxMessage;
+
+fn new_syntax_startup() {}
+fn new_syntax_update() {}
+
+// Test plugin using all new Bevy-aligned syntax
+define_plugin!(NewSyntaxPlugin {
+    init_resource: [NewSyntaxResource],
+    insert_resource: [InsertedResource { value: 42 }],
+    add_message: [NewSyntaxMessage],
+    add_systems_startup: [new_syntax_startup],
+    add_systems_update: [new_syntax_update]
+});
+
+#[test]
+fn test_new_bevy_aligned_syntax() {
+    let mut app = App::new();
+    app.add_plugins(NewSyntaxPlugin);
+
+    // Verify init_resource worked
+    assert!(app.world().contains_resource::<NewSyntaxResource>());
 
     // Verify insert_resource worked with the correct value
     assert!(app.world().contains_resource::<InsertedResource>());
@@ -563,6 +1145,75 @@ fn test_new_bevy_aligned_syntax() {
         .contains_resource::<Messages<NewSyntaxMessage>>());
 }
 
+// Test insert_resource_if_absent keeping the first inserted value
+define_plugin!(FirstInsertPlugin {
+    insert_resource_if_absent: {
+        InsertedResource => InsertedResource { value: 1 }
+    }
+});
+
+define_plugin!(SecondInsertPlugin {
+    insert_resource_if_absent: {
+        InsertedResource => InsertedResource { value: 2 }
+    }
+});
+
+#[test]
+fn test_insert_resource_if_absent() {
+    let mut app = App::new();
+    app.add_plugins(FirstInsertPlugin);
+    app.add_plugins(SecondInsertPlugin);
+
+    assert_eq!(app.world().resource::<InsertedResource>().value, 1);
+}
+
+// Test inspectable_resources initializing and reflecting a resource at once
+#[derive(Resource, Default, Reflect)]
+#[reflect(Resource)]
+struct InspectableSettings {
+    volume: f32,
+}
+
+define_plugin!(InspectablePlugin {
+    inspectable_resources: [InspectableSettings]
+});
+
+#[test]
+fn test_inspectable_resources() {
+    let mut app = App::new();
+    app.add_plugins(InspectablePlugin);
+
+    assert_eq!(app.world().resource::<InspectableSettings>().volume, 0.0);
+    let registry = app.world().resource::<AppTypeRegistry>().read();
+    let registration = registry
+        .get(std::any::TypeId::of::<InspectableSettings>())
+        .expect("InspectableSettings should be registered for reflection");
+    assert!(registration.data::<ReflectResource>().is_some());
+}
+
+// Test init_resource_with computing a resource from one inserted earlier
+#[derive(Resource)]
+struct DoubledResource {
+    value: i32,
+}
+
+define_plugin!(DerivedResourcePlugin {
+    insert_resource: [InsertedResource { value: 21 }],
+    init_resource_with: {
+        DoubledResource => |world| DoubledResource {
+            value: world.resource::<InsertedResource>().value * 2
+        }
+    }
+});
+
+#[test]
+fn test_init_resource_with() {
+    let mut app = App::new();
+    app.add_plugins(DerivedResourcePlugin);
+
+    assert_eq!(app.world().resource::<DoubledResource>().value, 42);
+}
+
 // Test plugin with meta block (currently just skipped, for future introspection)
 define_plugin!(MetaPlugin {
     meta: {
@@ -581,6 +1232,35 @@ fn test_meta_block_compiles() {
     assert!(app.world().contains_resource::<NewSyntaxResource>());
 }
 
+// Test plugin whose meta block also declares its settings resource
+#[derive(Resource)]
+struct AudioSettings {
+    volume: f32,
+}
+
+impl Default for AudioSettings {
+    fn default() -> Self {
+        Self { volume: 0.8 }
+    }
+}
+
+define_plugin!(SettingsMetaPlugin {
+    meta: {
+        version: "1.0.0",
+        default_settings: AudioSettings::default(),
+        description: "A test plugin with default settings"
+    }
+});
+
+#[test]
+fn test_meta_default_settings() {
+    let mut app = App::new();
+    app.add_plugins(SettingsMetaPlugin);
+
+    let settings = app.world().resource::<AudioSettings>();
+    assert_eq!(settings.volume, 0.8);
+}
+
 // Test plugin using new system scheduling syntax with states
 #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
 enum NewSyntaxState {
@@ -625,94 +1305,1852 @@ fn test_new_syntax_state_systems() {
     assert!(marker.0);
 }
 
-// Test custom_build (new name for custom_init)
-#[derive(Resource)]
-struct CustomBuildMarker;
+// Test that repeated on_enter entries for the same state merge
+#[derive(Resource, Default)]
+struct MergedEnterLog(Vec<&'static str>);
 
-define_plugin!(CustomBuildPlugin {
-    custom_build: |app: &mut App| {
-        app.insert_resource(CustomBuildMarker);
+fn first_enter(mut log: ResMut<MergedEnterLog>) {
+    log.0.push("first");
+}
+
+fn second_enter(mut log: ResMut<MergedEnterLog>) {
+    log.0.push("second");
+}
+
+fn third_enter(mut log: ResMut<MergedEnterLog>) {
+    log.0.push("third");
+}
+
+define_plugin!(MergedOnEnterPlugin {
+    init_resource: [MergedEnterLog],
+    init_state: [NewSyntaxState],
+    add_systems_on_enter: {
+        NewSyntaxState::Active => [first_enter],
+        NewSyntaxState::Active => [second_enter]
+    },
+    add_systems_on_enter: {
+        NewSyntaxState::Active => [third_enter]
     }
 });
 
 #[test]
-fn test_custom_build_syntax() {
+fn test_on_enter_entries_merge() {
     let mut app = App::new();
-    app.add_plugins(CustomBuildPlugin);
+    app.add_plugins(StatesPlugin);
+    app.add_plugins(MergedOnEnterPlugin);
 
-    assert!(app.world().contains_resource::<CustomBuildMarker>());
+    app.world_mut()
+        .resource_mut::<NextState<NewSyntaxState>>()
+        .set(NewSyntaxState::Active);
+    app.update();
+
+    // Every registration for the same state runs exactly once
+    let log = &app.world().resource::<MergedEnterLog>().0;
+    assert_eq!(log.len(), 3);
+    assert!(log.contains(&"first"));
+    assert!(log.contains(&"second"));
+    assert!(log.contains(&"third"));
 }
 
-// ============================================================================
-// Introspection tests (feature-gated)
-// ============================================================================
+// Test on_enter systems gated by a run condition
+#[derive(Resource, Default)]
+struct PlayerProfile {
+    first_time: bool,
+}
 
-#[cfg(feature = "introspection")]
-mod introspection_tests {
-    use super::*;
-    use bevy_plugin_builder::{PluginInfo, PluginRegistry};
+#[derive(Resource, Default)]
+struct TutorialLog(Vec<&'static str>);
 
-    // Test plugin with full metadata
-    #[derive(Resource, Default)]
-    struct IntrospectionResource;
+fn first_time_player(profile: Res<PlayerProfile>) -> bool {
+    profile.first_time
+}
 
-    #[derive(Message)]
-    struct IntrospectionMessage;
+fn spawn_tutorial(mut log: ResMut<TutorialLog>) {
+    log.0.push("tutorial");
+}
+
+fn show_hints(mut log: ResMut<TutorialLog>) {
+    log.0.push("hints");
+}
+
+fn grant_starter_kit(mut log: ResMut<TutorialLog>) {
+    log.0.push("starter_kit");
+}
+
+fn load_level(mut log: ResMut<TutorialLog>) {
+    log.0.push("level");
+}
+
+define_plugin!(TutorialPlugin {
+    init_resource: [PlayerProfile, TutorialLog],
+    init_state: [NewSyntaxState],
+    add_systems_on_enter: {
+        NewSyntaxState::Active => [spawn_tutorial.run_if(first_time_player), load_level],
+        NewSyntaxState::Active if first_time_player => [show_hints, grant_starter_kit]
+    }
+});
+
+fn enter_tutorial_app(first_time: bool) -> App {
+    let mut app = App::new();
+    app.add_plugins((StatesPlugin, TutorialPlugin));
+    app.insert_resource(PlayerProfile { first_time });
+    app.world_mut()
+        .resource_mut::<NextState<NewSyntaxState>>()
+        .set(NewSyntaxState::Active);
+    app.update();
+    app
+}
+
+#[test]
+fn test_on_enter_conditions_hold() {
+    let app = enter_tutorial_app(true);
+    let log = &app.world().resource::<TutorialLog>().0;
+    assert_eq!(log.len(), 4);
+    assert!(log.contains(&"tutorial"));
+    assert!(log.contains(&"hints"));
+    assert!(log.contains(&"starter_kit"));
+    assert!(log.contains(&"level"));
+}
+
+#[test]
+fn test_on_enter_conditions_fail() {
+    // Only the ungated system runs for a returning player
+    let app = enter_tutorial_app(false);
+    assert_eq!(app.world().resource::<TutorialLog>().0, vec!["level"]);
+}
+
+// Test on_enter_any sharing systems across several states
+#[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+enum ScreenState {
+    #[default]
+    Playing,
+    Menu,
+    GameOver,
+}
+
+#[derive(Resource, Default)]
+struct UiResets(u32);
+
+fn reset_ui(mut resets: ResMut<UiResets>) {
+    resets.0 += 1;
+}
+
+define_plugin!(SharedEnterPlugin {
+    init_state: [ScreenState],
+    init_resource: [UiResets],
+    on_enter_any: {
+        [ScreenState::Menu, ScreenState::GameOver] => [reset_ui]
+    }
+});
+
+#[test]
+fn test_on_enter_any() {
+    let mut app = App::new();
+    app.add_plugins((StatesPlugin, SharedEnterPlugin));
+    app.update();
+    assert_eq!(app.world().resource::<UiResets>().0, 0);
+
+    for state in [
+        ScreenState::Menu,
+        ScreenState::Playing,
+        ScreenState::GameOver,
+    ] {
+        app.world_mut()
+            .resource_mut::<NextState<ScreenState>>()
+            .set(state);
+        app.update();
+    }
+    assert_eq!(app.world().resource::<UiResets>().0, 2);
+}
+
+// Test ordering of on_enter systems within a single state
+#[derive(Resource, Default)]
+struct OrderedEnterLog(Vec<&'static str>);
+
+fn setup_world(mut log: ResMut<OrderedEnterLog>) {
+    log.0.push("setup_world");
+}
+
+fn spawn_player(mut log: ResMut<OrderedEnterLog>) {
+    log.0.push("spawn_player");
+}
+
+fn spawn_enemies(mut log: ResMut<OrderedEnterLog>) {
+    log.0.push("spawn_enemies");
+}
+
+fn start_music(mut log: ResMut<OrderedEnterLog>) {
+    log.0.push("start_music");
+}
+
+define_plugin!(OrderedOnEnterPlugin {
+    init_resource: [OrderedEnterLog],
+    init_state: [NewSyntaxState],
+    add_systems_on_enter: {
+        NewSyntaxState::Active => [setup_world, spawn_player].chain()
+    },
+    add_systems_on_exit: {
+        NewSyntaxState::Active => (spawn_enemies, start_music).chain()
+    }
+});
+
+#[test]
+fn test_on_enter_chained_ordering() {
+    let mut app = App::new();
+    app.add_plugins(StatesPlugin);
+    app.add_plugins(OrderedOnEnterPlugin);
+
+    app.world_mut()
+        .resource_mut::<NextState<NewSyntaxState>>()
+        .set(NewSyntaxState::Active);
+    app.update();
+
+    assert_eq!(
+        app.world().resource::<OrderedEnterLog>().0,
+        vec!["setup_world", "spawn_player"]
+    );
+
+    app.world_mut()
+        .resource_mut::<NextState<NewSyntaxState>>()
+        .set(NewSyntaxState::Idle);
+    app.update();
+
+    assert_eq!(
+        app.world().resource::<OrderedEnterLog>().0,
+        vec![
+            "setup_world",
+            "spawn_player",
+            "spawn_enemies",
+            "start_music"
+        ]
+    );
+}
+
+// Test distributive_run_if_update re-checking the condition per system
+#[derive(Resource)]
+struct Gate {
+    open: bool,
+    runs: u32,
+}
+
+impl Default for Gate {
+    fn default() -> Self {
+        Self {
+            open: true,
+            runs: 0,
+        }
+    }
+}
+
+fn gate_open(gate: Res<Gate>) -> bool {
+    gate.open
+}
+
+fn close_gate(mut gate: ResMut<Gate>) {
+    gate.runs += 1;
+    gate.open = false;
+}
+
+fn behind_gate(mut gate: ResMut<Gate>) {
+    gate.runs += 10;
+}
+
+define_plugin!(DistributiveGatePlugin {
+    init_resource: [Gate],
+    distributive_run_if_update: gate_open,
+    add_systems_update: [close_gate, behind_gate.after(close_gate)]
+});
+
+#[test]
+fn test_distributive_run_if_update() {
+    let mut app = App::new();
+    app.add_plugins(DistributiveGatePlugin);
+
+    // close_gate flips the condition, so behind_gate is skipped in the same update
+    app.update();
+    assert_eq!(app.world().resource::<Gate>().runs, 1);
+
+    app.update();
+    assert_eq!(app.world().resource::<Gate>().runs, 1);
+}
+
+// Test flush_between_update applying commands between chained systems
+#[derive(Component)]
+struct FlushedMarker;
+
+#[derive(Resource, Default)]
+struct SeenMarkers(Vec<usize>);
+
+fn spawn_flushed_marker(mut commands: Commands) {
+    commands.spawn(FlushedMarker);
+}
+
+fn count_flushed_markers(markers: Query<&FlushedMarker>, mut seen: ResMut<SeenMarkers>) {
+    seen.0.push(markers.iter().count());
+}
+
+define_plugin!(FlushBetweenPlugin {
+    init_resource: [SeenMarkers],
+    flush_between_update: true,
+    add_systems_update: [spawn_flushed_marker, count_flushed_markers]
+});
+
+define_plugin!(IgnoreDeferredPlugin {
+    init_resource: [SeenMarkers],
+    add_systems_update: [(spawn_flushed_marker, count_flushed_markers).chain_ignore_deferred()]
+});
+
+#[test]
+fn test_flush_between_update() {
+    let mut app = App::new();
+    app.add_plugins(FlushBetweenPlugin);
+    app.update();
+    app.update();
+
+    // The spawn is applied before counting in the same update
+    assert_eq!(app.world().resource::<SeenMarkers>().0, vec![1, 2]);
+}
+
+#[test]
+fn test_chain_ignore_deferred_passthrough() {
+    let mut app = App::new();
+    app.add_plugins(IgnoreDeferredPlugin);
+    app.update();
+    app.update();
+
+    // Without a flush the count lags one update behind
+    assert_eq!(app.world().resource::<SeenMarkers>().0, vec![0, 1]);
+}
+
+// Test add_systems_update_exclusive next to parallel systems
+#[derive(Component)]
+struct Debris;
+
+#[derive(Resource, Default)]
+struct DebrisSeen(usize);
+
+fn count_debris(debris: Query<&Debris>, mut seen: ResMut<DebrisSeen>) {
+    seen.0 = debris.iter().count();
+}
+
+fn clear_world(world: &mut World) {
+    world.clear_entities();
+}
+
+define_plugin!(ExclusiveSystemsPlugin {
+    init_resource: [DebrisSeen],
+    add_systems_update: [count_debris],
+    add_systems_update_exclusive: [clear_world.after(count_debris)]
+});
+
+#[test]
+fn test_add_systems_update_exclusive() {
+    let mut app = App::new();
+    app.add_plugins(ExclusiveSystemsPlugin);
+    app.world_mut().spawn(Debris);
+    app.world_mut().spawn(Debris);
+
+    app.update();
+    assert_eq!(app.world().resource::<DebrisSeen>().0, 2);
+    assert_eq!(
+        app.world_mut().query::<&Debris>().iter(app.world()).count(),
+        0
+    );
+}
+
+// Test add_observers reacting to a component being added
+#[derive(Component)]
+struct Player;
+
+#[derive(Resource, Default)]
+struct PlayersAdded(u32);
+
+fn count_added_players(_add: On<Add, Player>, mut added: ResMut<PlayersAdded>) {
+    added.0 += 1;
+}
+
+define_plugin!(ObserverPlugin {
+    init_resource: [PlayersAdded],
+    add_observers: [count_added_players]
+});
+
+#[test]
+fn test_add_observers() {
+    let mut app = App::new();
+    app.add_plugins(ObserverPlugin);
+
+    app.world_mut().spawn(Player);
+    app.world_mut().spawn(Player);
+    assert_eq!(app.world().resource::<PlayersAdded>().0, 2);
+}
+
+// Test add_message_buffered keeping a message for three updates
+#[derive(Message)]
+struct SlowPollEvent;
+
+define_plugin!(BufferedMessagePlugin {
+    add_message_buffered: {
+        SlowPollEvent => 3
+    }
+});
+
+#[test]
+fn test_add_message_buffered() {
+    let mut app = App::new();
+    app.add_plugins(BufferedMessagePlugin);
+    app.world_mut().write_message(SlowPollEvent);
+
+    let readable = |app: &App| {
+        let messages = app.world().resource::<Messages<SlowPollEvent>>();
+        messages.get_cursor().read(messages).count()
+    };
+
+    for _ in 0..3 {
+        app.update();
+        assert_eq!(readable(&app), 1);
+    }
+    app.update();
+    assert_eq!(readable(&app), 0);
+}
+
+#[test]
+#[should_panic(
+    expected = "add_message_buffered: `integration::SlowPollEvent` is already registered"
+)]
+fn test_add_message_buffered_after_add_message() {
+    let mut app = App::new();
+    app.add_message::<SlowPollEvent>();
+    app.add_plugins(BufferedMessagePlugin);
+}
+
+// Test add_plugins_configured disabling one plugin of a group
+#[derive(Resource)]
+struct AudioLoaded;
+
+#[derive(Resource)]
+struct RenderLoaded;
+
+define_plugin!(MockAudioPlugin {
+    custom_build: |app: &mut App| {
+        app.insert_resource(AudioLoaded);
+    }
+});
+
+define_plugin!(MockRenderPlugin {
+    custom_build: |app: &mut App| {
+        app.insert_resource(RenderLoaded);
+    }
+});
+
+struct MockDefaultPlugins;
+
+impl PluginGroup for MockDefaultPlugins {
+    fn build(self) -> bevy::app::PluginGroupBuilder {
+        bevy::app::PluginGroupBuilder::start::<Self>()
+            .add(MockAudioPlugin)
+            .add(MockRenderPlugin)
+    }
+}
+
+define_plugin!(ConfiguredGroupPlugin {
+    add_plugins_configured: {
+        MockDefaultPlugins => .disable::<MockAudioPlugin>()
+    }
+});
+
+#[test]
+fn test_add_plugins_configured() {
+    let mut app = App::new();
+    app.add_plugins(ConfiguredGroupPlugin);
+
+    assert!(app.world().contains_resource::<RenderLoaded>());
+    assert!(!app.world().contains_resource::<AudioLoaded>());
+}
+
+// Test cfg-gated entries in a system list
+#[derive(Resource, Default)]
+struct GatedRuns(Vec<&'static str>);
+
+fn always_runs(mut runs: ResMut<GatedRuns>) {
+    runs.0.push("always");
+}
+
+#[cfg(feature = "introspection")]
+fn introspection_only(mut runs: ResMut<GatedRuns>) {
+    runs.0.push("introspection");
+}
+
+#[cfg(not(feature = "introspection"))]
+fn without_introspection(mut runs: ResMut<GatedRuns>) {
+    runs.0.push("plain");
+}
+
+define_plugin!(CfgSystemsPlugin {
+    init_resource: [GatedRuns],
+    add_systems_update: [
+        #[cfg(feature = "introspection")]
+        introspection_only,
+        always_runs,
+        #[cfg(not(feature = "introspection"))]
+        without_introspection,
+    ]
+});
+
+#[test]
+fn test_cfg_gated_systems() {
+    let mut app = App::new();
+    app.add_plugins(CfgSystemsPlugin);
+    app.update();
+
+    let mut runs = app.world().resource::<GatedRuns>().0.clone();
+    runs.sort();
+    if cfg!(feature = "introspection") {
+        assert_eq!(runs, vec!["always", "introspection"]);
+    } else {
+        assert_eq!(runs, vec!["always", "plain"]);
+    }
+}
+
+// Test update_if_resource_exists waiting for a resource inserted mid-run
+#[derive(Resource)]
+struct LateConfig;
+
+#[derive(Resource, Default)]
+struct LateConfigTicks(u32);
+
+fn tick_with_late_config(mut ticks: ResMut<LateConfigTicks>) {
+    ticks.0 += 1;
+}
+
+define_plugin!(ResourceExistsPlugin {
+    init_resource: [LateConfigTicks],
+    update_if_resource_exists: {
+        LateConfig => [tick_with_late_config]
+    }
+});
+
+#[test]
+fn test_update_if_resource_exists() {
+    let mut app = App::new();
+    app.add_plugins(ResourceExistsPlugin);
+
+    app.update();
+    app.update();
+    assert_eq!(app.world().resource::<LateConfigTicks>().0, 0);
+
+    app.insert_resource(LateConfig);
+    app.update();
+    app.update();
+    assert_eq!(app.world().resource::<LateConfigTicks>().0, 2);
+}
+
+// Test update_if_windowed skipping systems in a headless app
+#[derive(Resource, Default)]
+struct WindowedRuns(u32);
+
+fn render_ui(mut runs: ResMut<WindowedRuns>) {
+    runs.0 += 1;
+}
+
+define_plugin!(WindowedPlugin {
+    init_resource: [WindowedRuns],
+    update_if_windowed: [render_ui]
+});
+
+#[test]
+fn test_update_if_windowed() {
+    let mut app = App::new();
+    app.add_plugins(WindowedPlugin);
+    app.update();
+    assert_eq!(app.world().resource::<WindowedRuns>().0, 0);
+
+    app.world_mut()
+        .spawn((Window::default(), bevy::window::PrimaryWindow));
+    app.update();
+    assert_eq!(app.world().resource::<WindowedRuns>().0, 1);
+}
+
+// Test update_every_n_frames throttling a system
+#[derive(Resource, Default)]
+struct ThrottledRuns(u32);
+
+fn expensive_system(mut runs: ResMut<ThrottledRuns>) {
+    runs.0 += 1;
+}
+
+define_plugin!(ThrottledPlugin {
+    init_resource: [ThrottledRuns],
+    update_every_n_frames: {
+        5 => [expensive_system]
+    }
+});
+
+#[test]
+fn test_update_every_n_frames() {
+    let mut app = App::new();
+    app.add_plugins(ThrottledPlugin);
+
+    let mut runs = Vec::new();
+    for _ in 0..12 {
+        app.update();
+        runs.push(app.world().resource::<ThrottledRuns>().0);
+    }
+    assert_eq!(runs, vec![0, 0, 0, 0, 1, 1, 1, 1, 1, 2, 2, 2]);
+}
+
+// Test add_systems_update_if deciding at build time
+const DEBUG_OVERLAY_ENABLED: bool = false;
+
+fn debug_overlay() {}
+fn gameplay_update() {}
+
+define_plugin!(BuildTimeConditionPlugin {
+    add_systems_update_if: {
+        DEBUG_OVERLAY_ENABLED => [debug_overlay],
+        !DEBUG_OVERLAY_ENABLED => [gameplay_update]
+    }
+});
+
+#[test]
+fn test_add_systems_update_if() {
+    use bevy::ecs::system::System;
+    use std::any::TypeId;
+
+    fn system_type_id<M>(system: impl IntoSystem<(), (), M>) -> TypeId {
+        System::type_id(&IntoSystem::into_system(system))
+    }
+
+    let mut app = App::new();
+    app.add_plugins(BuildTimeConditionPlugin);
+    app.update();
+
+    let schedules = app.world().resource::<Schedules>();
+    let system_types: Vec<TypeId> = schedules
+        .get(Update)
+        .unwrap()
+        .systems()
+        .unwrap()
+        .map(|(_, system)| System::type_id(system.as_ref()))
+        .collect();
+
+    // Only the system whose build-time condition held is in the schedule
+    assert!(system_types.contains(&system_type_id(gameplay_update)));
+    assert!(!system_types.contains(&system_type_id(debug_overlay)));
+}
+
+// Test state-scoped entity cleanup for states initialized by a plugin
+#[derive(Component)]
+struct LevelGeometry;
+
+fn spawn_level_geometry(mut commands: Commands) {
+    commands.spawn((LevelGeometry, DespawnOnExit(NewSyntaxState::Active)));
+}
+
+define_plugin!(StateScopedPlugin {
+    init_state: [NewSyntaxState],
+    add_systems_on_enter: {
+        NewSyntaxState::Active => [spawn_level_geometry]
+    }
+});
+
+#[test]
+fn test_state_scoped_entities_despawn() {
+    let mut app = App::new();
+    app.add_plugins(StatesPlugin);
+    app.add_plugins(StateScopedPlugin);
+
+    app.world_mut()
+        .resource_mut::<NextState<NewSyntaxState>>()
+        .set(NewSyntaxState::Active);
+    app.update();
+    let mut geometry = app.world_mut().query::<&LevelGeometry>();
+    assert_eq!(geometry.iter(app.world()).count(), 1);
+
+    app.world_mut()
+        .resource_mut::<NextState<NewSyntaxState>>()
+        .set(NewSyntaxState::Idle);
+    app.update();
+    assert_eq!(geometry.iter(app.world()).count(), 0);
+}
+
+// Test with_conditions gating a whole update list
+#[derive(Resource, Default)]
+struct ConditionFlags {
+    debug: bool,
+    verbose: bool,
+}
+
+#[derive(Resource, Default)]
+struct ConditionedRuns(u32);
+
+fn is_debug(flags: Res<ConditionFlags>) -> bool {
+    flags.debug
+}
+
+fn is_verbose(flags: Res<ConditionFlags>) -> bool {
+    flags.verbose
+}
+
+fn conditioned_first(mut runs: ResMut<ConditionedRuns>) {
+    runs.0 += 1;
+}
+
+fn conditioned_second(mut runs: ResMut<ConditionedRuns>) {
+    runs.0 += 10;
+}
+
+define_plugin!(ConditionedPlugin {
+    init_resource: [ConditionFlags, ConditionedRuns],
+    with_conditions: [is_debug, is_verbose],
+    add_systems_update: [conditioned_first, conditioned_second]
+});
+
+#[test]
+fn test_with_conditions() {
+    let mut app = App::new();
+    app.add_plugins(ConditionedPlugin);
+
+    // Neither condition holds
+    app.update();
+    assert_eq!(app.world().resource::<ConditionedRuns>().0, 0);
+
+    // Only one condition holds - conditions are combined with `and`
+    app.world_mut().resource_mut::<ConditionFlags>().debug = true;
+    app.update();
+    assert_eq!(app.world().resource::<ConditionedRuns>().0, 0);
+
+    // Both conditions hold - every system in the list runs
+    app.world_mut().resource_mut::<ConditionFlags>().verbose = true;
+    app.update();
+    assert_eq!(app.world().resource::<ConditionedRuns>().0, 11);
+}
+
+// Test update_before/update_after ordering against an external set
+#[derive(bevy::ecs::schedule::SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+struct ExternalSet;
+
+#[derive(Resource, Default)]
+struct OrderLog(Vec<&'static str>);
+
+fn external_system(mut log: ResMut<OrderLog>) {
+    log.0.push("external");
+}
+
+fn early_system(mut log: ResMut<OrderLog>) {
+    log.0.push("early");
+}
+
+fn late_system(mut log: ResMut<OrderLog>) {
+    log.0.push("late");
+}
+
+define_plugin!(EarlyOrderPlugin {
+    init_resource: [OrderLog],
+    add_systems_update: [early_system],
+    update_before: [ExternalSet]
+});
+
+define_plugin!(LateOrderPlugin {
+    update_after: [ExternalSet],
+    add_systems_update: [late_system]
+});
+
+#[test]
+fn test_update_ordering_against_set() {
+    let mut app = App::new();
+    app.add_plugins((EarlyOrderPlugin, LateOrderPlugin));
+    app.add_systems(Update, external_system.in_set(ExternalSet));
+
+    app.update();
+
+    let log = &app.world().resource::<OrderLog>().0;
+    assert_eq!(log, &["early", "external", "late"]);
+}
+
+// Test ordering external systems against a generated, named system set
+#[derive(Resource, Default)]
+struct ScoringLog(Vec<&'static str>);
+
+fn tally_score(mut log: ResMut<ScoringLog>) {
+    log.0.push("tally_score");
+}
+
+fn apply_bonus(mut log: ResMut<ScoringLog>) {
+    log.0.push("apply_bonus");
+}
+
+fn show_score(mut log: ResMut<ScoringLog>) {
+    log.0.push("show_score");
+}
+
+define_plugin!(ScoringPlugin {
+    init_resource: [ScoringLog],
+    generate_system_set: ScoringSystems,
+    add_systems_update: [tally_score, apply_bonus.after(tally_score)]
+});
+
+#[test]
+fn test_generate_system_set() {
+    let mut app = App::new();
+    // Added before the plugin, so only the set ordering puts it last
+    app.add_systems(Update, show_score.after(ScoringSystems));
+    app.add_plugins(ScoringPlugin);
+    app.update();
+
+    assert_eq!(
+        app.world().resource::<ScoringLog>().0,
+        vec!["tally_score", "apply_bonus", "show_score"]
+    );
+}
+
+// Test switching a whole plugin off at runtime with togglable: true
+#[derive(Resource, Default)]
+struct WeatherTicks(u32);
+
+fn tick_weather(mut ticks: ResMut<WeatherTicks>) {
+    ticks.0 += 1;
+}
+
+define_plugin!(WeatherPlugin {
+    init_resource: [WeatherTicks],
+    togglable: true,
+    add_systems_update: [tick_weather]
+});
+
+#[test]
+fn test_togglable() {
+    use bevy_plugin_builder::PluginEnabled;
+
+    let mut app = App::new();
+    app.add_plugins(WeatherPlugin);
+    assert!(app
+        .world()
+        .resource::<PluginEnabled<WeatherPlugin>>()
+        .is_enabled());
+
+    app.update();
+    assert_eq!(app.world().resource::<WeatherTicks>().0, 1);
+
+    app.world_mut()
+        .resource_mut::<PluginEnabled<WeatherPlugin>>()
+        .set(false);
+    app.update();
+    app.update();
+    assert_eq!(app.world().resource::<WeatherTicks>().0, 1);
+
+    app.world_mut()
+        .resource_mut::<PluginEnabled<WeatherPlugin>>()
+        .set(true);
+    app.update();
+    assert_eq!(app.world().resource::<WeatherTicks>().0, 2);
+}
+
+// Test run_if: one condition gating both Update and FixedUpdate systems
+#[derive(Resource)]
+struct SimulationRunning;
+
+#[derive(Resource, Default)]
+struct SimulationTicks {
+    update: u32,
+    fixed: u32,
+}
+
+fn tick_simulation_update(mut ticks: ResMut<SimulationTicks>) {
+    ticks.update += 1;
+}
+
+fn tick_simulation_fixed(mut ticks: ResMut<SimulationTicks>) {
+    ticks.fixed += 1;
+}
+
+define_plugin!(SimulationPlugin {
+    init_resource: [SimulationTicks],
+    run_if: resource_exists::<SimulationRunning>,
+    add_systems_update: [tick_simulation_update],
+    add_systems_fixed_update: [tick_simulation_fixed]
+});
+
+#[test]
+fn test_run_if_gates_update_and_fixed_update() {
+    let mut app = App::new();
+    app.add_plugins(SimulationPlugin);
+
+    app.update();
+    app.world_mut().run_schedule(FixedUpdate);
+    let ticks = app.world().resource::<SimulationTicks>();
+    assert_eq!((ticks.update, ticks.fixed), (0, 0));
+
+    app.insert_resource(SimulationRunning);
+    app.update();
+    app.world_mut().run_schedule(FixedUpdate);
+    let ticks = app.world().resource::<SimulationTicks>();
+    assert_eq!((ticks.update, ticks.fixed), (1, 1));
+}
+
+// Test add_message_with update policies
+#[derive(Message)]
+struct ManualMessage;
+
+#[derive(Message)]
+struct AutomaticMessage;
+
+define_plugin!(MessagePolicyPlugin {
+    add_message_with: {
+        ManualMessage => ManualUpdate,
+        AutomaticMessage => Automatic
+    }
+});
+
+#[test]
+fn test_add_message_with_policies() {
+    let mut app = App::new();
+    app.add_plugins(MessagePolicyPlugin);
+
+    app.world_mut().write_message(ManualMessage);
+    app.world_mut().write_message(AutomaticMessage);
+
+    // Automatic messages are cleared after two updates
+    app.update();
+    app.update();
+    app.update();
+
+    assert_eq!(
+        app.world().resource::<Messages<AutomaticMessage>>().len(),
+        0
+    );
+    // Manual messages survive until drained by the app
+    assert_eq!(app.world().resource::<Messages<ManualMessage>>().len(), 1);
+
+    app.world_mut()
+        .resource_mut::<Messages<ManualMessage>>()
+        .clear();
+    assert!(app.world().resource::<Messages<ManualMessage>>().is_empty());
+}
+
+// Test ManualUpdate messages keeping their contents until drained
+#[derive(Message, Debug, PartialEq)]
+struct RareEvent(u32);
+
+define_plugin!(PersistentMessagePlugin {
+    add_message_with: { RareEvent => ManualUpdate }
+});
+
+#[test]
+fn test_manual_update_message_drained() {
+    let mut app = App::new();
+    app.add_plugins(PersistentMessagePlugin);
+
+    app.world_mut().write_message(RareEvent(7));
+    app.update();
+    app.update();
+
+    let drained: Vec<RareEvent> = app
+        .world_mut()
+        .resource_mut::<Messages<RareEvent>>()
+        .drain()
+        .collect();
+    assert_eq!(drained, vec![RareEvent(7)]);
+    assert!(app.world().resource::<Messages<RareEvent>>().is_empty());
+}
+
+// Test on_substate_update gating systems by sub-state
+#[derive(SubStates, Default, Debug, Clone, PartialEq, Eq, Hash)]
+#[source(RootFlowState = RootFlowState::Active)]
+enum BossSubState {
+    #[default]
+    Exploring,
+    BossLevel,
+}
+
+#[derive(Resource, Default)]
+struct BossTicks(u32);
+
+fn boss_tick(mut ticks: ResMut<BossTicks>) {
+    ticks.0 += 1;
+}
+
+define_plugin!(BossPlugin {
+    init_resource: [BossTicks],
+    init_state: [RootFlowState],
+    add_sub_state: [BossSubState],
+    on_substate_update: {
+        BossSubState::BossLevel => [boss_tick]
+    }
+});
+
+define_plugin!(UnregisteredSubStatePlugin {
+    init_resource: [BossTicks],
+    on_substate_update: {
+        BossSubState::BossLevel => [boss_tick]
+    }
+});
+
+#[test]
+fn test_on_substate_update() {
+    let mut app = App::new();
+    app.add_plugins((StatesPlugin, BossPlugin));
+    app.finish();
+
+    // Parent state not active - sub-state doesn't exist
+    app.update();
+    assert_eq!(app.world().resource::<BossTicks>().0, 0);
+
+    // Sub-state active but in its default variant
+    app.world_mut()
+        .resource_mut::<NextState<RootFlowState>>()
+        .set(RootFlowState::Active);
+    app.update();
+    assert_eq!(app.world().resource::<BossTicks>().0, 0);
+
+    app.world_mut()
+        .resource_mut::<NextState<BossSubState>>()
+        .set(BossSubState::BossLevel);
+    app.update();
+    app.update();
+    assert_eq!(app.world().resource::<BossTicks>().0, 2);
+}
+
+#[test]
+#[should_panic(expected = "never registered")]
+fn test_on_substate_update_requires_sub_state() {
+    let mut app = App::new();
+    app.add_plugins((StatesPlugin, UnregisteredSubStatePlugin));
+    app.finish();
+}
+
+// Test requires_bevy_features checking for the feature's plugin in finish()
+fn render_only_system() {}
+
+define_plugin!(RenderFeaturePlugin {
+    requires_bevy_features: ["bevy_render"],
+    add_systems_update: [render_only_system]
+});
+
+define_plugin!(StateFeaturePlugin {
+    requires_bevy_features: ["bevy_state"],
+    init_state: [TestState]
+});
+
+#[test]
+#[should_panic(
+    expected = "Plugin 'RenderFeaturePlugin' requires the bevy feature 'bevy_render', but RenderPlugin was never added"
+)]
+fn test_requires_bevy_features_missing() {
+    let mut app = App::new();
+    app.add_plugins(RenderFeaturePlugin);
+    app.finish();
+}
+
+#[test]
+fn test_requires_bevy_features_present() {
+    let mut app = App::new();
+    app.add_plugins((StatesPlugin, StateFeaturePlugin));
+    app.finish();
+    assert!(app.world().contains_resource::<State<TestState>>());
+}
+
+// Test custom_build (new name for custom_init)
+#[derive(Resource)]
+struct CustomBuildMarker;
+
+define_plugin!(CustomBuildPlugin {
+    custom_build: |app: &mut App| {
+        app.insert_resource(CustomBuildMarker);
+    }
+});
+
+#[test]
+fn test_custom_build_syntax() {
+    let mut app = App::new();
+    app.add_plugins(CustomBuildPlugin);
+
+    assert!(app.world().contains_resource::<CustomBuildMarker>());
+}
+
+// Test that resources are registered before systems and custom_build,
+// whatever order the configuration lists them in
+#[derive(Resource)]
+struct SpawnBudget(u32);
+
+#[derive(Resource, Default)]
+struct SpawnedCount(u32);
+
+fn spawn_within_budget(budget: Res<SpawnBudget>, mut spawned: ResMut<SpawnedCount>) {
+    spawned.0 = budget.0;
+}
+
+define_plugin!(SpawnBudgetPlugin {
+    add_systems_startup: [spawn_within_budget],
+    custom_build: |app: &mut App| {
+        // Runs during build(), so the resources must already be registered
+        let budget = app.world().resource::<SpawnBudget>().0;
+        app.insert_resource(SpawnBudget(budget * 2));
+    },
+    insert_resource: [SpawnBudget(4)],
+    init_resource: [SpawnedCount]
+});
+
+#[test]
+fn test_registrations_before_systems_and_custom_build() {
+    let mut app = App::new();
+    app.add_plugins(SpawnBudgetPlugin);
+    app.update();
+
+    assert_eq!(app.world().resource::<SpawnedCount>().0, 8);
+}
+
+// Test custom_build and custom_finish taking named functions
+#[derive(Resource, Default)]
+struct NamedHookLog(Vec<&'static str>);
+
+fn setup_named_build(app: &mut App) {
+    app.init_resource::<NamedHookLog>();
+    app.world_mut()
+        .resource_mut::<NamedHookLog>()
+        .0
+        .push("build");
+}
+
+mod named_hooks {
+    use super::*;
+
+    pub fn finish(app: &mut App) {
+        app.world_mut()
+            .resource_mut::<NamedHookLog>()
+            .0
+            .push("finish");
+    }
+}
+
+define_plugin!(NamedHooksPlugin {
+    custom_build: setup_named_build,
+    custom_finish: named_hooks::finish,
+    init_resource: [CustomBuildMarkerResource]
+});
+
+#[derive(Resource, Default)]
+struct CustomBuildMarkerResource;
+
+#[test]
+fn test_custom_hooks_named_functions() {
+    let mut app = App::new();
+    app.add_plugins(NamedHooksPlugin);
+    app.finish();
+
+    assert_eq!(
+        app.world().resource::<NamedHookLog>().0,
+        vec!["build", "finish"]
+    );
+    assert!(app.world().contains_resource::<CustomBuildMarkerResource>());
+}
+
+// Test fields and generated constructors flowing into build
+#[derive(Resource, Debug, PartialEq)]
+struct MovementSpeed(f32);
+
+#[derive(Resource, Debug, PartialEq)]
+struct MovementLabel(&'static str);
+
+define_plugin!(#[derive(Default)] ConfigurableMovementPlugin {
+    fields: { speed: f32, label: &'static str },
+    constructors: {
+        with_speed(speed: f32),
+        with_speed_and_label(speed: f32, label: &'static str)
+    },
+    custom_build: |app: &mut App, plugin: &Self| {
+        app.insert_resource(MovementSpeed(plugin.speed));
+        app.insert_resource(MovementLabel(plugin.label));
+    }
+});
+
+#[test]
+fn test_plugin_fields_and_constructors() {
+    let mut app = App::new();
+    app.add_plugins(ConfigurableMovementPlugin::with_speed(5.0));
+    assert_eq!(app.world().resource::<MovementSpeed>(), &MovementSpeed(5.0));
+    assert_eq!(app.world().resource::<MovementLabel>(), &MovementLabel(""));
+
+    let mut app = App::new();
+    app.add_plugins(ConfigurableMovementPlugin::with_speed_and_label(
+        2.5, "boost",
+    ));
+    assert_eq!(
+        app.world().resource::<MovementLabel>(),
+        &MovementLabel("boost")
+    );
+
+    let mut app = App::new();
+    app.add_plugins(ConfigurableMovementPlugin {
+        speed: 1.0,
+        label: "manual",
+    });
+    assert_eq!(app.world().resource::<MovementSpeed>(), &MovementSpeed(1.0));
+    assert_eq!(
+        app.world().resource::<MovementLabel>(),
+        &MovementLabel("manual")
+    );
+}
+
+// Test settings_resource: the plugin's fields flow into a resource systems read
+#[derive(Resource)]
+struct SpawnerSettings {
+    rate: u32,
+    tag: String,
+}
+
+#[derive(Resource, Default)]
+struct SpawnedTags(Vec<String>);
+
+fn spawn_from_settings(settings: Res<SpawnerSettings>, mut spawned: ResMut<SpawnedTags>) {
+    for _ in 0..settings.rate {
+        spawned.0.push(settings.tag.clone());
+    }
+}
+
+define_plugin!(SpawnerPlugin {
+    fields: { rate: u32, tag: String },
+    settings_resource: SpawnerSettings,
+    init_resource: [SpawnedTags],
+    add_systems_startup: [spawn_from_settings]
+});
+
+#[test]
+fn test_settings_resource_from_fields() {
+    let mut app = App::new();
+    app.add_plugins(SpawnerPlugin {
+        rate: 2,
+        tag: "slime".to_string(),
+    });
+    app.update();
+
+    assert_eq!(app.world().resource::<SpawnerSettings>().rate, 2);
+    assert_eq!(
+        app.world().resource::<SpawnedTags>().0,
+        vec!["slime", "slime"]
+    );
+}
+
+// Test init_schedule and add_systems_to with a custom schedule
+#[derive(bevy::ecs::schedule::ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+struct CustomSchedule;
+
+#[derive(Resource, Default)]
+struct CustomScheduleCounter(u32);
+
+fn custom_schedule_system(mut counter: ResMut<CustomScheduleCounter>) {
+    counter.0 += 1;
+}
+
+define_plugin!(CustomSchedulePlugin {
+    init_resource: [CustomScheduleCounter],
+    init_schedule: [CustomSchedule],
+    add_systems_to: {
+        CustomSchedule => [custom_schedule_system]
+    }
+});
+
+#[test]
+fn test_custom_schedule() {
+    let mut app = App::new();
+    app.add_plugins(CustomSchedulePlugin);
+
+    // The custom schedule is not part of the main schedule, so update does not run it
+    app.update();
+    assert_eq!(app.world().resource::<CustomScheduleCounter>().0, 0);
+
+    // Running the schedule manually executes its systems
+    app.world_mut().run_schedule(CustomSchedule);
+    assert_eq!(app.world().resource::<CustomScheduleCounter>().0, 1);
+}
+
+// Test add_systems_to_many registering one system in several schedules
+#[derive(Resource, Default)]
+struct ScheduleRuns(Vec<&'static str>);
+
+fn record_schedule_run(mut runs: ResMut<ScheduleRuns>) {
+    runs.0.push("sync");
+}
+
+define_plugin!(MultiScheduleSyncPlugin {
+    init_resource: [ScheduleRuns],
+    add_systems_to_many: {
+        [Update, PostUpdate] => [record_schedule_run]
+    }
+});
+
+#[test]
+fn test_add_systems_to_many() {
+    let mut app = App::new();
+    app.add_plugins(MultiScheduleSyncPlugin);
+    app.update();
+
+    // Ran once in Update and once in PostUpdate
+    assert_eq!(
+        app.world().resource::<ScheduleRuns>().0,
+        vec!["sync", "sync"]
+    );
+
+    app.world_mut().resource_mut::<ScheduleRuns>().0.clear();
+    app.world_mut().run_schedule(PostUpdate);
+    assert_eq!(app.world().resource::<ScheduleRuns>().0.len(), 1);
+}
+
+// Test ambiguous_with_all_update exempting systems from ambiguity detection
+#[derive(Resource, Default)]
+struct SharedTally(u32);
+
+fn tally_first(mut tally: ResMut<SharedTally>) {
+    tally.0 += 1;
+}
+
+fn tally_second(mut tally: ResMut<SharedTally>) {
+    tally.0 += 1;
+}
+
+define_plugin!(AmbiguousTallyPlugin {
+    init_resource: [SharedTally],
+    ambiguous_with_all_update: [tally_first, tally_second]
+});
+
+define_plugin!(UnmarkedTallyPlugin {
+    init_resource: [SharedTally],
+    add_systems_update: [tally_first, tally_second]
+});
+
+fn app_with_ambiguity_errors() -> App {
+    use bevy::ecs::schedule::{LogLevel, ScheduleBuildSettings};
+
+    let mut app = App::new();
+    app.edit_schedule(Update, |schedule| {
+        schedule.set_build_settings(ScheduleBuildSettings {
+            ambiguity_detection: LogLevel::Error,
+            ..default()
+        });
+    });
+    app
+}
+
+#[test]
+fn test_ambiguous_with_all_update() {
+    let mut app = app_with_ambiguity_errors();
+    app.add_plugins(AmbiguousTallyPlugin);
+    app.update();
+    assert_eq!(app.world().resource::<SharedTally>().0, 2);
+
+    // The same systems without the marker are reported as ambiguous
+    let result = std::panic::catch_unwind(|| {
+        let mut app = app_with_ambiguity_errors();
+        app.add_plugins(UnmarkedTallyPlugin);
+        app.update();
+    });
+    assert!(result.is_err());
+}
+
+// Test *_raw keys taking a pre-built system config
+#[derive(Resource, Default)]
+struct RawOrder(Vec<&'static str>);
+
+fn raw_first(mut order: ResMut<RawOrder>) {
+    order.0.push("first");
+}
+
+fn raw_second(mut order: ResMut<RawOrder>) {
+    order.0.push("second");
+}
+
+fn raw_setup(mut order: ResMut<RawOrder>) {
+    order.0.push("setup");
+}
+
+/// Helper composing a system config the bracket list can't express
+fn chained_raw_systems() -> impl IntoScheduleConfigs<bevy::ecs::system::ScheduleSystem, ()> {
+    (raw_first, raw_second).chain()
+}
+
+define_plugin!(RawSystemsPlugin {
+    init_resource: [RawOrder],
+    add_systems_startup_raw: raw_setup,
+    add_systems_update_raw: chained_raw_systems(),
+    add_systems_fixed_update_raw: (raw_second, raw_first).chain()
+});
+
+#[test]
+fn test_add_systems_raw() {
+    let mut app = App::new();
+    app.add_plugins(RawSystemsPlugin);
+    app.update();
+
+    assert_eq!(
+        app.world().resource::<RawOrder>().0,
+        vec!["setup", "first", "second"]
+    );
+}
+
+// Test trace_registrations logging each registration
+#[derive(Resource, Default)]
+struct TracedSettings;
+
+#[derive(Message)]
+struct TracedMessage;
+
+fn traced_system() {}
+
+define_plugin!(TracedPlugin {
+    trace_registrations: true,
+    init_resource: [TracedSettings],
+    add_message: [TracedMessage],
+    add_systems_update: [traced_system]
+});
+
+/// Tracing layer that records the message of every event
+struct CaptureLayer(std::sync::Arc<std::sync::Mutex<Vec<String>>>);
+
+impl<S: bevy::log::tracing::Subscriber> bevy::log::tracing_subscriber::Layer<S> for CaptureLayer {
+    fn on_event(
+        &self,
+        event: &bevy::log::tracing::Event<'_>,
+        _ctx: bevy::log::tracing_subscriber::layer::Context<'_, S>,
+    ) {
+        struct MessageVisitor<'a>(&'a mut String);
+
+        impl bevy::log::tracing::field::Visit for MessageVisitor<'_> {
+            fn record_debug(
+                &mut self,
+                field: &bevy::log::tracing::field::Field,
+                value: &dyn std::fmt::Debug,
+            ) {
+                if field.name() == "message" {
+                    *self.0 = format!("{value:?}");
+                }
+            }
+        }
+
+        let mut message = String::new();
+        event.record(&mut MessageVisitor(&mut message));
+        self.0.lock().unwrap().push(message);
+    }
+}
+
+/// Run `build` on a new app and return the message of every event it logs
+fn captured_logs(build: impl FnOnce(&mut App)) -> Vec<String> {
+    use bevy::log::tracing_subscriber::layer::SubscriberExt;
+
+    let logs = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let subscriber = bevy::log::tracing_subscriber::registry().with(CaptureLayer(logs.clone()));
+    bevy::log::tracing::subscriber::with_default(subscriber, || build(&mut App::new()));
+
+    let logs = logs.lock().unwrap().clone();
+    logs
+}
+
+#[test]
+fn test_trace_registrations() {
+    let logs = captured_logs(|app| {
+        app.add_plugins(TracedPlugin);
+        // Plugins without the flag stay quiet
+        app.add_plugins(BasicTestPlugin);
+    });
+
+    assert!(logs.contains(&"TracedPlugin: init_resource TracedSettings".to_string()));
+    assert!(logs.contains(&"TracedPlugin: add_message TracedMessage".to_string()));
+    assert!(logs.contains(&"TracedPlugin: add_systems_update traced_system".to_string()));
+    assert!(!logs.iter().any(|log| log.contains("BasicTestPlugin:")));
+}
+
+// Test dry_run logging registrations without making them
+#[derive(Resource, Default)]
+struct DryRunSettings;
+
+#[derive(Message)]
+struct DryRunMessage;
+
+fn dry_run_system() {}
+
+define_plugin!(DryRunPlugin {
+    dry_run: true,
+    init_resource: [DryRunSettings],
+    add_message: [DryRunMessage],
+    add_systems_update: [dry_run_system],
+    custom_build: |app: &mut App| {
+        app.init_resource::<DryRunSettings>();
+    }
+});
+
+#[test]
+fn test_dry_run() {
+    let logs = captured_logs(|app| {
+        app.add_plugins(DryRunPlugin);
+        app.finish();
+
+        assert!(!app.world().contains_resource::<DryRunSettings>());
+        assert!(!app.world().contains_resource::<Messages<DryRunMessage>>());
+    });
+
+    assert!(logs.contains(&"DryRunPlugin: (dry run) init_resource DryRunSettings".to_string()));
+    assert!(logs.contains(&"DryRunPlugin: (dry run) add_message DryRunMessage".to_string()));
+    assert!(logs.contains(&"DryRunPlugin: (dry run) add_systems_update dry_run_system".to_string()));
+}
+
+// ============================================================================
+// Runtime builder tests
+// ============================================================================
+
+use bevy_plugin_builder::PluginBuilder;
+
+fn build_basic_test_plugin() -> bevy_plugin_builder::BuiltPlugin {
+    // Same registrations as BasicTestPlugin, constructed at runtime
+    PluginBuilder::new("BuilderBasicTestPlugin")
+        .init_resource::<TestResource>()
+        .add_message::<TestEvent>()
+        .add_systems_startup(startup_system)
+        .add_systems_update(update_system)
+        .build()
+}
+
+#[test]
+fn test_builder_basic_plugin() {
+    let mut app = App::new();
+    app.add_plugins(build_basic_test_plugin());
+
+    assert!(app.world().contains_resource::<TestResource>());
+    assert!(app.world().contains_resource::<Messages<TestEvent>>());
+
+    let resource = app.world().resource::<TestResource>();
+    assert_eq!(resource.value, 0);
+}
+
+#[test]
+fn test_builder_system_execution() {
+    let mut app = App::new();
+    app.add_plugins(build_basic_test_plugin());
+
+    app.update();
+
+    // Startup system spawned the entity
+    let entities: Vec<_> = app
+        .world_mut()
+        .query::<&TestComponent>()
+        .iter(app.world())
+        .collect();
+    assert_eq!(entities.len(), 1);
+
+    app.update();
+
+    // Update system ran on both frames
+    let resource = app.world().resource::<TestResource>();
+    assert_eq!(resource.value, 2);
+}
+
+#[test]
+fn test_builder_dependency_satisfied() {
+    let mut app = App::new();
+    app.add_plugins(PhysicsPlugin);
+    app.add_plugins(
+        PluginBuilder::new("BuilderDependentPlugin")
+            .depends_on::<PhysicsPlugin>()
+            .init_resource::<GameConfig>()
+            .build(),
+    );
+
+    assert!(app.world().contains_resource::<GameConfig>());
+}
+
+#[test]
+#[should_panic(expected = "requires")]
+fn test_builder_dependency_missing_panics() {
+    let mut app = App::new();
+    app.add_plugins(
+        PluginBuilder::new("BuilderDependentPlugin")
+            .depends_on::<PhysicsPlugin>()
+            .build(),
+    );
+}
+
+// ============================================================================
+// Introspection tests (feature-gated)
+// ============================================================================
+
+#[cfg(feature = "introspection")]
+mod introspection_tests {
+    use super::*;
+    use bevy_plugin_builder::{PluginInfo, PluginRegistry};
+
+    // Test plugin with full metadata
+    #[derive(Resource, Default)]
+    struct IntrospectionResource;
+
+    #[derive(Message)]
+    struct IntrospectionMessage;
+
+    fn introspection_startup() {}
+    fn introspection_update() {}
+
+    define_plugin!(IntrospectionTestPlugin {
+        meta: {
+            version: "1.2.3",
+            description: "A test plugin for introspection"
+        },
+        init_resource: [IntrospectionResource],
+        add_message: [IntrospectionMessage],
+        add_systems_startup: [introspection_startup],
+        add_systems_update: [introspection_update]
+    });
+
+    #[test]
+    fn test_plugin_info_trait() {
+        // PluginInfo trait should be implemented
+        assert_eq!(IntrospectionTestPlugin::NAME, "IntrospectionTestPlugin");
+        assert_eq!(IntrospectionTestPlugin::VERSION, Some("1.2.3"));
+
+        let metadata = IntrospectionTestPlugin::metadata();
+        assert_eq!(metadata.name, "IntrospectionTestPlugin");
+        assert_eq!(metadata.version, Some("1.2.3"));
+        assert_eq!(
+            metadata.description,
+            Some("A test plugin for introspection")
+        );
+    }
+
+    // System counts are consts, so they can be checked at compile time
+    const _: () = assert!(FullFeatureTestPlugin::STARTUP_SYSTEM_COUNT == 1);
+    const _: () = assert!(FullFeatureTestPlugin::UPDATE_SYSTEM_COUNT == 2);
+    const _: () = assert!(FullFeatureTestPlugin::FIXED_SYSTEM_COUNT == 0);
+
+    #[test]
+    fn test_plugin_info_system_counts() {
+        assert_eq!(FixedUpdateTestPlugin::FIXED_SYSTEM_COUNT, 1);
+        assert_eq!(
+            RawSystemsPlugin::UPDATE_SYSTEM_COUNT,
+            RawSystemsPlugin::metadata().systems.update.len()
+        );
+    }
+
+    #[test]
+    fn test_plugin_metadata_resources() {
+        let metadata = IntrospectionTestPlugin::metadata();
+        assert_eq!(metadata.resources.len(), 1);
+        assert_eq!(metadata.resources[0].name, "IntrospectionResource");
+        assert!(metadata.has_resource::<IntrospectionResource>());
+        assert!(!metadata.has_resource::<String>()); // Non-existent resource
+    }
+
+    #[test]
+    fn test_plugin_metadata_messages() {
+        let metadata = IntrospectionTestPlugin::metadata();
+        assert_eq!(metadata.messages.len(), 1);
+        assert_eq!(metadata.messages[0].name, "IntrospectionMessage");
+        assert!(metadata.has_message::<IntrospectionMessage>());
+    }
+
+    #[test]
+    fn test_plugin_metadata_systems() {
+        let metadata = IntrospectionTestPlugin::metadata();
+        assert_eq!(metadata.systems.startup.len(), 1);
+        assert_eq!(metadata.systems.startup[0], "introspection_startup");
+        assert_eq!(metadata.systems.update.len(), 1);
+        assert_eq!(metadata.systems.update[0], "introspection_update");
+        assert_eq!(metadata.total_systems(), 2);
+    }
+
+    #[test]
+    fn test_plugin_metadata_with_default_settings() {
+        // default_settings doesn't hide the other meta entries
+        assert_eq!(SettingsMetaPlugin::VERSION, Some("1.0.0"));
+        assert_eq!(
+            SettingsMetaPlugin::metadata().description,
+            Some("A test plugin with default settings")
+        );
+    }
+
+    #[test]
+    fn test_plugin_metadata_state_systems() {
+        // A bracketed list counts each system, a system expression counts as one
+        let metadata = OrderedOnEnterPlugin::metadata();
+        assert_eq!(metadata.systems.on_enter_count, 2);
+        assert_eq!(metadata.systems.on_exit_count, 1);
+    }
+
+    #[test]
+    fn test_state_transition_systems_metadata() {
+        let metadata = TransitionObserverPlugin::metadata();
+        assert_eq!(metadata.systems.state_transition, &["record_transition"]);
+        assert_eq!(metadata.total_systems(), 1);
+        assert!(BasicTestPlugin::metadata()
+            .systems
+            .state_transition
+            .is_empty());
+    }
+
+    define_plugin!(FooPlugin {
+        meta: {
+            display_name: "Foo Subsystem",
+            version: "0.4.0"
+        },
+        init_resource: [IntrospectionResource]
+    });
+
+    define_plugin!(FooClientPlugin {
+        depends_on: [FooPlugin],
+        add_message: [IntrospectionMessage]
+    });
+
+    #[test]
+    fn test_display_name_metadata() {
+        let metadata = FooPlugin::metadata();
+        assert_eq!(metadata.display_name, Some("Foo Subsystem"));
+        // The type name is kept for lookups, including Bevy's own
+        assert_eq!(FooPlugin.name(), std::any::type_name::<FooPlugin>());
+        let mut app = App::new();
+        app.add_plugins(FooPlugin);
+        assert!(app.is_plugin_added::<FooPlugin>());
+        assert_eq!(FooPlugin::NAME, "FooPlugin");
+        assert_eq!(metadata.name, "FooPlugin");
+        // Entries after display_name are still read
+        assert_eq!(FooPlugin::VERSION, Some("0.4.0"));
+        assert_eq!(BasicTestPlugin::metadata().display_name, None);
+    }
+
+    #[test]
+    fn test_display_name_dependency_queries() {
+        let mut registry = PluginRegistry::new();
+        registry.register::<FooPlugin>();
+        registry.register::<FooClientPlugin>();
+
+        assert!(FooClientPlugin::metadata().depends_on(FooPlugin::NAME));
+        assert_eq!(
+            registry.plugins_depending_on(FooPlugin::NAME),
+            vec!["FooClientPlugin"]
+        );
+        assert_eq!(registry.leaves(), vec!["FooClientPlugin"]);
+        assert!(registry.find_by_name("FooPlugin").is_some());
+    }
+
+    #[test]
+    fn test_custom_logic_metadata() {
+        let metadata = FullFeatureTestPlugin::metadata();
+        assert!(metadata.has_custom_build);
+        assert!(metadata.has_custom_finish);
+
+        // custom_build taking the plugin itself counts as well
+        let metadata = ConfigurableMovementPlugin::metadata();
+        assert!(metadata.has_custom_build);
+        assert!(!metadata.has_custom_finish);
+
+        let metadata = BasicTestPlugin::metadata();
+        assert!(!metadata.has_custom_build);
+        assert!(!metadata.has_custom_finish);
+    }
 
-    fn introspection_startup() {}
-    fn introspection_update() {}
+    #[derive(Component, Reflect)]
+    struct ReflectedPlayer;
 
-    define_plugin!(IntrospectionTestPlugin {
-        meta: {
-            version: "1.2.3",
-            description: "A test plugin for introspection"
-        },
-        init_resource: [IntrospectionResource],
-        add_message: [IntrospectionMessage],
-        add_systems_startup: [introspection_startup],
-        add_systems_update: [introspection_update]
+    #[derive(Component, Reflect)]
+    struct ReflectedBoss;
+
+    define_plugin!(ReflectedComponentsPlugin {
+        register_type: [Health],
+        register_components: [ReflectedPlayer, ReflectedBoss]
     });
 
     #[test]
-    fn test_plugin_info_trait() {
-        // PluginInfo trait should be implemented
-        assert_eq!(IntrospectionTestPlugin::NAME, "IntrospectionTestPlugin");
-        assert_eq!(IntrospectionTestPlugin::VERSION, Some("1.2.3"));
+    fn test_register_components_metadata() {
+        let metadata = ReflectedComponentsPlugin::metadata();
+        let names: Vec<_> = metadata
+            .reflected_components
+            .iter()
+            .map(|info| info.name)
+            .collect();
+        assert_eq!(names, vec!["ReflectedPlayer", "ReflectedBoss"]);
+        assert!(metadata.has_reflected_component::<ReflectedBoss>());
+        // Components are kept apart from the other reflected types
+        assert!(metadata.has_reflected_type::<Health>());
+        assert!(!metadata.has_reflected_type::<ReflectedPlayer>());
+        assert!(!metadata.has_reflected_component::<Health>());
 
-        let metadata = IntrospectionTestPlugin::metadata();
-        assert_eq!(metadata.name, "IntrospectionTestPlugin");
-        assert_eq!(metadata.version, Some("1.2.3"));
+        let mut app = App::new();
+        app.add_plugins(ReflectedComponentsPlugin);
+        let registry = app.world().resource::<AppTypeRegistry>().read();
+        assert!(registry.contains(std::any::TypeId::of::<ReflectedPlayer>()));
+        assert!(registry.contains(std::any::TypeId::of::<ReflectedBoss>()));
+    }
+
+    #[test]
+    fn test_generic_reflected_type_metadata() {
+        let metadata = GenericReflectPlugin::metadata();
+        assert!(metadata.has_reflected_type::<Wrapper<Health>>());
+        assert!(metadata.has_reflected_type::<Wrapper<Option<Wrapper<Health>>>>());
+        // Each instantiation is its own type
+        assert!(!metadata.has_reflected_type::<Wrapper<f32>>());
+
+        let names: Vec<&str> = metadata
+            .reflected_types
+            .iter()
+            .map(|info| info.name)
+            .collect();
         assert_eq!(
-            metadata.description,
-            Some("A test plugin for introspection")
+            names,
+            vec![
+                "Wrapper<Health>",
+                "Option<Health>",
+                "Wrapper<Vec<Health>>",
+                "Wrapper<Option<Wrapper<Health>>>"
+            ]
         );
     }
 
     #[test]
-    fn test_plugin_metadata_resources() {
-        let metadata = IntrospectionTestPlugin::metadata();
-        assert_eq!(metadata.resources.len(), 1);
-        assert_eq!(metadata.resources[0].name, "IntrospectionResource");
-        assert!(metadata.has_resource::<IntrospectionResource>());
-        assert!(!metadata.has_resource::<String>()); // Non-existent resource
+    fn test_finish_init_resource_metadata() {
+        assert!(TileAtlasPlugin::metadata().has_resource::<TileAtlas>());
     }
 
     #[test]
-    fn test_plugin_metadata_messages() {
-        let metadata = IntrospectionTestPlugin::metadata();
-        assert_eq!(metadata.messages.len(), 1);
-        assert_eq!(metadata.messages[0].name, "IntrospectionMessage");
-        assert!(metadata.has_message::<IntrospectionMessage>());
+    fn test_conditional_on_enter_counts() {
+        // A gated list counts each of its systems
+        assert_eq!(TutorialPlugin::metadata().systems.on_enter_count, 4);
     }
 
     #[test]
-    fn test_plugin_metadata_systems() {
-        let metadata = IntrospectionTestPlugin::metadata();
-        assert_eq!(metadata.systems.startup.len(), 1);
-        assert_eq!(metadata.systems.startup[0], "introspection_startup");
-        assert_eq!(metadata.systems.update.len(), 1);
-        assert_eq!(metadata.systems.update[0], "introspection_update");
-        assert_eq!(metadata.total_systems(), 2);
+    fn test_schedule_graph_lists_update_systems() {
+        let graph = FlushBetweenPlugin::metadata().schedule_graph();
+        assert!(graph.contains("  Update\n    spawn_flushed_marker\n    count_flushed_markers"));
+        assert!(graph.contains("  Startup (none)"));
+    }
+
+    #[test]
+    fn test_observers_metadata() {
+        let metadata = ObserverPlugin::metadata();
+        assert_eq!(metadata.observers, &["count_added_players"]);
+        assert!(BasicTestPlugin::metadata().observers.is_empty());
+    }
+
+    define_plugin!(CfgTransitionPlugin {
+        init_resource: [ObservedTransitions],
+        add_systems_state_transition: [
+            record_transition,
+            #[cfg(not(feature = "introspection"))]
+            introspection_update
+        ]
+    });
+
+    #[test]
+    fn test_cfg_gated_systems_metadata() {
+        // Systems compiled out by their cfg are not listed or counted
+        let metadata = CfgSystemsPlugin::metadata();
+        assert_eq!(
+            metadata.systems.update,
+            &["introspection_only", "always_runs"]
+        );
+        assert_eq!(CfgSystemsPlugin::UPDATE_SYSTEM_COUNT, 2);
+
+        let metadata = CfgTransitionPlugin::metadata();
+        assert_eq!(metadata.systems.state_transition, &["record_transition"]);
+    }
+
+    #[test]
+    fn test_on_enter_any_counts() {
+        // One system shared by two states counts once per state
+        let metadata = SharedEnterPlugin::metadata();
+        assert_eq!(metadata.systems.on_enter_count, 2);
     }
 
     // Test plugin without metadata block
@@ -720,6 +3158,43 @@ mod introspection_tests {
         init_resource: [IntrospectionResource]
     });
 
+    // Transition maps with several states, a trailing comma and an empty list
+    fn count_enter_a() {}
+    fn count_enter_b() {}
+    fn count_enter_c() {}
+
+    define_plugin!(CountedTransitionsPlugin {
+        init_state: [TestState],
+        add_systems_on_enter: {
+            TestState::StateA => [count_enter_a, count_enter_b],
+            TestState::StateB => [count_enter_a, count_enter_b, count_enter_c,],
+            TestState::StateA => [],
+        },
+        add_systems_on_exit: {
+            TestState::StateA => [],
+            TestState::StateB => [count_enter_c]
+        }
+    });
+
+    #[test]
+    fn test_transition_counts_across_states() {
+        let metadata = CountedTransitionsPlugin::metadata();
+        assert_eq!(metadata.systems.on_enter_count, 5);
+        assert_eq!(metadata.systems.on_exit_count, 1);
+        assert_eq!(metadata.total_systems(), 6);
+
+        let mut app = App::new();
+        app.add_plugins((StatesPlugin, CountedTransitionsPlugin));
+        app.update();
+    }
+
+    #[test]
+    fn test_inspectable_resources_metadata() {
+        let metadata = InspectablePlugin::metadata();
+        assert!(metadata.has_resource::<InspectableSettings>());
+        assert!(metadata.has_reflected_type::<InspectableSettings>());
+    }
+
     #[test]
     fn test_plugin_info_without_meta() {
         assert_eq!(NoMetaPlugin::NAME, "NoMetaPlugin");
@@ -793,6 +3268,164 @@ mod introspection_tests {
         assert_eq!(registry.total_systems(), 2); // Only IntrospectionTestPlugin has systems
     }
 
+    #[test]
+    fn test_plugin_registry_plugins_with_reflected_type() {
+        let mut registry = PluginRegistry::new();
+        registry.register::<FullFeatureTestPlugin>();
+        registry.register::<BasicTestPlugin>();
+
+        assert!(FullFeatureTestPlugin::metadata().has_reflected_type::<TestComponent>());
+        assert_eq!(
+            registry.plugins_with_reflected_type::<TestComponent>(),
+            vec!["FullFeatureTestPlugin"]
+        );
+        assert!(registry
+            .plugins_with_reflected_type::<TestResource>()
+            .is_empty());
+    }
+
+    define_plugin!(DebugHudTestPlugin {
+        meta: { version: "0.3.0" },
+        init_resource: [TestResource],
+        add_systems_update: [another_update_system],
+        generate_debug_hud: true
+    });
+
+    #[test]
+    fn test_generate_debug_hud() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(DebugHudTestPlugin);
+
+        assert!(app
+            .world()
+            .resource::<PluginRegistry>()
+            .get::<DebugHudTestPlugin>()
+            .is_some());
+
+        app.update();
+
+        let report = app
+            .world()
+            .resource::<bevy_plugin_builder::PluginDebugReport>();
+        assert_eq!(
+            report.0,
+            "DebugHudTestPlugin 0.3.0: 1 resources, 0 messages, 0 states, 1 systems"
+        );
+    }
+
+    #[derive(Resource, Default)]
+    struct AudioMixer;
+
+    define_plugin!(MusicPlugin {
+        init_resource: [AudioMixer]
+    });
+
+    define_plugin!(SoundEffectsPlugin {
+        init_resource: [AudioMixer]
+    });
+
+    #[test]
+    fn test_overlapping_resources_warning() {
+        let logs = captured_logs(|app| {
+            app.add_plugins((MusicPlugin, SoundEffectsPlugin));
+            app.finish();
+        });
+
+        // Reported once, by the plugin registered second
+        let warnings: Vec<_> = logs
+            .iter()
+            .filter(|log| log.contains("AudioMixer"))
+            .collect();
+        assert_eq!(
+            warnings,
+            vec!["Plugin SoundEffectsPlugin initializes resource AudioMixer, which is already initialized by MusicPlugin"]
+        );
+    }
+
+    define_plugin!(DebugImplTestPlugin {
+        init_resource: [TestResource, AnotherResource],
+        add_message: [TestEvent],
+        add_systems_update: [another_update_system],
+        impl_debug: true
+    });
+
+    #[test]
+    fn test_impl_debug() {
+        let plugins: Vec<Box<dyn std::fmt::Debug>> = vec![Box::new(DebugImplTestPlugin)];
+        let output = format!("{:?}", plugins[0]);
+
+        assert!(output.starts_with("DebugImplTestPlugin"));
+        assert!(output.contains("resources: 2"));
+        assert!(output.contains("systems: 1"));
+    }
+
+    // Plugin that initializes the same resource as its dependency
+    define_plugin!(DuplicatePhysicsConfigPlugin {
+        depends_on: [PhysicsPlugin],
+        init_resource: [PhysicsConfig]
+    });
+
+    #[test]
+    fn test_plugin_registry_plugins_depending_on() {
+        let mut registry = PluginRegistry::new();
+        registry.register::<PhysicsPlugin>();
+        registry.register::<GamePlugin>();
+        registry.register::<UIPlugin>();
+
+        // GamePlugin and UIPlugin both depend on PhysicsPlugin
+        assert_eq!(
+            registry.plugins_depending_on("PhysicsPlugin"),
+            vec!["GamePlugin", "UIPlugin"]
+        );
+        assert_eq!(
+            registry.plugins_depending_on("AudioPlugin"),
+            vec!["UIPlugin"]
+        );
+        assert!(registry.plugins_depending_on("GamePlugin").is_empty());
+    }
+
+    #[test]
+    fn test_plugin_registry_duplicate_resources() {
+        let mut registry = PluginRegistry::new();
+        registry.register::<PhysicsPlugin>();
+        registry.register::<AudioPlugin>();
+        assert!(registry.duplicate_resources().is_empty());
+
+        registry.register::<DuplicatePhysicsConfigPlugin>();
+        assert_eq!(
+            registry.duplicate_resources(),
+            vec![(
+                "PhysicsConfig",
+                vec!["PhysicsPlugin", "DuplicatePhysicsConfigPlugin"]
+            )]
+        );
+    }
+
+    // Duplicates PhysicsPlugin's resource through GamePlugin, not directly
+    define_plugin!(TransitiveDuplicatePlugin {
+        depends_on: [GamePlugin],
+        init_resource: [PhysicsConfig]
+    });
+
+    #[test]
+    fn test_plugin_registry_register_with_dependencies() {
+        let mut registry = PluginRegistry::new();
+        registry.register_with_dependencies::<TransitiveDuplicatePlugin>();
+
+        assert_eq!(
+            registry.list_all().map(|m| m.name).collect::<Vec<_>>(),
+            vec!["PhysicsPlugin", "GamePlugin", "TransitiveDuplicatePlugin"]
+        );
+        assert_eq!(
+            registry.duplicate_resources(),
+            vec![(
+                "PhysicsConfig",
+                vec!["PhysicsPlugin", "TransitiveDuplicatePlugin"]
+            )]
+        );
+    }
+
     #[test]
     fn test_plugin_registry_list_order() {
         let mut registry = PluginRegistry::new();
@@ -807,6 +3440,194 @@ mod introspection_tests {
         assert_eq!(names[1], "NoMetaPlugin");
         assert_eq!(names[2], "DependentIntrospectionPlugin");
     }
+
+    define_plugin!(ForgottenConfigPlugin {
+        warn_if_empty: true
+    });
+
+    define_plugin!(NonEmptyWarnPlugin {
+        warn_if_empty: true,
+        init_resource: [IntrospectionResource]
+    });
+
+    #[test]
+    fn test_warn_if_empty() {
+        let logs = captured_logs(|app| {
+            app.add_plugins(ForgottenConfigPlugin);
+        });
+        assert!(logs
+            .iter()
+            .any(|log| log == "Plugin ForgottenConfigPlugin registered nothing"));
+
+        let logs = captured_logs(|app| {
+            app.add_plugins(NonEmptyWarnPlugin);
+        });
+        assert!(!logs.iter().any(|log| log.contains("registered nothing")));
+    }
+
+    define_plugin!(StatelessTransitionPlugin {
+        warn_if_transitions_without_states: true,
+        add_systems_on_exit: {
+            NewSyntaxState::Active => [introspection_update]
+        }
+    });
+
+    // Reacts to a state another plugin owns, so it doesn't opt in
+    define_plugin!(StateListenerPlugin {
+        add_systems_on_exit: {
+            NewSyntaxState::Active => [introspection_update]
+        }
+    });
+
+    #[test]
+    fn test_warn_on_transitions_without_states() {
+        let logs = captured_logs(|app| {
+            app.add_plugins(StatelessTransitionPlugin);
+        });
+        assert!(logs.iter().any(|log| {
+            log.starts_with(
+            "Plugin StatelessTransitionPlugin adds OnEnter/OnExit systems but initializes no states"
+        )
+        }));
+
+        // The check is opt-in
+        let logs = captured_logs(|app| {
+            app.add_plugins(StateListenerPlugin);
+        });
+        assert!(!logs.iter().any(|log| log.contains("initializes no states")));
+    }
+
+    fn duplicated_movement() {}
+
+    define_plugin!(DuplicatedSchedulePlugin {
+        add_systems_update: [duplicated_movement, introspection_update],
+        add_systems_fixed_update: [duplicated_movement]
+    });
+
+    #[test]
+    fn test_warn_on_systems_in_multiple_schedules() {
+        let logs = captured_logs(|app| {
+            app.add_plugins(DuplicatedSchedulePlugin);
+        });
+        let warnings: Vec<_> = logs.iter().filter(|log| log.contains("to both")).collect();
+        assert_eq!(
+            warnings,
+            vec!["Plugin DuplicatedSchedulePlugin adds system duplicated_movement to both Update and FixedUpdate"]
+        );
+
+        let logs = captured_logs(|app| {
+            app.add_plugins(IntrospectionTestPlugin);
+        });
+        assert!(!logs.iter().any(|log| log.contains("to both")));
+    }
+
+    #[test]
+    fn test_builder_metadata_in_registry() {
+        let plugin = PluginBuilder::new("DynamicIntrospectionPlugin")
+            .init_resource::<IntrospectionResource>()
+            .add_message::<IntrospectionMessage>()
+            .add_systems_update(update_system)
+            .depends_on::<PhysicsPlugin>()
+            .build();
+
+        let mut registry = PluginRegistry::new();
+        registry.register::<IntrospectionTestPlugin>();
+        registry.register_metadata(plugin.metadata());
+        // Registering the same metadata again is ignored
+        registry.register_metadata(plugin.metadata());
+
+        assert_eq!(registry.len(), 2);
+        assert_eq!(
+            registry.plugin_names(),
+            vec!["IntrospectionTestPlugin", "DynamicIntrospectionPlugin"]
+        );
+
+        let metadata = registry.find_by_name("DynamicIntrospectionPlugin").unwrap();
+        assert!(metadata.has_resource::<IntrospectionResource>());
+        assert!(metadata.has_message::<IntrospectionMessage>());
+        assert!(metadata.depends_on("PhysicsPlugin"));
+        assert_eq!(metadata.systems.update, &["update_system"]);
+
+        let plugins = registry.plugins_with_message::<IntrospectionMessage>();
+        assert!(plugins.contains(&"DynamicIntrospectionPlugin"));
+    }
+
+    define_plugin!(ConstMetadataPlugin {
+        metadata_const_name: CONST_METADATA_PLUGIN_METADATA,
+        meta: { version: "0.4.0" },
+        init_resource: [IntrospectionResource],
+        add_systems_update: [introspection_update]
+    });
+
+    // Checked at compile time, without going through PluginInfo
+    const _: () = assert!(CONST_METADATA_PLUGIN_METADATA.resources.len() == 1);
+    const _: () = assert!(CONST_METADATA_PLUGIN_METADATA.systems.update.len() == 1);
+    const _: () = assert!(CONST_METADATA_PLUGIN_METADATA.version.is_some());
+
+    #[test]
+    fn test_metadata_const_name() {
+        assert!(std::ptr::eq(
+            CONST_METADATA_PLUGIN_METADATA,
+            ConstMetadataPlugin::metadata()
+        ));
+        assert_eq!(CONST_METADATA_PLUGIN_METADATA.name, "ConstMetadataPlugin");
+    }
+}
+
+// ============================================================================
+// Reflect report tests (feature-gated)
+// ============================================================================
+
+#[cfg(feature = "reflect")]
+mod reflect_report_tests {
+    use super::*;
+    use bevy::reflect::serde::ReflectSerializer;
+    use bevy_plugin_builder::ReflectablePluginReport;
+
+    #[derive(Resource, Default)]
+    struct ServerTickRate;
+
+    define_plugin!(NetcodePlugin {
+        meta: {
+            version: "0.4.0",
+            description: "Replicates entities to clients"
+        },
+        init_resource: [ServerTickRate],
+        generate_reflect_report: true
+    });
+
+    #[test]
+    fn test_reflect_report_populated_in_finish() {
+        let mut app = App::new();
+        app.add_plugins(NetcodePlugin);
+        assert!(app
+            .world()
+            .get_resource::<ReflectablePluginReport>()
+            .is_none());
+
+        app.finish();
+
+        let report = app.world().resource::<ReflectablePluginReport>();
+        assert_eq!(report.plugins.len(), 1);
+        assert_eq!(report.plugins[0].name, "NetcodePlugin");
+        assert_eq!(report.plugins[0].version.as_deref(), Some("0.4.0"));
+    }
+
+    #[test]
+    fn test_reflect_report_serializes() {
+        let mut app = App::new();
+        app.add_plugins(NetcodePlugin);
+        app.finish();
+
+        let registry = app.world().resource::<AppTypeRegistry>().read();
+        let report = app.world().resource::<ReflectablePluginReport>();
+        let serializer = ReflectSerializer::new(report, &registry);
+        let text = ron::to_string(&serializer).expect("report should serialize");
+
+        assert!(text.contains("ReflectablePluginReport"));
+        assert!(text.contains("NetcodePlugin"));
+        assert!(text.contains("Replicates entities to clients"));
+    }
 }
 
 // =============================================================================
@@ -840,6 +3661,7 @@ mod testing_feature_tests {
     define_plugin!(TestableResourcePlugin {
         init_resource: [TestingModuleResource],
         generate_tests: {
+            module: testable_resource_plugin_tests,
             test_resources: true
         }
     });
@@ -857,6 +3679,7 @@ mod testing_feature_tests {
         init_resource: [TestingModuleResource, AnotherTestingResource],
         add_message: [TestingModuleEvent, AnotherTestingEvent],
         generate_tests: {
+            module: testable_multi_option_plugin_tests,
             test_resources: true,
             test_messages: true
         }
@@ -875,6 +3698,7 @@ mod testing_feature_tests {
         init_resource: [TestingModuleResource],
         add_message: [TestingModuleEvent],
         generate_tests: {
+            module: testable_selective_plugin_tests,
             test_resources: true,
             test_messages: false  // Should not generate message tests
         }
@@ -899,6 +3723,7 @@ mod testing_feature_tests {
     define_plugin!(TestableStatePlugin {
         init_state: [TestingModuleState],
         generate_tests: {
+            module: testable_state_plugin_tests,
             test_states: true
         }
     });
@@ -922,6 +3747,7 @@ mod testing_feature_tests {
         add_systems_startup: [testable_startup],
         add_systems_update: [testable_update],
         generate_tests: {
+            module: testable_complex_plugin_tests,
             test_resources: true,
             test_messages: true
         }
@@ -938,6 +3764,7 @@ mod testing_feature_tests {
     define_plugin!(TestableWithCustomBuild {
         init_resource: [TestingModuleResource],
         generate_tests: {
+            module: testable_with_custom_build_tests,
             test_resources: true
         },
         custom_build: |app: &mut App| {
@@ -952,4 +3779,133 @@ mod testing_feature_tests {
         app.add_plugins(TestableWithCustomBuild);
         assert!(app.world().contains_resource::<TestingModuleResource>());
     }
+
+    // Test test_app_boots adding StatesPlugin, dependencies of dependencies,
+    // a unit dependency by path and a dependency with fields through Default
+    #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+    enum BootState {
+        #[default]
+        Loading,
+        Ready,
+    }
+
+    #[derive(Resource)]
+    struct BootAtlas;
+
+    // Res<Time> needs the MinimalPlugins that test_app_boots adds
+    fn finish_loading(
+        _time: Res<Time>,
+        _atlas: Res<BootAtlas>,
+        mut next_state: ResMut<NextState<BootState>>,
+    ) {
+        next_state.set(BootState::Ready);
+    }
+
+    // Only initialized in finish(), so the test has to finish the app
+    define_plugin!(BootBasePlugin {
+        finish_init_resource: {
+            BootAtlas => |_world| BootAtlas
+        }
+    });
+
+    define_plugin!(BootDependencyPlugin {
+        depends_on: [BootBasePlugin],
+        init_resource: [AnotherTestingResource]
+    });
+
+    define_plugin!(
+        #[derive(Default)]
+        BootSettingsPlugin {
+            fields: { tick_rate: u32 }
+        }
+    );
+
+    define_plugin!(TestableBootPlugin {
+        depends_on: [BootDependencyPlugin, BootSettingsPlugin],
+        init_state: [BootState],
+        add_systems_update: [finish_loading.run_if(in_state(BootState::Loading))],
+        generate_tests: {
+            module: testable_boot_plugin_tests,
+            test_app_boots: true
+        }
+    });
+
+    // Test generate_tests with named custom_build and custom_finish functions
+    fn insert_testing_module_resource(app: &mut App) {
+        app.init_resource::<TestingModuleResource>();
+    }
+
+    fn finish_noop(_app: &mut App) {}
+
+    define_plugin!(TestableNamedHooksPlugin {
+        custom_build: insert_testing_module_resource,
+        generate_tests: {
+            module: testable_named_hooks_tests,
+            test_unique: true
+        },
+        custom_finish: finish_noop
+    });
+
+    #[test]
+    fn test_plugin_with_named_hooks_and_generate_tests() {
+        let mut app = App::new();
+        app.add_plugins(TestableNamedHooksPlugin);
+        app.finish();
+        assert!(app.world().contains_resource::<TestingModuleResource>());
+    }
+
+    // Test generate_tests with reflection registration testing
+    #[derive(Reflect, Default)]
+    #[reflect(Default)]
+    struct TestingReflectedSettings {
+        volume: f32,
+    }
+
+    #[derive(Reflect)]
+    struct TestingReflectedMarker;
+
+    define_plugin!(TestableReflectPlugin {
+        register_type: [
+            TestingReflectedSettings,
+            TestingReflectedMarker,
+            Option<TestingReflectedMarker>
+        ],
+        generate_tests: {
+            module: testable_reflect_plugin_tests,
+            test_reflect: true
+        }
+    });
+
+    #[test]
+    fn test_reflect_plugin_compiles() {
+        let mut app = App::new();
+        app.add_plugins(TestableReflectPlugin);
+        let registry = app.world().resource::<AppTypeRegistry>().read();
+        let settings = registry
+            .get_type_data::<ReflectDefault>(std::any::TypeId::of::<TestingReflectedSettings>())
+            .expect("TestingReflectedSettings should be registered with ReflectDefault")
+            .default()
+            .take::<TestingReflectedSettings>()
+            .expect("ReflectDefault should build TestingReflectedSettings");
+        assert_eq!(settings.volume, 0.0);
+    }
+
+    // Test generate_tests checking for resources duplicated across dependencies
+    #[cfg(feature = "introspection")]
+    define_plugin!(TestableNoDuplicatesPlugin {
+        depends_on: [PhysicsPlugin, AudioPlugin],
+        init_resource: [TestingModuleResource],
+        generate_tests: {
+            module: testable_no_duplicates_plugin_tests,
+            test_no_duplicate_resources: true
+        }
+    });
+
+    #[cfg(feature = "introspection")]
+    #[test]
+    fn test_no_duplicates_plugin_compiles() {
+        let mut app = App::new();
+        app.add_plugins((PhysicsPlugin, AudioPlugin, TestableNoDuplicatesPlugin));
+        assert!(app.world().contains_resource::<TestingModuleResource>());
+    }
 }
@@ -0,0 +1,85 @@
+//! Several introspected plugins in one module.
+//!
+//! Each plugin's metadata statics are generated inside its own `const _`
+//! block, so plugins declared side by side (including from a wrapper macro)
+//! never clash on names like `RESOURCES` or `METADATA`.
+
+#![cfg(feature = "introspection")]
+
+use bevy::prelude::*;
+use bevy_plugin_builder::{define_plugin, PluginInfo, PluginRegistry};
+
+#[derive(Resource, Default)]
+struct MapData;
+
+#[derive(Resource, Default)]
+struct WeatherData;
+
+#[derive(Resource, Default)]
+struct TrafficData;
+
+#[derive(Message)]
+struct MapLoaded;
+
+fn tick() {}
+
+// Wrapper macro, the way plugins are typically generated in bulk
+macro_rules! data_plugin {
+    ($name:ident, $resource:ty) => {
+        define_plugin!($name {
+            meta: {
+                version: "1.0.0"
+            },
+            init_resource: [$resource],
+            add_systems_update: [tick]
+        });
+    };
+}
+
+data_plugin!(WeatherPlugin, WeatherData);
+data_plugin!(TrafficPlugin, TrafficData);
+
+define_plugin!(MapPlugin {
+    meta: {
+        version: "2.0.0",
+        description: "Map loading"
+    },
+    init_resource: [MapData],
+    add_message: [MapLoaded]
+});
+
+#[test]
+fn test_plugins_keep_separate_metadata() {
+    assert_eq!(MapPlugin::metadata().name, "MapPlugin");
+    assert_eq!(WeatherPlugin::metadata().name, "WeatherPlugin");
+    assert_eq!(TrafficPlugin::metadata().name, "TrafficPlugin");
+
+    assert!(MapPlugin::metadata().has_resource::<MapData>());
+    assert!(MapPlugin::metadata().has_message::<MapLoaded>());
+    assert!(WeatherPlugin::metadata().has_resource::<WeatherData>());
+    assert!(!WeatherPlugin::metadata().has_resource::<TrafficData>());
+    assert!(TrafficPlugin::metadata().has_resource::<TrafficData>());
+
+    assert_eq!(MapPlugin::VERSION, Some("2.0.0"));
+    assert_eq!(WeatherPlugin::VERSION, Some("1.0.0"));
+}
+
+#[test]
+fn test_plugins_register_independently() {
+    let mut registry = PluginRegistry::new();
+    registry.register::<MapPlugin>();
+    registry.register::<WeatherPlugin>();
+    registry.register::<TrafficPlugin>();
+
+    assert_eq!(registry.len(), 3);
+    assert_eq!(
+        registry.plugins_with_resource::<TrafficData>(),
+        vec!["TrafficPlugin"]
+    );
+
+    let mut app = App::new();
+    app.add_plugins((MapPlugin, WeatherPlugin, TrafficPlugin));
+    assert!(app.world().contains_resource::<MapData>());
+    assert!(app.world().contains_resource::<WeatherData>());
+    assert!(app.world().contains_resource::<TrafficData>());
+}
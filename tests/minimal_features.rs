@@ -0,0 +1,45 @@
+//! Build with no optional features enabled.
+//!
+//! Without `introspection` and `testing`, `meta:` and `generate_tests:` are
+//! accepted but generate nothing, and the metadata/registry types are not
+//! compiled. Run with `cargo test --no-default-features --test minimal_features`.
+
+#![cfg(not(any(feature = "introspection", feature = "testing")))]
+
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+
+#[derive(Resource, Default)]
+struct Score(u32);
+
+#[derive(Message)]
+struct ScoreChanged;
+
+fn add_point(mut score: ResMut<Score>) {
+    score.0 += 1;
+}
+
+define_plugin!(ScorePlugin {
+    meta: {
+        version: "1.0.0",
+        description: "Keeps score"
+    },
+    init_resource: [Score],
+    add_message: [ScoreChanged],
+    add_systems_update: [add_point],
+    generate_tests: {
+        module: score_plugin_tests,
+        test_resources: true,
+        test_messages: true
+    }
+});
+
+#[test]
+fn test_plugin_builds_without_optional_features() {
+    let mut app = App::new();
+    app.add_plugins(ScorePlugin);
+    app.update();
+
+    assert_eq!(app.world().resource::<Score>().0, 1);
+    assert!(app.world().contains_resource::<Messages<ScoreChanged>>());
+}
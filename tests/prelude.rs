@@ -0,0 +1,67 @@
+//! Everything a typical plugin needs, imported through the prelude.
+
+use bevy::prelude::*;
+use bevy_plugin_builder::prelude::*;
+
+#[derive(Resource, Default)]
+struct PreludeResource;
+
+#[derive(Message)]
+struct PreludeMessage;
+
+fn prelude_system() {}
+
+define_plugin!(PreludeBasePlugin {
+    init_resource: [PreludeResource]
+});
+
+define_plugin!(PreludePlugin {
+    depends_on: [PreludeBasePlugin],
+    add_message: [PreludeMessage],
+    add_systems_update: [prelude_system]
+});
+
+fn assert_marker<P: PluginMarker + PluginDependencies>() {}
+
+#[test]
+fn test_prelude_imports_macro_and_traits() {
+    assert_marker::<PreludePlugin>();
+    assert_eq!(
+        <PreludePlugin as PluginDependencies>::DEPENDENCY_TYPE_IDS.len(),
+        1
+    );
+
+    let mut app = App::new();
+    let err: MissingPluginError = app.add_plugin_checked(PreludePlugin).unwrap_err();
+    assert!(err.missing.ends_with("PreludeBasePlugin"));
+
+    app.add_plugins(PreludeBasePlugin);
+    app.add_plugin_checked(PreludePlugin).unwrap();
+    app.configure_sets(Update, PluginSystemSet::<PreludePlugin>::new());
+    app.update();
+
+    assert!(app.world().contains_resource::<PreludeResource>());
+}
+
+#[test]
+fn test_prelude_imports_builder() {
+    let plugin: BuiltPlugin = PluginBuilder::new("PreludeBuilt")
+        .init_resource::<PreludeResource>()
+        .build();
+
+    let mut app = App::new();
+    app.add_plugins(plugin);
+    assert!(app.world().contains_resource::<PreludeResource>());
+}
+
+#[cfg(feature = "introspection")]
+#[test]
+fn test_prelude_imports_introspection() {
+    let metadata: &PluginMetadata = PreludePlugin::metadata();
+    assert_eq!(metadata.name, "PreludePlugin");
+
+    let mut registry = PluginRegistry::new();
+    registry.register::<PreludeBasePlugin>();
+    registry.register::<PreludePlugin>();
+    assert_eq!(registry.plugins_with_resource::<PreludeResource>().len(), 1);
+}
@@ -0,0 +1,16 @@
+#![deny(deprecated)]
+
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+
+#[derive(Resource, Default)]
+struct Score(u32);
+
+define_plugin!(LintedPlugin {
+    lint_custom_build: true,
+    custom_build: |app: &mut App| {
+        app.init_resource::<Score>();
+    }
+});
+
+fn main() {}
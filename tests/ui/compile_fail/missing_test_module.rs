@@ -0,0 +1,26 @@
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+
+#[derive(Resource, Default)]
+struct Inventory;
+
+#[derive(Resource, Default)]
+struct CombatStats;
+
+// Two plugins in one scope can't share a default test module name, so each
+// generate_tests: block has to name its own
+define_plugin!(InventoryPlugin {
+    init_resource: [Inventory],
+    generate_tests: {
+        test_resources: true
+    }
+});
+
+define_plugin!(CombatPlugin {
+    init_resource: [CombatStats],
+    generate_tests: {
+        test_resources: true
+    }
+});
+
+fn main() {}
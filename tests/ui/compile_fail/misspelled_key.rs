@@ -0,0 +1,11 @@
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+
+#[derive(Resource, Default)]
+struct Score;
+
+define_plugin!(MisspelledKeyPlugin {
+    init_resourse: [Score]
+});
+
+fn main() {}
@@ -0,0 +1,11 @@
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+
+#[derive(Resource, Reflect, Default)]
+struct Score(u32);
+
+define_plugin!(ScorePlugin {
+    register_components: [Score]
+});
+
+fn main() {}
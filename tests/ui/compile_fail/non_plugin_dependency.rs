@@ -0,0 +1,12 @@
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+
+#[derive(Resource, Default)]
+struct NotAPlugin;
+
+define_plugin!(NeedsResourcePlugin {
+    depends_on: [NotAPlugin],
+    init_resource: [NotAPlugin]
+});
+
+fn main() {}
@@ -0,0 +1,11 @@
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+
+#[derive(Resource, Default)]
+struct NotAState;
+
+define_plugin!(MenuPlugin {
+    init_state: [NotAState]
+});
+
+fn main() {}
@@ -0,0 +1,15 @@
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+
+#[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+enum AppState {
+    #[default]
+    Menu,
+}
+
+define_plugin!(MenuPlugin {
+    init_state: [AppState],
+    add_sub_state: [AppState]
+});
+
+fn main() {}
@@ -0,0 +1,12 @@
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+
+fn move_player() {}
+
+const SPAWN_RATE: u32 = 5;
+
+define_plugin!(PlayerPlugin {
+    add_systems_update: [move_player, SPAWN_RATE]
+});
+
+fn main() {}